@@ -0,0 +1,102 @@
+//! Runs the user, term, taxonomy and business subgraphs in one process
+//!
+//! Every subgraph keeps its own env prefix, port and routes, so the gateway's
+//! `supergraph.yaml` works unchanged. They share one tokio runtime, one logging
+//! subscriber and one metrics registry. All four listeners are bound before any of
+//! them serves, and a single SIGINT/SIGTERM, or one subgraph failing, drains all
+//! four servers.
+//!
+//! ```sh
+//! cargo run -p all_subgraphs
+//! ```
+
+use std::error::Error;
+
+use corelib::predule::{InProcessTermSelectionValidator, InProcessUserDirectory};
+use observability::predule::init_logging;
+use subgraph_runtime::predule::{ServeGroup, SubgraphRuntime};
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn Error>> {
+    // load env, each subgraph from its own prefix
+    let user_config = user::setup::env::EnvConfig::load_from_env()?;
+    let term_config = term::setup::env::EnvConfig::load_from_env()?;
+    let taxonomy_config = taxonomy::setup::env::EnvConfig::load_from_env()?;
+    let business_config = business::setup::env::EnvConfig::load_from_env()?;
+
+    // one subscriber per process, configured from the user subgraph settings
+    init_logging(&user_config.logging);
+
+    // build app states, the user and business subgraphs check catalog references in-process
+    // and the business subgraph looks its callers up in the user service
+    let term_state = term::setup::state::build_state(&term_config);
+    let taxonomy_state = taxonomy::setup::state::build_state(&taxonomy_config);
+    let user_state = user::setup::state::build_state_with_validator(
+        &user_config,
+        Some(Box::new(InProcessTermSelectionValidator::new(
+            taxonomy_state.taxonomy_service.clone(),
+            term_state.term_service.clone(),
+        ))),
+    );
+    let business_state = business::setup::state::build_state_with_adapters(
+        &business_config,
+        Some(Box::new(InProcessTermSelectionValidator::new(
+            taxonomy_state.taxonomy_service.clone(),
+            term_state.term_service.clone(),
+        ))),
+        Some(Box::new(InProcessUserDirectory::new(
+            user_state.user_service.clone(),
+        ))),
+    )?;
+
+    let user = SubgraphRuntime::builder("user")
+        .server(&user_config.server)
+        .graphql(&user_config.graphql)
+        .auth(user_config.auth.as_ref())
+        .schema(user::setup::schema::build_schema(user_state.clone()))
+        .state(user_state)
+        .build()?;
+    let term = SubgraphRuntime::builder("term")
+        .server(&term_config.server)
+        .graphql(&term_config.graphql)
+        .schema(term::setup::schema::build_schema(term_state.clone()))
+        .state(term_state)
+        .build()?;
+    let taxonomy = SubgraphRuntime::builder("taxonomy")
+        .server(&taxonomy_config.server)
+        .graphql(&taxonomy_config.graphql)
+        .schema(taxonomy::setup::schema::build_schema(
+            taxonomy_state.clone(),
+        ))
+        .state(taxonomy_state)
+        .build()?;
+    let business = SubgraphRuntime::builder("business")
+        .server(&business_config.server)
+        .graphql(&business_config.graphql)
+        .auth(business_config.auth.as_ref())
+        .schema(business::setup::schema::build_schema(
+            business_state.clone(),
+        ))
+        .state(business_state)
+        .build()?;
+
+    // bind every listener first so a taken port fails before anything serves
+    let user = user.bind().await?;
+    let term = term.bind().await?;
+    let taxonomy = taxonomy.bind().await?;
+    let business = business.bind().await?;
+
+    // the first failure stops the others, which drain and shut down their state
+    let group = ServeGroup::new();
+    let served = tokio::try_join!(
+        group.serve(user),
+        group.serve(term),
+        group.serve(taxonomy),
+        group.serve(business),
+    );
+    group.stop().await;
+    served?;
+
+    tracing::info!("All subgraphs stopped");
+    Ok(())
+}
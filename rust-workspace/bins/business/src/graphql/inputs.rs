@@ -1,15 +1,67 @@
 use std::collections::HashMap;
 
-use async_graphql::InputObject;
+use async_graphql::{InputObject, MaybeUndefined};
+use chrono::{NaiveDate, NaiveTime};
+use corelib::predule::{
+    BusinessHours, DomainError, GeoPoint, HoursException, PhysicalAddress, TaxonomyId, TermId,
+    TermSelection, TimeRange,
+};
+use uuid::Uuid;
+
+use crate::graphql::types::Weekday;
 
 #[derive(InputObject)]
 pub struct ContactInfoInput {
     pub email: Option<String>,
     pub phone: Option<String>,
-    pub address: Option<String>,
+    pub address: Option<AddressInput>,
     pub website: Option<String>,
 }
 
+/// Coordinates in degrees
+#[derive(InputObject)]
+pub struct GeoPointInput {
+    pub latitude: f64,
+    pub longitude: f64,
+}
+
+impl GeoPointInput {
+    /// # Errors
+    /// A coordinate is out of range, as field errors under `field`
+    pub fn into_point(self, field: &str) -> Result<GeoPoint, DomainError> {
+        GeoPoint::new(self.latitude, self.longitude, field)
+    }
+}
+
+#[derive(InputObject)]
+pub struct AddressInput {
+    pub street: String,
+    pub city: String,
+    pub region: Option<String>,
+    pub postal_code: Option<String>,
+    pub country_term_id: Uuid,
+    /// Needed for the business to show up in geo searches
+    pub location: Option<GeoPointInput>,
+}
+
+impl AddressInput {
+    pub fn into_address(self) -> Result<PhysicalAddress, DomainError> {
+        let location = self
+            .location
+            .map(|location| location.into_point("address.location"))
+            .transpose()?;
+
+        PhysicalAddress::new(
+            self.street,
+            self.city,
+            self.region,
+            self.postal_code,
+            TermId::from_uuid(self.country_term_id),
+            location,
+        )
+    }
+}
+
 #[derive(InputObject)]
 pub struct SocialMediaInput {
     pub facebook: Option<String>,
@@ -21,16 +73,85 @@ pub struct SocialMediaInput {
     pub other: Option<HashMap<String, String>>,
 }
 
+/// Free-text hours such as `Mon-Fri` -> `9am–12pm, 1pm–5pm`, `Sun` -> `closed`
 #[derive(InputObject)]
 pub struct BusinessHourEntryInput {
     pub day: String,
     pub hours: String,
 }
 
+/// Opening interval in local time; it runs past midnight when `closes` is not after `opens`
+#[derive(InputObject)]
+pub struct TimeRangeInput {
+    pub opens: NaiveTime,
+    pub closes: NaiveTime,
+}
+
+#[derive(InputObject)]
+pub struct DayHoursInput {
+    pub weekday: Weekday,
+    pub intervals: Vec<TimeRangeInput>,
+}
+
+/// Hours of one date that replace the weekly schedule, closed all day without intervals
+#[derive(InputObject)]
+pub struct HoursExceptionInput {
+    pub date: NaiveDate,
+    #[graphql(default)]
+    pub intervals: Vec<TimeRangeInput>,
+    pub note: Option<String>,
+}
+
+/// Days missing from both `weekly` and `entries` are closed
+#[derive(InputObject)]
+pub struct BusinessHoursInput {
+    /// IANA timezone, e.g. `Europe/Paris`
+    pub timezone: String,
+    #[graphql(default)]
+    pub weekly: Vec<DayHoursInput>,
+    /// Free-text hours, parsed and added to `weekly`
+    #[graphql(default)]
+    pub entries: Vec<BusinessHourEntryInput>,
+    #[graphql(default)]
+    pub exceptions: Vec<HoursExceptionInput>,
+}
+
+impl BusinessHoursInput {
+    pub fn into_hours(self) -> Result<BusinessHours, DomainError> {
+        let mut weekly: Vec<(chrono::Weekday, TimeRange)> = self
+            .weekly
+            .into_iter()
+            .flat_map(|day| {
+                let weekday = day.weekday.into();
+                day.intervals
+                    .into_iter()
+                    .map(move |range| (weekday, TimeRange::new(range.opens, range.closes)))
+            })
+            .collect();
+        for entry in self.entries {
+            weekly.extend(BusinessHours::parse_entry(&entry.day, &entry.hours)?);
+        }
+
+        let exceptions = self.exceptions.into_iter().map(|exception| {
+            HoursException::new(
+                exception.date,
+                exception
+                    .intervals
+                    .into_iter()
+                    .map(|range| TimeRange::new(range.opens, range.closes))
+                    .collect(),
+                exception.note,
+            )
+        });
+
+        BusinessHours::new(&self.timezone, weekly, exceptions)
+    }
+}
+
 #[derive(InputObject)]
 pub struct BusinessFeaturesInput {
-    /// Example: Mon -> "9am–5pm"
-    pub hours: Option<Vec<BusinessHourEntryInput>>,
+    /// Opening hours in the business's timezone
+    pub hours: Option<BusinessHoursInput>,
 
     /// Services provided by the business
     pub services: Option<Vec<String>>,
@@ -42,6 +163,31 @@ pub struct BusinessFeaturesInput {
     pub extra: Option<HashMap<String, String>>,
 }
 
+/// Terms a Business is filed under in one taxonomy; repeated terms are kept once
+#[derive(InputObject)]
+pub struct CategoryEntryInput {
+    pub taxonomy_id: Uuid,
+    pub term_ids: Vec<Uuid>,
+}
+
+impl CategoryEntryInput {
+    /// A taxonomy listed twice has its terms merged
+    ///
+    /// # Errors
+    /// An entry without terms, as a field error under `categories.<taxonomyId>`
+    pub fn into_categories(entries: Vec<Self>) -> Result<TermSelection, DomainError> {
+        TermSelection::new(
+            "categories",
+            entries.into_iter().map(|entry| {
+                (
+                    TaxonomyId::from_uuid(entry.taxonomy_id),
+                    entry.term_ids.into_iter().map(TermId::from_uuid),
+                )
+            }),
+        )
+    }
+}
+
 #[derive(InputObject)]
 pub struct CreateBusinessInput {
     pub name: String,
@@ -51,11 +197,12 @@ pub struct CreateBusinessInput {
     pub features: Option<BusinessFeaturesInput>,
 }
 
+/// Fields left out are unchanged, every field but `name` is cleared when set to `null`
 #[derive(InputObject)]
 pub struct UpdateBusinessInput {
     pub name: Option<String>,
-    pub description: Option<String>,
-    pub contact_info: Option<ContactInfoInput>,
-    pub social_media: Option<SocialMediaInput>,
-    pub features: Option<BusinessFeaturesInput>,
+    pub description: MaybeUndefined<String>,
+    pub contact_info: MaybeUndefined<ContactInfoInput>,
+    pub social_media: MaybeUndefined<SocialMediaInput>,
+    pub features: MaybeUndefined<BusinessFeaturesInput>,
 }
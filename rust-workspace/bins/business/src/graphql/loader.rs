@@ -0,0 +1,67 @@
+use std::{collections::HashMap, sync::Arc};
+
+use async_graphql::dataloader::{DataLoader, HashMapCache, Loader};
+use corelib::predule::{Business as DomainBusiness, BusinessId, DomainError, UserId};
+
+use crate::setup::state::{AppState, IBusinessservice};
+
+/// Request-scoped, caching loader stored in the GraphQL context
+pub type BusinessDataLoader = DataLoader<BusinessLoader, HashMapCache>;
+
+/// Batches every `BusinessId` (or member `UserId`) asked for while resolving a request into one
+/// `find_by_ids` (or `find_by_members`) call
+pub struct BusinessLoader {
+    business_service: Arc<IBusinessservice>,
+}
+
+impl Loader<BusinessId> for BusinessLoader {
+    type Value = DomainBusiness;
+    type Error = Arc<DomainError>;
+
+    async fn load(
+        &self,
+        keys: &[BusinessId],
+    ) -> Result<HashMap<BusinessId, DomainBusiness>, Self::Error> {
+        let found = self.business_service.find_by_ids(keys).await?;
+        Ok(found
+            .into_iter()
+            .map(|business| (business.id, business))
+            .collect())
+    }
+}
+
+impl Loader<UserId> for BusinessLoader {
+    type Value = Vec<DomainBusiness>;
+    type Error = Arc<DomainError>;
+
+    async fn load(
+        &self,
+        keys: &[UserId],
+    ) -> Result<HashMap<UserId, Vec<DomainBusiness>>, Self::Error> {
+        let found = self.business_service.find_by_members(keys).await?;
+
+        let mut by_member: HashMap<UserId, Vec<DomainBusiness>> = HashMap::new();
+        for business in found {
+            for member in business.members.members() {
+                if keys.contains(&member.user_id()) {
+                    by_member
+                        .entry(member.user_id())
+                        .or_default()
+                        .push(business.clone());
+                }
+            }
+        }
+        Ok(by_member)
+    }
+}
+
+/// Builds an empty loader; called once per request so nothing is cached across requests
+pub fn business_loader(app_state: &AppState) -> BusinessDataLoader {
+    DataLoader::with_cache(
+        BusinessLoader {
+            business_service: app_state.business_service.clone(),
+        },
+        tokio::spawn,
+        HashMapCache::default(),
+    )
+}
@@ -1,23 +1,44 @@
 use std::collections::HashMap;
 
 use async_graphql::{Context, Object, Result};
+use bin_shared_kernel::predule::IntoPatch;
 use corelib::predule::{
-    BusinessDescription, BusinessFeatures, BusinessHourEntry, BusinessId, BusinessName,
-    ContactInfo, DomainError, EmailAddress, ExtraFeatureKey, ExtraFeatureValue, PhoneNumber,
-    PhysicalAddress, ServiceName, SocialMedia, SocialMediaLink, SocialPlatformName, Tag,
-    WebsiteUrl,
+    BusinessChanges, BusinessDescription, BusinessFeatures, BusinessId, BusinessName, ContactInfo,
+    DomainError, EmailAddress, ExtraFeatureKey, ExtraFeatureValue, IdentityId, PhoneNumber,
+    ServiceName, SocialMedia, SocialMediaLink, SocialPlatformName, Tag, UserId, WebsiteUrl,
 };
+use subgraph_runtime::predule::{ADMIN_ROLE, AuthenticatedIdentity, RoleGuard};
 use uuid::Uuid;
 
-use crate::graphql::inputs::{CreateBusinessInput, UpdateBusinessInput};
-use crate::graphql::types::Business;
+use crate::graphql::inputs::{
+    AddressInput, BusinessHoursInput, CategoryEntryInput, CreateBusinessInput, UpdateBusinessInput,
+};
+use crate::graphql::types::{Business, ClaimMethod, MemberRole, RequestedBusinessClaim};
 use crate::setup::state::AppState;
 
 pub struct Mutation;
 
+/// User of the calling identity, `None` for anonymous callers and identities without a user
+pub(crate) async fn calling_user(ctx: &Context<'_>) -> Result<Option<UserId>> {
+    let Some(identity) = ctx.data_opt::<AuthenticatedIdentity>() else {
+        return Ok(None);
+    };
+    let app_state = ctx.data::<AppState>().expect("AppState not initialized");
+
+    let identity_id = IdentityId::new(identity.id.as_str())?;
+    Ok(app_state.business_service.user_of(&identity_id).await?)
+}
+
+/// User of the calling identity, for mutations only a user can make
+async fn acting_user(ctx: &Context<'_>) -> Result<UserId> {
+    calling_user(ctx).await?.ok_or_else(|| {
+        DomainError::forbidden("requires an authenticated identity with a user").into()
+    })
+}
+
 #[Object]
 impl Mutation {
-    /// Create a Business
+    /// Create a Business owned by the calling User, anonymous callers list one nobody manages yet
     async fn create_business<'ctx>(
         &self,
         ctx: &Context<'ctx>,
@@ -25,7 +46,7 @@ impl Mutation {
     ) -> Result<Business> {
         let app_state = ctx.data::<AppState>().expect("AppState not initialized");
 
-        let mut business_service = app_state.business_service.lock().await;
+        let business_service = &app_state.business_service;
 
         let business_name = BusinessName::new(input.name)?;
 
@@ -37,7 +58,7 @@ impl Mutation {
         let business_contact_info = if let Some(ci) = input.contact_info {
             let email = ci.email.map(EmailAddress::new).transpose()?;
             let phone = ci.phone.map(PhoneNumber::new).transpose()?;
-            let address = ci.address.map(PhysicalAddress::new).transpose()?;
+            let address = ci.address.map(AddressInput::into_address).transpose()?;
             let website = ci.website.map(WebsiteUrl::new).transpose()?;
 
             Some(ContactInfo::new(email, phone, address, website))
@@ -78,17 +99,8 @@ impl Mutation {
         };
 
         let business_features = if let Some(f) = input.features {
-            // hours → Option<Vec<BusinessHourEntry>>
-            let hours = if let Some(hours_vec) = f.hours {
-                Some(
-                    hours_vec
-                        .into_iter()
-                        .map(|h| BusinessHourEntry::new(h.day, h.hours))
-                        .collect::<Result<Vec<_>, _>>()?,
-                )
-            } else {
-                None
-            };
+            // hours → Option<BusinessHours>
+            let hours = f.hours.map(BusinessHoursInput::into_hours).transpose()?;
 
             // services → Option<Vec<ServiceName>>
             let services = if let Some(svc_vec) = f.services {
@@ -144,6 +156,7 @@ impl Mutation {
                 business_contact_info,
                 business_social_media,
                 business_features,
+                calling_user(ctx).await?,
             )
             .await?;
 
@@ -152,7 +165,7 @@ impl Mutation {
         Ok(business)
     }
 
-    /// Update a Business
+    /// Update a Business, only its members may once it has an owner and only they may change its contact info
     async fn update_business<'ctx>(
         &self,
         ctx: &Context<'ctx>,
@@ -161,136 +174,394 @@ impl Mutation {
     ) -> Result<Business> {
         let app_state = ctx.data::<AppState>().expect("AppState not initialized");
 
-        let mut business_service = app_state.business_service.lock().await;
+        let business_service = &app_state.business_service;
 
         let b_id = BusinessId::from_uuid(business_id);
 
         let business_name = input.name.map(BusinessName::new).transpose()?;
         let business_description = input
             .description
+            .into_patch()
             .map(BusinessDescription::new)
             .transpose()?;
 
-        let business_contact_info = if let Some(ci) = input.contact_info {
-            let email = ci.email.map(EmailAddress::new).transpose()?;
-            let phone = ci.phone.map(PhoneNumber::new).transpose()?;
-            let address = ci.address.map(PhysicalAddress::new).transpose()?;
-            let website = ci.website.map(WebsiteUrl::new).transpose()?;
+        let business_contact_info = input
+            .contact_info
+            .into_patch()
+            .map(|ci| -> Result<ContactInfo, DomainError> {
+                let email = ci.email.map(EmailAddress::new).transpose()?;
+                let phone = ci.phone.map(PhoneNumber::new).transpose()?;
+                let address = ci.address.map(AddressInput::into_address).transpose()?;
+                let website = ci.website.map(WebsiteUrl::new).transpose()?;
+
+                Ok(ContactInfo::new(email, phone, address, website))
+            })
+            .transpose()?;
 
-            Some(ContactInfo::new(email, phone, address, website))
-        } else {
-            None
+        let business_social_media = input
+            .social_media
+            .into_patch()
+            .map(|sm| -> Result<SocialMedia, DomainError> {
+                let facebook = sm.facebook.map(SocialMediaLink::new).transpose()?;
+                let instagram = sm.instagram.map(SocialMediaLink::new).transpose()?;
+                let twitter = sm.twitter.map(SocialMediaLink::new).transpose()?;
+                let tiktok = sm.tiktok.map(SocialMediaLink::new).transpose()?;
+                let linkedin = sm.linkedin.map(SocialMediaLink::new).transpose()?;
+                let youtube = sm.youtube.map(SocialMediaLink::new).transpose()?;
+
+                // Handle social_media.other (HashMap<String, String> → HashMap<PlatformName, Link>)
+                let other = sm
+                    .other
+                    .map(|other_map| {
+                        other_map
+                            .into_iter()
+                            .map(|(k, v)| {
+                                Ok::<_, DomainError>((
+                                    SocialPlatformName::new(k)?,
+                                    SocialMediaLink::new(v)?,
+                                ))
+                            })
+                            .collect::<Result<HashMap<_, _>, _>>()
+                    })
+                    .transpose()?;
+
+                Ok(SocialMedia::new(
+                    facebook, instagram, twitter, tiktok, linkedin, youtube, other,
+                ))
+            })
+            .transpose()?;
+
+        let business_features = input
+            .features
+            .into_patch()
+            .map(|f| -> Result<BusinessFeatures, DomainError> {
+                // hours → Option<BusinessHours>
+                let hours = f.hours.map(BusinessHoursInput::into_hours).transpose()?;
+
+                // services → Option<Vec<ServiceName>>
+                let services = f
+                    .services
+                    .map(|svc_vec| {
+                        svc_vec
+                            .into_iter()
+                            .map(ServiceName::new)
+                            .collect::<Result<Vec<_>, _>>()
+                    })
+                    .transpose()?;
+
+                // tags → Option<Vec<Tag>>
+                let tags = f
+                    .tags
+                    .map(|tag_vec| {
+                        tag_vec
+                            .into_iter()
+                            .map(Tag::new)
+                            .collect::<Result<Vec<_>, _>>()
+                    })
+                    .transpose()?;
+
+                // extra → Option<HashMap<ExtraFeatureKey, ExtraFeatureValue>>
+                let extras = f
+                    .extra
+                    .map(|extra_map| {
+                        extra_map
+                            .into_iter()
+                            .map(|(k, v)| {
+                                Ok::<_, DomainError>((
+                                    ExtraFeatureKey::new(k)?,
+                                    ExtraFeatureValue::new(v)?,
+                                ))
+                            })
+                            .collect::<Result<HashMap<_, _>, _>>()
+                    })
+                    .transpose()?;
+
+                Ok(BusinessFeatures::new(hours, services, tags, extras))
+            })
+            .transpose()?;
+
+        // Save business via service
+        let changes = BusinessChanges {
+            name: business_name,
+            description: business_description,
+            contact_info: business_contact_info,
+            social_media: business_social_media,
+            features: business_features,
         };
+        let domain_business = business_service
+            .update_business(b_id, calling_user(ctx).await?, changes)
+            .await?;
 
-        let business_social_media = if let Some(sm) = input.social_media {
-            let facebook = sm.facebook.map(SocialMediaLink::new).transpose()?;
-            let instagram = sm.instagram.map(SocialMediaLink::new).transpose()?;
-            let twitter = sm.twitter.map(SocialMediaLink::new).transpose()?;
-            let tiktok = sm.tiktok.map(SocialMediaLink::new).transpose()?;
-            let linkedin = sm.linkedin.map(SocialMediaLink::new).transpose()?;
-            let youtube = sm.youtube.map(SocialMediaLink::new).transpose()?;
+        let business = Business::from(domain_business);
 
-            // Handle social_media.other (HashMap<String, String> → HashMap<PlatformName, Link>)
-            let other = if let Some(other_map) = sm.other {
-                Some(
-                    other_map
-                        .into_iter()
-                        .map(|(k, v)| {
-                            Ok::<_, DomainError>((
-                                SocialPlatformName::new(k)?,
-                                SocialMediaLink::new(v)?,
-                            ))
-                        })
-                        .collect::<Result<HashMap<_, _>, _>>()?,
-                )
-            } else {
-                None
-            };
+        Ok(business)
+    }
 
-            Some(SocialMedia::new(
-                facebook, instagram, twitter, tiktok, linkedin, youtube, other,
-            ))
-        } else {
-            None
-        };
+    /// Replace the categories of a Business, an empty list clears them; only its members may once it has an owner
+    async fn set_business_categories<'ctx>(
+        &self,
+        ctx: &Context<'ctx>,
+        business_id: Uuid,
+        categories: Vec<CategoryEntryInput>,
+    ) -> Result<Business> {
+        let app_state = ctx.data::<AppState>().expect("AppState not initialized");
 
-        let business_features = if let Some(f) = input.features {
-            // hours → Option<Vec<BusinessHourEntry>>
-            let hours = if let Some(hours_vec) = f.hours {
-                Some(
-                    hours_vec
-                        .into_iter()
-                        .map(|h| BusinessHourEntry::new(h.day, h.hours))
-                        .collect::<Result<Vec<_>, _>>()?,
-                )
-            } else {
-                None
-            };
+        let business_service = &app_state.business_service;
 
-            // services → Option<Vec<ServiceName>>
-            let services = if let Some(svc_vec) = f.services {
-                Some(
-                    svc_vec
-                        .into_iter()
-                        .map(ServiceName::new)
-                        .collect::<Result<Vec<_>, _>>()?,
-                )
-            } else {
-                None
-            };
+        let b_id = BusinessId::from_uuid(business_id);
+        let categories = CategoryEntryInput::into_categories(categories)?;
 
-            // tags → Option<Vec<Tag>>
-            let tags = if let Some(tag_vec) = f.tags {
-                Some(
-                    tag_vec
-                        .into_iter()
-                        .map(Tag::new)
-                        .collect::<Result<Vec<_>, _>>()?,
-                )
-            } else {
-                None
-            };
+        let domain_business = business_service
+            .set_categories(b_id, calling_user(ctx).await?, categories)
+            .await?;
 
-            // extra → Option<HashMap<ExtraFeatureKey, ExtraFeatureValue>>
-            let extras = if let Some(extra_map) = f.extra {
-                Some(
-                    extra_map
-                        .into_iter()
-                        .map(|(k, v)| {
-                            Ok::<_, DomainError>((
-                                ExtraFeatureKey::new(k)?,
-                                ExtraFeatureValue::new(v)?,
-                            ))
-                        })
-                        .collect::<Result<HashMap<_, _>, _>>()?,
-                )
-            } else {
-                None
-            };
+        Ok(Business::from(domain_business))
+    }
 
-            Some(BusinessFeatures::new(hours, services, tags, extras))
-        } else {
-            None
-        };
+    /// Invite a User to join a Business as an admin or editor, on behalf of the calling member
+    async fn invite_business_member<'ctx>(
+        &self,
+        ctx: &Context<'ctx>,
+        business_id: Uuid,
+        user_id: Uuid,
+        role: MemberRole,
+    ) -> Result<Business> {
+        let app_state = ctx.data::<AppState>().expect("AppState not initialized");
+
+        let business_service = &app_state.business_service;
+
+        let b_id = BusinessId::from_uuid(business_id);
 
-        // Save business via service
         let domain_business = business_service
-            .update_business(
+            .invite_member(
                 b_id,
-                business_name,
-                business_description,
-                business_contact_info,
-                business_social_media,
-                business_features,
+                acting_user(ctx).await?,
+                UserId::from_uuid(user_id),
+                role.into(),
             )
             .await?;
 
-        let business = Business::from(domain_business);
+        Ok(Business::from(domain_business))
+    }
 
-        Ok(business)
+    /// Accept the invitation of the calling User, who becomes a member
+    async fn accept_business_invitation<'ctx>(
+        &self,
+        ctx: &Context<'ctx>,
+        business_id: Uuid,
+    ) -> Result<Business> {
+        let app_state = ctx.data::<AppState>().expect("AppState not initialized");
+
+        let business_service = &app_state.business_service;
+
+        let b_id = BusinessId::from_uuid(business_id);
+
+        let domain_business = business_service
+            .accept_invitation(b_id, acting_user(ctx).await?)
+            .await?;
+
+        Ok(Business::from(domain_business))
+    }
+
+    /// Decline the invitation of the calling User
+    async fn decline_business_invitation<'ctx>(
+        &self,
+        ctx: &Context<'ctx>,
+        business_id: Uuid,
+    ) -> Result<Business> {
+        let app_state = ctx.data::<AppState>().expect("AppState not initialized");
+
+        let business_service = &app_state.business_service;
+
+        let b_id = BusinessId::from_uuid(business_id);
+
+        let domain_business = business_service
+            .decline_invitation(b_id, acting_user(ctx).await?)
+            .await?;
+
+        Ok(Business::from(domain_business))
+    }
+
+    /// Withdraw the invitation of a User
+    async fn revoke_business_invitation<'ctx>(
+        &self,
+        ctx: &Context<'ctx>,
+        business_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<Business> {
+        let app_state = ctx.data::<AppState>().expect("AppState not initialized");
+
+        let business_service = &app_state.business_service;
+
+        let b_id = BusinessId::from_uuid(business_id);
+
+        let domain_business = business_service
+            .revoke_invitation(b_id, acting_user(ctx).await?, UserId::from_uuid(user_id))
+            .await?;
+
+        Ok(Business::from(domain_business))
+    }
+
+    /// Give a member another role, short of owner
+    async fn change_business_member_role<'ctx>(
+        &self,
+        ctx: &Context<'ctx>,
+        business_id: Uuid,
+        user_id: Uuid,
+        role: MemberRole,
+    ) -> Result<Business> {
+        let app_state = ctx.data::<AppState>().expect("AppState not initialized");
+
+        let business_service = &app_state.business_service;
+
+        let b_id = BusinessId::from_uuid(business_id);
+
+        let domain_business = business_service
+            .change_member_role(
+                b_id,
+                acting_user(ctx).await?,
+                UserId::from_uuid(user_id),
+                role.into(),
+            )
+            .await?;
+
+        Ok(Business::from(domain_business))
+    }
+
+    /// Remove a member, or leave when `userId` is the caller
+    async fn remove_business_member<'ctx>(
+        &self,
+        ctx: &Context<'ctx>,
+        business_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<Business> {
+        let app_state = ctx.data::<AppState>().expect("AppState not initialized");
+
+        let business_service = &app_state.business_service;
+
+        let b_id = BusinessId::from_uuid(business_id);
+
+        let domain_business = business_service
+            .remove_member(b_id, acting_user(ctx).await?, UserId::from_uuid(user_id))
+            .await?;
+
+        Ok(Business::from(domain_business))
+    }
+
+    /// Hand ownership to another member, the calling owner stays on as an admin
+    async fn transfer_business_ownership<'ctx>(
+        &self,
+        ctx: &Context<'ctx>,
+        business_id: Uuid,
+        new_owner_id: Uuid,
+    ) -> Result<Business> {
+        let app_state = ctx.data::<AppState>().expect("AppState not initialized");
+
+        let business_service = &app_state.business_service;
+
+        let b_id = BusinessId::from_uuid(business_id);
+
+        let domain_business = business_service
+            .transfer_ownership(
+                b_id,
+                acting_user(ctx).await?,
+                UserId::from_uuid(new_owner_id),
+            )
+            .await?;
+
+        Ok(Business::from(domain_business))
     }
 
-    /// Soft Delete a Business
+    /// Claim a Business for the calling User, `email` is the address on the contact email's domain to mail the code to
+    async fn request_business_claim<'ctx>(
+        &self,
+        ctx: &Context<'ctx>,
+        business_id: Uuid,
+        method: ClaimMethod,
+        email: Option<String>,
+    ) -> Result<RequestedBusinessClaim> {
+        let app_state = ctx.data::<AppState>().expect("AppState not initialized");
+
+        let business_service = &app_state.business_service;
+
+        let b_id = BusinessId::from_uuid(business_id);
+        let email = email.map(EmailAddress::new).transpose()?;
+
+        let domain_business = business_service
+            .request_claim(b_id, acting_user(ctx).await?, method.into(), email)
+            .await?;
+
+        // `EMAIL` codes only ever travel by mail, or the mailbox would prove nothing
+        let website_code = domain_business
+            .claim
+            .as_ref()
+            .filter(|_| method == ClaimMethod::Website)
+            .map(|claim| claim.code().to_string());
+
+        Ok(RequestedBusinessClaim {
+            business: Business::from(domain_business),
+            website_code,
+        })
+    }
+
+    /// Prove the calling User's claim, with the mailed `code` for `EMAIL` claims; `WEBSITE` claims are checked against the website
+    async fn prove_business_claim<'ctx>(
+        &self,
+        ctx: &Context<'ctx>,
+        business_id: Uuid,
+        code: Option<String>,
+    ) -> Result<Business> {
+        let app_state = ctx.data::<AppState>().expect("AppState not initialized");
+
+        let business_service = &app_state.business_service;
+
+        let b_id = BusinessId::from_uuid(business_id);
+
+        let domain_business = business_service
+            .prove_claim(b_id, acting_user(ctx).await?, code)
+            .await?;
+
+        Ok(Business::from(domain_business))
+    }
+
+    /// Approve a proven claim, verifying the Business and making the claimant an owner
+    #[graphql(guard = "RoleGuard::new(ADMIN_ROLE)")]
+    async fn approve_business_claim<'ctx>(
+        &self,
+        ctx: &Context<'ctx>,
+        business_id: Uuid,
+    ) -> Result<Business> {
+        let app_state = ctx.data::<AppState>().expect("AppState not initialized");
+
+        let business_service = &app_state.business_service;
+
+        let b_id = BusinessId::from_uuid(business_id);
+
+        let domain_business = business_service.approve_claim(b_id).await?;
+
+        Ok(Business::from(domain_business))
+    }
+
+    /// Reject a proven claim
+    #[graphql(guard = "RoleGuard::new(ADMIN_ROLE)")]
+    async fn reject_business_claim<'ctx>(
+        &self,
+        ctx: &Context<'ctx>,
+        business_id: Uuid,
+        reason: String,
+    ) -> Result<Business> {
+        let app_state = ctx.data::<AppState>().expect("AppState not initialized");
+
+        let business_service = &app_state.business_service;
+
+        let b_id = BusinessId::from_uuid(business_id);
+
+        let domain_business = business_service.reject_claim(b_id, reason).await?;
+
+        Ok(Business::from(domain_business))
+    }
+
+    /// Soft Delete a Business, only its owners may once it has one
     async fn soft_delete_business<'ctx>(
         &self,
         ctx: &Context<'ctx>,
@@ -298,16 +569,18 @@ impl Mutation {
     ) -> Result<Uuid> {
         let app_state = ctx.data::<AppState>().expect("AppState not initialized");
 
-        let mut business_service = app_state.business_service.lock().await;
+        let business_service = &app_state.business_service;
 
         let b_id = BusinessId::from_uuid(business_id);
 
-        let domain_business = business_service.soft_delete_business(b_id).await?;
+        let domain_business = business_service
+            .soft_delete_business(b_id, calling_user(ctx).await?)
+            .await?;
 
         Ok(domain_business.id.as_uuid())
     }
 
-    /// Permanetly Delete a Business
+    /// Permanetly Delete a Business, only its owners may once it has one
     async fn permanetly_delete_business<'ctx>(
         &self,
         ctx: &Context<'ctx>,
@@ -315,11 +588,13 @@ impl Mutation {
     ) -> Result<&'static str> {
         let app_state = ctx.data::<AppState>().expect("AppState not initialized");
 
-        let mut business_service = app_state.business_service.lock().await;
+        let business_service = &app_state.business_service;
 
         let b_id = BusinessId::from_uuid(business_id);
 
-        business_service.permanetly_delete_business(b_id).await?;
+        business_service
+            .permanetly_delete_business(b_id, calling_user(ctx).await?)
+            .await?;
 
         Ok("deleted!")
     }
@@ -1,10 +1,16 @@
 use std::str::FromStr;
 
 use async_graphql::*;
-use corelib::predule::BusinessId;
+use corelib::predule::{BusinessId, GeoArea, GeoBounds};
+use subgraph_runtime::predule::{ADMIN_ROLE, RoleGuard};
 use uuid::Uuid;
 
-use crate::{graphql::types::Business, setup::state::AppState};
+use crate::graphql::{
+    inputs::{CategoryEntryInput, GeoPointInput},
+    loader::BusinessDataLoader,
+    types::{Business, NearbyBusiness, User},
+};
+use crate::setup::state::AppState;
 
 pub struct Query;
 
@@ -13,44 +19,121 @@ impl Query {
     /// Get a single Business Entity
     #[graphql(entity)]
     async fn by_id<'ctx>(&self, ctx: &Context<'ctx>, id: ID) -> Result<Option<Business>, Error> {
-        let app_state = ctx
-            .data::<AppState>()
-            .map_err(|_| Error::new("AppState not available"))?;
-
-        let business_service = app_state.business_service.lock().await;
+        let loader = ctx
+            .data::<BusinessDataLoader>()
+            .map_err(|_| Error::new("BusinessDataLoader not available"))?;
 
         let b_id = Uuid::from_str(id.as_str())?;
-        let business_id = BusinessId::from_uuid(b_id);
-        let domain_business = business_service.find_by_id(&business_id).await?;
+        let domain_business = loader.load_one(BusinessId::from_uuid(b_id)).await?;
 
         Ok(domain_business.map(Business::from))
     }
+    /// Resolve a User entity, the router asks for its businesses here
+    #[graphql(entity)]
+    async fn user_by_id(&self, id: ID) -> User {
+        User { id }
+    }
+
     /// Get a single Business
     async fn get_business<'ctx>(
         &self,
         ctx: &Context<'ctx>,
         id: ID,
     ) -> Result<Option<Business>, Error> {
-        let app_state = ctx
-            .data::<AppState>()
-            .map_err(|_| Error::new("AppState not available"))?;
-
-        let business_service = app_state.business_service.lock().await;
+        let loader = ctx
+            .data::<BusinessDataLoader>()
+            .map_err(|_| Error::new("BusinessDataLoader not available"))?;
 
         let b_id = Uuid::from_str(id.as_str())?;
-        let business_id = BusinessId::from_uuid(b_id);
-        let domain_business = business_service.find_by_id(&business_id).await?;
+        let domain_business = loader.load_one(BusinessId::from_uuid(b_id)).await?;
 
         Ok(domain_business.map(Business::from))
     }
 
+    /// Businesses whose claim was proven and waits for an admin, oldest first
+    #[graphql(guard = "RoleGuard::new(ADMIN_ROLE)")]
+    async fn business_claim_queue<'ctx>(
+        &self,
+        ctx: &Context<'ctx>,
+        #[graphql(default = 20, validator(minimum = 1, maximum = 100))] limit: i32,
+        #[graphql(default = 0, validator(minimum = 0))] offset: i32,
+    ) -> Result<Vec<Business>, Error> {
+        let app_state = ctx.data::<AppState>().expect("AppState not initialized");
+
+        let businesses = app_state
+            .business_service
+            .pending_claims(limit as usize, offset as usize)
+            .await?;
+
+        Ok(businesses.into_iter().map(Business::from).collect())
+    }
+
+    /// Businesses within `radiusKm` of a point, nearest first
+    async fn businesses_near<'ctx>(
+        &self,
+        ctx: &Context<'ctx>,
+        center: GeoPointInput,
+        radius_km: f64,
+        #[graphql(default = 20, validator(minimum = 1, maximum = 100))] limit: i32,
+        #[graphql(default = 0, validator(minimum = 0))] offset: i32,
+    ) -> Result<Vec<NearbyBusiness>, Error> {
+        let app_state = ctx.data::<AppState>().expect("AppState not initialized");
+
+        let area = GeoArea::radius(center.into_point("center")?, radius_km)?;
+        let found = app_state
+            .business_service
+            .find_in_area(&area, limit as usize, offset as usize)
+            .await?;
+
+        Ok(found.into_iter().map(NearbyBusiness::from).collect())
+    }
+
+    /// Businesses inside a box, nearest to its center first
+    ///
+    /// A `southWest` longitude east of the `northEast` one wraps across the antimeridian.
+    async fn businesses_in_bounds<'ctx>(
+        &self,
+        ctx: &Context<'ctx>,
+        south_west: GeoPointInput,
+        north_east: GeoPointInput,
+        #[graphql(default = 20, validator(minimum = 1, maximum = 100))] limit: i32,
+        #[graphql(default = 0, validator(minimum = 0))] offset: i32,
+    ) -> Result<Vec<NearbyBusiness>, Error> {
+        let app_state = ctx.data::<AppState>().expect("AppState not initialized");
+
+        let bounds = GeoBounds::new(
+            south_west.into_point("southWest")?,
+            north_east.into_point("northEast")?,
+        )?;
+        let found = app_state
+            .business_service
+            .find_in_area(&GeoArea::Bounds(bounds), limit as usize, offset as usize)
+            .await?;
+
+        Ok(found.into_iter().map(NearbyBusiness::from).collect())
+    }
+
     /// Get many  Business
     async fn get_businesss(&self) -> &'static str {
         "get businesss"
     }
 
-    /// Search  Business
-    async fn search_businesss(&self) -> &'static str {
-        "search businesss"
+    /// Businesses filed under at least one of the given terms in every given taxonomy, oldest first
+    async fn search_businesses<'ctx>(
+        &self,
+        ctx: &Context<'ctx>,
+        #[graphql(default)] categories: Vec<CategoryEntryInput>,
+        #[graphql(default = 20, validator(minimum = 1, maximum = 100))] limit: i32,
+        #[graphql(default = 0, validator(minimum = 0))] offset: i32,
+    ) -> Result<Vec<Business>, Error> {
+        let app_state = ctx.data::<AppState>().expect("AppState not initialized");
+
+        let categories = CategoryEntryInput::into_categories(categories)?;
+        let businesses = app_state
+            .business_service
+            .search(categories, limit as usize, offset as usize)
+            .await?;
+
+        Ok(businesses.into_iter().map(Business::from).collect())
     }
 }
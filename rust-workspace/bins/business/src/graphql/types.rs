@@ -1,22 +1,66 @@
 use std::collections::HashMap;
 
+use async_graphql::ComplexObject;
+use async_graphql::Context;
+use async_graphql::Enum;
 use async_graphql::ID;
+use async_graphql::Result;
 use async_graphql::SimpleObject;
+use bin_shared_kernel::predule::TaxonomyRef;
+use bin_shared_kernel::predule::TermRef;
+use chrono::{DateTime, NaiveDate, NaiveTime, Utc};
 use corelib::predule::Business as DomainBusiness;
+use corelib::predule::BusinessClaim as DomainBusinessClaim;
 use corelib::predule::BusinessFeatures as DomainBusinessFeatures;
-use corelib::predule::BusinessHourEntry as DomainBusinessHourEntry;
+use corelib::predule::BusinessHours as DomainBusinessHours;
+use corelib::predule::BusinessVerification as DomainBusinessVerification;
+use corelib::predule::ClaimMethod as DomainClaimMethod;
+use corelib::predule::ClaimStatus as DomainClaimStatus;
 use corelib::predule::ContactInfo as DomainContactInfo;
+use corelib::predule::DomainError;
+use corelib::predule::GeoPoint as DomainGeoPoint;
+use corelib::predule::HoursException as DomainHoursException;
+use corelib::predule::Invitation as DomainInvitation;
+use corelib::predule::MemberRole as DomainMemberRole;
+use corelib::predule::Membership as DomainMembership;
+use corelib::predule::Nearby;
+use corelib::predule::PhysicalAddress as DomainPhysicalAddress;
 use corelib::predule::SocialMedia as DomainSocialMedia;
+use corelib::predule::TermSelection;
+use corelib::predule::TimeRange as DomainTimeRange;
+use corelib::predule::UserId;
+use subgraph_runtime::predule::{ADMIN_ROLE, AuthenticatedIdentity};
+use uuid::Uuid;
+
+use crate::graphql::loader::BusinessDataLoader;
+use crate::graphql::mutation::calling_user;
 
 
 #[derive(SimpleObject)]
 pub struct ContactInfo {
     pub email: Option<String>,
     pub phone: Option<String>,
-    pub address: Option<String>,
+    pub address: Option<Address>,
     pub website: Option<String>,
 }
 
+/// Coordinates in degrees
+#[derive(SimpleObject)]
+pub struct GeoPoint {
+    pub latitude: f64,
+    pub longitude: f64,
+}
+
+#[derive(SimpleObject)]
+pub struct Address {
+    pub street: String,
+    pub city: String,
+    pub region: Option<String>,
+    pub postal_code: Option<String>,
+    pub country: TermRef,
+    pub location: Option<GeoPoint>,
+}
+
 #[derive(SimpleObject)]
 pub struct SocialMedia {
     pub facebook: Option<String>,
@@ -28,16 +72,101 @@ pub struct SocialMedia {
     pub other: HashMap<String, String>,
 }
 
+#[derive(Enum, Clone, Copy, PartialEq, Eq)]
+pub enum Weekday {
+    Monday,
+    Tuesday,
+    Wednesday,
+    Thursday,
+    Friday,
+    Saturday,
+    Sunday,
+}
+
+impl From<chrono::Weekday> for Weekday {
+    fn from(value: chrono::Weekday) -> Self {
+        match value {
+            chrono::Weekday::Mon => Weekday::Monday,
+            chrono::Weekday::Tue => Weekday::Tuesday,
+            chrono::Weekday::Wed => Weekday::Wednesday,
+            chrono::Weekday::Thu => Weekday::Thursday,
+            chrono::Weekday::Fri => Weekday::Friday,
+            chrono::Weekday::Sat => Weekday::Saturday,
+            chrono::Weekday::Sun => Weekday::Sunday,
+        }
+    }
+}
+
+impl From<Weekday> for chrono::Weekday {
+    fn from(value: Weekday) -> Self {
+        match value {
+            Weekday::Monday => chrono::Weekday::Mon,
+            Weekday::Tuesday => chrono::Weekday::Tue,
+            Weekday::Wednesday => chrono::Weekday::Wed,
+            Weekday::Thursday => chrono::Weekday::Thu,
+            Weekday::Friday => chrono::Weekday::Fri,
+            Weekday::Saturday => chrono::Weekday::Sat,
+            Weekday::Sunday => chrono::Weekday::Sun,
+        }
+    }
+}
+
+/// Opening interval in local time; it runs past midnight when `closes` is not after `opens`
 #[derive(SimpleObject)]
-pub struct BusinessHourEntry {
-    day: String,
-    hours: String,
+pub struct TimeRange {
+    pub opens: NaiveTime,
+    pub closes: NaiveTime,
+    pub overnight: bool,
+}
+
+/// Opening intervals of one weekday, closed when empty
+#[derive(SimpleObject)]
+pub struct DayHours {
+    pub weekday: Weekday,
+    pub intervals: Vec<TimeRange>,
+}
+
+/// Hours of one date that replace the weekly schedule, e.g. a holiday
+#[derive(SimpleObject)]
+pub struct HoursException {
+    pub date: NaiveDate,
+    /// Empty when closed all day
+    pub intervals: Vec<TimeRange>,
+    pub closed: bool,
+    pub note: Option<String>,
+}
+
+/// Weekly opening hours with dated exceptions, in the business's timezone
+#[derive(SimpleObject)]
+#[graphql(complex)]
+pub struct BusinessHours {
+    /// IANA timezone, e.g. `Europe/Paris`
+    pub timezone: String,
+    /// Every weekday, Monday first
+    pub weekly: Vec<DayHours>,
+    pub exceptions: Vec<HoursException>,
+
+    #[graphql(skip)]
+    pub hours: DomainBusinessHours,
+}
+
+#[ComplexObject]
+impl BusinessHours {
+    /// Whether the business is open at `at`
+    async fn is_open_at(&self, at: DateTime<Utc>) -> bool {
+        self.hours.is_open_at(at)
+    }
+
+    /// Whether the business is open right now
+    async fn is_open_now(&self) -> bool {
+        self.hours.is_open_at(Utc::now())
+    }
 }
 
 #[derive(SimpleObject)]
 pub struct BusinessFeatures {
-    /// Example: Mon -> "9am–5pm"
-    pub hours: Option<Vec<BusinessHourEntry>>,
+    /// Opening hours in the business's timezone
+    pub hours: Option<BusinessHours>,
 
     /// Services provided by the business
     pub services: Vec<String>,
@@ -58,6 +187,14 @@ pub struct Business {
     pub contact_info: Option<ContactInfo>,
     pub social_media: Option<SocialMedia>,
     pub features: Option<BusinessFeatures>,
+    /// Catalogue terms the Business is filed under, one entry per taxonomy
+    pub categories: Vec<BusinessCategory>,
+    pub members: Vec<BusinessMember>,
+    /// Invitations not yet accepted or declined
+    pub invitations: Vec<BusinessInvitation>,
+    pub verification: BusinessVerification,
+    /// Latest claim that was not approved, if any
+    pub claim: Option<BusinessClaim>,
 
     pub created_at: String,
     pub updated_at: String,
@@ -66,16 +203,58 @@ pub struct Business {
     pub version: u64,
 }
 
+/// Terms a Business is filed under in one taxonomy, e.g. its amenities
+#[derive(SimpleObject)]
+pub struct BusinessCategory {
+    pub taxonomy: TaxonomyRef,
+    pub terms: Vec<TermRef>,
+}
+
+impl BusinessCategory {
+    /// One entry per taxonomy, in the stable order `TermSelection` keeps
+    pub fn from_categories(categories: &TermSelection) -> Vec<Self> {
+        categories
+            .iter()
+            .map(|(taxonomy_id, term_ids)| Self {
+                taxonomy: (*taxonomy_id).into(),
+                terms: term_ids.iter().copied().map(TermRef::from).collect(),
+            })
+            .collect()
+    }
+}
+
 impl From<DomainContactInfo> for ContactInfo {
     fn from(value: DomainContactInfo) -> Self {
         Self {
             email: value.email.map(|v| v.as_str().to_string()),
             phone: value.phone.map(|v| v.as_str().to_string()),
-            address: value.address.map(|v| v.as_str().to_string()),
+            address: value.address.map(Address::from),
             website: value.website.map(|v| v.as_str().to_string()),
         }
     }
 }
+impl From<DomainGeoPoint> for GeoPoint {
+    fn from(value: DomainGeoPoint) -> Self {
+        Self {
+            latitude: value.latitude(),
+            longitude: value.longitude(),
+        }
+    }
+}
+
+impl From<DomainPhysicalAddress> for Address {
+    fn from(value: DomainPhysicalAddress) -> Self {
+        Self {
+            street: value.street().to_string(),
+            city: value.city().to_string(),
+            region: value.region().map(str::to_string),
+            postal_code: value.postal_code().map(str::to_string),
+            country: value.country_term_id().into(),
+            location: value.location().map(GeoPoint::from),
+        }
+    }
+}
+
 impl From<DomainSocialMedia> for SocialMedia {
     fn from(value: DomainSocialMedia) -> Self {
         Self {
@@ -100,11 +279,40 @@ impl From<DomainSocialMedia> for SocialMedia {
     }
 }
 
-impl From<DomainBusinessHourEntry> for BusinessHourEntry {
-    fn from(value: DomainBusinessHourEntry) -> Self {
+impl From<&DomainTimeRange> for TimeRange {
+    fn from(value: &DomainTimeRange) -> Self {
         Self {
-            day: value.day().to_string(),
-            hours: value.hours().to_string(),
+            opens: value.opens(),
+            closes: value.closes(),
+            overnight: value.is_overnight(),
+        }
+    }
+}
+
+impl From<&DomainHoursException> for HoursException {
+    fn from(value: &DomainHoursException) -> Self {
+        Self {
+            date: value.date(),
+            intervals: value.intervals().iter().map(TimeRange::from).collect(),
+            closed: value.is_closed(),
+            note: value.note().map(str::to_string),
+        }
+    }
+}
+
+impl From<DomainBusinessHours> for BusinessHours {
+    fn from(value: DomainBusinessHours) -> Self {
+        Self {
+            timezone: value.timezone().name().to_string(),
+            weekly: value
+                .weekly()
+                .map(|(weekday, intervals)| DayHours {
+                    weekday: weekday.into(),
+                    intervals: intervals.iter().map(TimeRange::from).collect(),
+                })
+                .collect(),
+            exceptions: value.exceptions().map(HoursException::from).collect(),
+            hours: value,
         }
     }
 }
@@ -112,9 +320,7 @@ impl From<DomainBusinessHourEntry> for BusinessHourEntry {
 impl From<DomainBusinessFeatures> for BusinessFeatures {
     fn from(value: DomainBusinessFeatures) -> Self {
         Self {
-            hours: value
-                .hours
-                .map(|v| v.into_iter().map(|p| p.into()).collect()),
+            hours: value.hours.map(BusinessHours::from),
             services: value
                 .services
                 .into_iter()
@@ -147,6 +353,21 @@ impl From<DomainBusiness> for Business {
             contact_info: value.contact_info.map(|v| v.into()),
             social_media: value.social_media.map(|v| v.into()),
             features: value.features.map(|v| v.into()),
+            categories: BusinessCategory::from_categories(&value.categories),
+            members: value
+                .members
+                .members()
+                .iter()
+                .map(BusinessMember::from)
+                .collect(),
+            invitations: value
+                .members
+                .invitations()
+                .iter()
+                .map(BusinessInvitation::from)
+                .collect(),
+            verification: value.verification.into(),
+            claim: value.claim.as_ref().map(BusinessClaim::from),
 
             created_at: value.timestamps.created_human(),
             updated_at: value.timestamps.updated_human(),
@@ -156,3 +377,266 @@ impl From<DomainBusiness> for Business {
         }
     }
 }
+
+/// A Business found by a geo search
+#[derive(SimpleObject)]
+pub struct NearbyBusiness {
+    pub business: Business,
+    /// Great-circle distance from the search origin
+    pub distance_km: f64,
+}
+
+impl From<Nearby<DomainBusiness>> for NearbyBusiness {
+    fn from(value: Nearby<DomainBusiness>) -> Self {
+        Self {
+            business: value.value.into(),
+            distance_km: value.distance_km,
+        }
+    }
+}
+
+// `User` of the user subgraph, extended with the businesses it is a member of.
+// Kept free of doc comments so the owner's description wins in the supergraph.
+#[derive(SimpleObject, Clone)]
+#[graphql(complex)]
+pub struct User {
+    pub id: ID,
+}
+
+impl From<UserId> for User {
+    fn from(value: UserId) -> Self {
+        Self {
+            id: value.as_uuid().into(),
+        }
+    }
+}
+
+#[ComplexObject]
+impl User {
+    /// Businesses this User is a member of, optionally only those where they hold `role`
+    async fn businesses<'ctx>(
+        &self,
+        ctx: &Context<'ctx>,
+        role: Option<MemberRole>,
+    ) -> Result<Vec<Business>> {
+        let loader = ctx.data::<BusinessDataLoader>()?;
+
+        let user_id = UserId::from_uuid(Uuid::parse_str(self.id.as_str())?);
+        let businesses = loader.load_one(user_id).await?.unwrap_or_default();
+
+        Ok(businesses
+            .into_iter()
+            .filter(|business| {
+                role.is_none_or(|role| business.members.role_of(&user_id) == Some(role.into()))
+            })
+            .map(Business::from)
+            .collect())
+    }
+}
+
+/// What a member may do on a Business
+///
+/// - `OWNER`: Everything an admin can, manages admins and transfers ownership.
+/// - `ADMIN`: Edits the business and manages editors.
+/// - `EDITOR`: Edits the business.
+#[derive(Enum, Clone, Copy, PartialEq, Eq)]
+pub enum MemberRole {
+    Owner,
+    Admin,
+    Editor,
+}
+
+impl From<DomainMemberRole> for MemberRole {
+    fn from(value: DomainMemberRole) -> Self {
+        match value {
+            DomainMemberRole::Owner => MemberRole::Owner,
+            DomainMemberRole::Admin => MemberRole::Admin,
+            DomainMemberRole::Editor => MemberRole::Editor,
+        }
+    }
+}
+
+impl From<MemberRole> for DomainMemberRole {
+    fn from(value: MemberRole) -> Self {
+        match value {
+            MemberRole::Owner => DomainMemberRole::Owner,
+            MemberRole::Admin => DomainMemberRole::Admin,
+            MemberRole::Editor => DomainMemberRole::Editor,
+        }
+    }
+}
+
+#[derive(SimpleObject)]
+pub struct BusinessMember {
+    pub user: User,
+    pub role: MemberRole,
+    pub joined_at: DateTime<Utc>,
+}
+
+impl From<&DomainMembership> for BusinessMember {
+    fn from(value: &DomainMembership) -> Self {
+        Self {
+            user: value.user_id().into(),
+            role: value.role().into(),
+            joined_at: *value.joined_at(),
+        }
+    }
+}
+
+#[derive(SimpleObject)]
+pub struct BusinessInvitation {
+    pub user: User,
+    /// Role the user gets on accepting
+    pub role: MemberRole,
+    pub invited_by: User,
+    pub invited_at: DateTime<Utc>,
+}
+
+impl From<&DomainInvitation> for BusinessInvitation {
+    fn from(value: &DomainInvitation) -> Self {
+        Self {
+            user: value.user_id().into(),
+            role: value.role().into(),
+            invited_by: value.invited_by().into(),
+            invited_at: *value.invited_at(),
+        }
+    }
+}
+
+/// How a claimant proves they control a Business
+///
+/// - `EMAIL`: A code is mailed to an address on the domain of the business contact email.
+/// - `WEBSITE`: The claimant places the code on the business website.
+#[derive(Enum, Clone, Copy, PartialEq, Eq)]
+pub enum ClaimMethod {
+    Email,
+    Website,
+}
+
+impl From<DomainClaimMethod> for ClaimMethod {
+    fn from(value: DomainClaimMethod) -> Self {
+        match value {
+            DomainClaimMethod::Email => ClaimMethod::Email,
+            DomainClaimMethod::Website => ClaimMethod::Website,
+        }
+    }
+}
+
+impl From<ClaimMethod> for DomainClaimMethod {
+    fn from(value: ClaimMethod) -> Self {
+        match value {
+            ClaimMethod::Email => DomainClaimMethod::Email,
+            ClaimMethod::Website => DomainClaimMethod::Website,
+        }
+    }
+}
+
+#[derive(Enum, Clone, Copy, PartialEq, Eq)]
+pub enum ClaimStatus {
+    AwaitingProof,
+    AwaitingReview,
+    Rejected,
+}
+
+/// A user's request to be recognised as the one running a Business
+#[derive(SimpleObject)]
+#[graphql(complex)]
+pub struct BusinessClaim {
+    pub claimant: User,
+    pub method: ClaimMethod,
+    /// Contact website of the Business when the claim was opened, what `WEBSITE` claims are proven on
+    pub website: Option<String>,
+    /// Domain of the contact email when the claim was opened, what `EMAIL` claims are proven on
+    pub email_domain: Option<String>,
+    pub status: ClaimStatus,
+    /// Why an admin turned the claim down, `REJECTED` claims only
+    pub rejection_reason: Option<String>,
+    pub requested_at: DateTime<Utc>,
+    pub proven_at: Option<DateTime<Utc>>,
+
+    #[graphql(skip)]
+    pub claimant_id: UserId,
+    #[graphql(skip)]
+    pub email: Option<String>,
+}
+
+#[ComplexObject]
+impl BusinessClaim {
+    /// Address the code was mailed to, `EMAIL` claims only; readable by the claimant and admins
+    async fn email<'ctx>(&self, ctx: &Context<'ctx>) -> Result<Option<String>> {
+        let admin = ctx
+            .data_opt::<AuthenticatedIdentity>()
+            .is_some_and(|identity| identity.has_role(ADMIN_ROLE));
+        if !admin && calling_user(ctx).await? != Some(self.claimant_id) {
+            return Err(DomainError::forbidden(
+                "only the claimant or an admin can read the claim email",
+            )
+            .into());
+        }
+        Ok(self.email.clone())
+    }
+}
+
+impl From<&DomainBusinessClaim> for BusinessClaim {
+    fn from(value: &DomainBusinessClaim) -> Self {
+        let (status, rejection_reason) = match value.status() {
+            DomainClaimStatus::AwaitingProof => (ClaimStatus::AwaitingProof, None),
+            DomainClaimStatus::AwaitingReview => (ClaimStatus::AwaitingReview, None),
+            DomainClaimStatus::Rejected { reason } => (ClaimStatus::Rejected, Some(reason.clone())),
+        };
+
+        Self {
+            claimant: value.claimant().into(),
+            method: value.method().into(),
+            website: value.website().map(|v| v.as_str().to_string()),
+            email_domain: value.email_domain().map(str::to_string),
+            status,
+            rejection_reason,
+            requested_at: *value.requested_at(),
+            proven_at: value.proven_at().copied(),
+            claimant_id: value.claimant(),
+            email: value.email().map(|v| v.as_str().to_string()),
+        }
+    }
+}
+
+/// Whether a Business was confirmed by an approved claim
+#[derive(SimpleObject)]
+pub struct BusinessVerification {
+    pub verified: bool,
+    /// User whose claim was approved
+    pub claimant: Option<User>,
+    pub method: Option<ClaimMethod>,
+    pub verified_at: Option<DateTime<Utc>>,
+}
+
+impl From<DomainBusinessVerification> for BusinessVerification {
+    fn from(value: DomainBusinessVerification) -> Self {
+        match value {
+            DomainBusinessVerification::Unverified => Self {
+                verified: false,
+                claimant: None,
+                method: None,
+                verified_at: None,
+            },
+            DomainBusinessVerification::Verified {
+                claimant,
+                method,
+                verified_at,
+            } => Self {
+                verified: true,
+                claimant: Some(claimant.into()),
+                method: Some(method.into()),
+                verified_at: Some(verified_at),
+            },
+        }
+    }
+}
+
+/// A claim just opened, with what the claimant needs to prove it
+#[derive(SimpleObject)]
+pub struct RequestedBusinessClaim {
+    pub business: Business,
+    /// Code to place on the business website, `WEBSITE` claims only; `EMAIL` codes are mailed
+    pub website_code: Option<String>,
+}
@@ -1 +0,0 @@
-pub mod graphiql;
\ No newline at end of file
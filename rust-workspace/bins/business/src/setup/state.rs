@@ -1,21 +1,113 @@
+use std::{sync::Arc, time::Duration};
+
 use corelib::predule::{
-    IBusinessserviceInMemoryBusinessRepository, InMemoryBusinessAuditLogHandler, InMemoryBusinessEventBus, InMemoryBusinessRepository, BusinessService
+    BusinessDomainEventBus, BusinessService, ComponentHealth, DomainError, HealthCheck,
+    HttpClaimCodeMailer, HttpTermSelectionValidator, HttpUserDirectory, HttpWebsiteFetcher,
+    InMemoryBusinessAuditLogHandler, InMemoryBusinessEventBus, InMemoryBusinessRepository,
+    TermSelectionValidator, UserDirectory,
+};
+use env_config::predule::EventBusKind;
+use observability::predule::{
+    InstrumentedBusinessEventBus, InstrumentedBusinessEventHandler, InstrumentedBusinessRepository,
+    Readiness,
 };
-use tokio::sync::Mutex;
+use subgraph_runtime::predule::SubgraphState;
+
+use crate::setup::env::EnvConfig;
+
+/// How long a business website gets to answer when a claim is proven through it
+const WEBSITE_FETCH_TIMEOUT: Duration = Duration::from_secs(10);
+
+pub type IBusinessservice =
+    BusinessService<InstrumentedBusinessRepository<InMemoryBusinessRepository>>;
+
+#[derive(Clone)]
 pub struct AppState {
-    pub business_service: Mutex<IBusinessserviceInMemoryBusinessRepository>,
+    pub env_config: EnvConfig,
+    pub business_service: Arc<IBusinessservice>,
+}
+
+/// Checks categories against the catalog subgraphs over HTTP when `catalog` is configured,
+/// and looks callers up in the user subgraph over HTTP when `directory` is
+pub fn build_state(env_config: &EnvConfig) -> Result<AppState, DomainError> {
+    let category_validator = match &env_config.catalog {
+        Some(catalog) => Some(Box::new(HttpTermSelectionValidator::new(
+            &catalog.taxonomy_url,
+            &catalog.term_url,
+            Duration::from_secs(catalog.timeout_secs),
+        )?) as Box<dyn TermSelectionValidator>),
+        None => None,
+    };
+    let user_directory = match &env_config.directory {
+        Some(directory) => Some(Box::new(HttpUserDirectory::new(
+            &directory.user_url,
+            Duration::from_secs(directory.timeout_secs),
+        )?) as Box<dyn UserDirectory>),
+        None => None,
+    };
+
+    build_state_with_adapters(env_config, category_validator, user_directory)
 }
 
-pub fn build_state() -> AppState {
+/// Builds the state around a given category validator and user directory, e.g.
+/// in-process ones when the other subgraphs run in the same process
+pub fn build_state_with_adapters(
+    env_config: &EnvConfig,
+    category_validator: Option<Box<dyn TermSelectionValidator>>,
+    user_directory: Option<Box<dyn UserDirectory>>,
+) -> Result<AppState, DomainError> {
     // Business
-    let business_repository = InMemoryBusinessRepository::new();
+    let business_repository =
+        InstrumentedBusinessRepository::new(InMemoryBusinessRepository::new());
 
-    let mut business_event_bus = InMemoryBusinessEventBus::new();
-    business_event_bus.with(Box::new(InMemoryBusinessAuditLogHandler));
+    let business_event_bus: Box<dyn BusinessDomainEventBus> = match env_config.event_bus.kind {
+        EventBusKind::InMemory => {
+            let mut bus = InMemoryBusinessEventBus::new();
+            bus.with(Box::new(InstrumentedBusinessEventHandler::new(
+                InMemoryBusinessAuditLogHandler,
+            )));
+            Box::new(bus)
+        }
+    };
 
-    let business_service = BusinessService::new(business_repository).with_bus(Box::new(business_event_bus));
+    let website_fetcher = HttpWebsiteFetcher::new(WEBSITE_FETCH_TIMEOUT)?;
+
+    let mut business_service = BusinessService::new(business_repository)
+        .with_bus(Box::new(InstrumentedBusinessEventBus::new(
+            business_event_bus,
+        )))
+        .with_website_fetcher(Box::new(website_fetcher));
+    if let Some(mail) = &env_config.mail {
+        business_service = business_service.with_claim_mailer(Box::new(HttpClaimCodeMailer::new(
+            &mail.api_url,
+            &mail.api_key,
+            &mail.from,
+            Duration::from_secs(mail.timeout_secs),
+        )?));
+    }
+    if let Some(validator) = category_validator {
+        business_service = business_service.with_category_validator(validator);
+    }
+    if let Some(directory) = user_directory {
+        business_service = business_service.with_user_directory(directory);
+    }
+
+    Ok(AppState {
+        env_config: env_config.clone(),
+        business_service: Arc::new(business_service),
+    })
+}
+
+impl SubgraphState for AppState {
+    async fn shutdown(&self) -> Result<(), DomainError> {
+        self.business_service.shutdown().await
+    }
+}
 
-    AppState {
-        business_service: Mutex::new(business_service),
+impl Readiness for AppState {
+    async fn readiness(&self) -> Vec<ComponentHealth> {
+        let mut components = vec![self.env_config.check().await];
+        components.extend(self.business_service.health().await);
+        components
     }
 }
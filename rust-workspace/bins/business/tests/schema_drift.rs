@@ -0,0 +1,13 @@
+use business::setup::schema::export_sdl;
+use subgraph_runtime::predule::assert_sdl_matches;
+
+#[test]
+fn gateway_schema_matches_generated_sdl() {
+    assert_sdl_matches(
+        concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/../../../gateway/schemas/business.graphql"
+        ),
+        &export_sdl(),
+    );
+}
@@ -1,4 +1,4 @@
-use async_graphql::InputObject;
+use async_graphql::{InputObject, MaybeUndefined};
 use uuid::Uuid;
 
 #[derive(InputObject)]
@@ -9,10 +9,11 @@ pub struct CreateTaxonomyInput {
     pub description: Option<String>,
 }
 
+/// Fields left out are unchanged, `parentId` and `description` are cleared when set to `null`
 #[derive(InputObject)]
 pub struct UpdateTaxonomyInput {
-    pub parent_id: Option<Uuid>,
+    pub parent_id: MaybeUndefined<Uuid>,
     pub name: Option<String>,
     pub visible: Option<bool>,
-    pub description: Option<String>,
+    pub description: MaybeUndefined<String>,
 }
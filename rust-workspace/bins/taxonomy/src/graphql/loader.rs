@@ -0,0 +1,41 @@
+use std::{collections::HashMap, sync::Arc};
+
+use async_graphql::dataloader::{DataLoader, HashMapCache, Loader};
+use corelib::predule::{DomainError, Taxonomy as DomainTaxonomy, TaxonomyId};
+
+use crate::setup::state::{AppState, ITaxonomyservice};
+
+/// Request-scoped, caching loader stored in the GraphQL context
+pub type TaxonomyDataLoader = DataLoader<TaxonomyLoader, HashMapCache>;
+
+/// Batches every `TaxonomyId` asked for while resolving a request into one `find_by_ids` call
+pub struct TaxonomyLoader {
+    taxonomy_service: Arc<ITaxonomyservice>,
+}
+
+impl Loader<TaxonomyId> for TaxonomyLoader {
+    type Value = DomainTaxonomy;
+    type Error = Arc<DomainError>;
+
+    async fn load(
+        &self,
+        keys: &[TaxonomyId],
+    ) -> Result<HashMap<TaxonomyId, DomainTaxonomy>, Self::Error> {
+        let found = self.taxonomy_service.find_by_ids(keys).await?;
+        Ok(found
+            .into_iter()
+            .map(|taxonomy| (taxonomy.id, taxonomy))
+            .collect())
+    }
+}
+
+/// Builds an empty loader; called once per request so nothing is cached across requests
+pub fn taxonomy_loader(app_state: &AppState) -> TaxonomyDataLoader {
+    DataLoader::with_cache(
+        TaxonomyLoader {
+            taxonomy_service: app_state.taxonomy_service.clone(),
+        },
+        tokio::spawn,
+        HashMapCache::default(),
+    )
+}
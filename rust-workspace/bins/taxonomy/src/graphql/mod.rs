@@ -1,3 +1,4 @@
+pub mod loader;
 pub mod mutation;
 pub mod query;
 pub mod types;
@@ -1,4 +1,5 @@
 use async_graphql::{Context, Object, Result};
+use bin_shared_kernel::predule::IntoPatch;
 use corelib::predule::{TaxonomyDescription, TaxonomyId, TaxonomyName};
 use uuid::Uuid;
 
@@ -18,7 +19,7 @@ impl Mutation {
     ) -> Result<Taxonomy> {
         let app_state = ctx.data::<AppState>().expect("AppState not initialized");
 
-        let mut taxonomy_service = app_state.taxonomy_service.lock().await;
+        let taxonomy_service = &app_state.taxonomy_service;
 
         let parent_id = input.parent_id.map(TaxonomyId::from_uuid);
 
@@ -46,20 +47,21 @@ impl Mutation {
     ) -> Result<Taxonomy> {
         let app_state = ctx.data::<AppState>().expect("AppState not initialized");
 
-        let mut taxonomy_service = app_state.taxonomy_service.lock().await;
+        let taxonomy_service = &app_state.taxonomy_service;
 
         let t_id = TaxonomyId::from_uuid(taxonomy_id);
 
-        let parent_id = input.parent_id.map(TaxonomyId::from_uuid);
+        let parent_id = input.parent_id.into_patch().map(TaxonomyId::from_uuid);
 
         let name = input.name.map(TaxonomyName::new).transpose()?;
 
         let description = input
             .description
+            .into_patch()
             .map(TaxonomyDescription::new)
             .transpose()?;
 
-        let visible = input.visible.map(|v| v);
+        let visible = input.visible;
         // Save taxonomy via service
         let domain_taxonomy = taxonomy_service
             .update_taxonomy(t_id, parent_id, name, visible, description)
@@ -78,7 +80,7 @@ impl Mutation {
     ) -> Result<Uuid> {
         let app_state = ctx.data::<AppState>().expect("AppState not initialized");
 
-        let mut taxonomy_service = app_state.taxonomy_service.lock().await;
+        let taxonomy_service = &app_state.taxonomy_service;
 
         let t_id = TaxonomyId::from_uuid(taxonomy_id);
 
@@ -95,7 +97,7 @@ impl Mutation {
     ) -> Result<&'static str> {
         let app_state = ctx.data::<AppState>().expect("AppState not initialized");
 
-        let mut taxonomy_service = app_state.taxonomy_service.lock().await;
+        let taxonomy_service = &app_state.taxonomy_service;
 
         let t_id = TaxonomyId::from_uuid(taxonomy_id);
 
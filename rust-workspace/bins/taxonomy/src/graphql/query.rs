@@ -4,7 +4,7 @@ use async_graphql::*;
 use corelib::predule::TaxonomyId;
 use uuid::Uuid;
 
-use crate::{graphql::types::Taxonomy, setup::state::AppState};
+use crate::graphql::{loader::TaxonomyDataLoader, types::Taxonomy};
 
 pub struct Query;
 
@@ -13,31 +13,25 @@ impl Query {
     /// Get a single Taxonomy entity
     #[graphql(entity)]
     async fn by_id<'ctx>(&self, ctx: &Context<'ctx>, id: ID) -> Result<Option<Taxonomy>, Error> {
-        let app_state = ctx
-            .data::<AppState>()
-            .map_err(|_| Error::new("AppState not available"))?;
-
-        let taxonomy_service = app_state.taxonomy_service.lock().await;
+        let loader = ctx
+            .data::<TaxonomyDataLoader>()
+            .map_err(|_| Error::new("TaxonomyDataLoader not available"))?;
 
         let t_id = Uuid::from_str(id.as_str())?;
-        let term_id = TaxonomyId::from_uuid(t_id);
-        let domain_term = taxonomy_service.find_by_id(&term_id).await?;
+        let domain_taxonomy = loader.load_one(TaxonomyId::from_uuid(t_id)).await?;
 
-        Ok(domain_term.map(Taxonomy::from))
+        Ok(domain_taxonomy.map(Taxonomy::from))
     }
     /// Get a single Taxonomy
     async fn get_taxonomy<'ctx>(&self, ctx: &Context<'ctx>, id: ID) -> Result<Option<Taxonomy>, Error> {
-        let app_state = ctx
-            .data::<AppState>()
-            .map_err(|_| Error::new("AppState not available"))?;
-
-        let taxonomy_service = app_state.taxonomy_service.lock().await;
+        let loader = ctx
+            .data::<TaxonomyDataLoader>()
+            .map_err(|_| Error::new("TaxonomyDataLoader not available"))?;
 
         let t_id = Uuid::from_str(id.as_str())?;
-        let term_id = TaxonomyId::from_uuid(t_id);
-        let domain_term = taxonomy_service.find_by_id(&term_id).await?;
+        let domain_taxonomy = loader.load_one(TaxonomyId::from_uuid(t_id)).await?;
 
-        Ok(domain_term.map(Taxonomy::from))
+        Ok(domain_taxonomy.map(Taxonomy::from))
     }
 
     /// Get many  Taxonomy
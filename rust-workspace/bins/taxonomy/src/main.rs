@@ -1,46 +1,36 @@
 use std::error::Error;
 
-use async_graphql_axum::GraphQL;
-use axum::{Router, routing::get};
-use tokio::net::TcpListener;
+use observability::predule::init_logging;
+use subgraph_runtime::predule::{SubgraphRuntime, print_schema_requested};
 
-use crate::{
-    routes::handlers::graphiql::graphiql,
-    setup::{env::EnvConfig, schema::build_schema, state::build_state},
+use taxonomy::setup::{
+    env::EnvConfig,
+    schema::{build_schema, export_sdl},
+    state::build_state,
 };
 
-mod graphql;
-mod routes;
-mod setup;
-
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn Error>> {
+    if print_schema_requested() {
+        print!("{}", export_sdl());
+        return Ok(());
+    }
+
     // load env
-    let env_config = EnvConfig::load();
+    let env_config = EnvConfig::load()?;
+    init_logging(&env_config.logging);
 
     // build app state
-    let app_state = build_state();
-
-    // build graphql schema
-    let gql_schema = build_schema(app_state);
-
-    // setup server routes
-    let routes = Router::new()
-        .route("/", get(graphiql).post_service(GraphQL::new(gql_schema)))
-        .route("/health", get(|| async { "ok" }));
-
-    dbg!(format!(
-        "[LOG] Taxonomy subgrah running at http://localhost:{}",
-        env_config.server.port
-    ));
-    axum::serve(
-        TcpListener::bind(format!("127.0.0.1:{}", env_config.server.port))
-            .await
-            .unwrap(),
-        routes,
-    )
-    .await
-    .unwrap();
+    let app_state = build_state(&env_config);
+
+    SubgraphRuntime::builder("taxonomy")
+        .server(&env_config.server)
+        .graphql(&env_config.graphql)
+        .schema(build_schema(app_state.clone()))
+        .state(app_state)
+        .build()?
+        .run()
+        .await?;
 
     Ok(())
 }
@@ -1 +0,0 @@
-pub mod handlers;
\ No newline at end of file
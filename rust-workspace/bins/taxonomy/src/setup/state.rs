@@ -1,23 +1,62 @@
+use std::sync::Arc;
+
 use corelib::predule::{
-    ITaxonomyserviceInMemoryTaxonomyRepository, InMemoryTaxonomyAuditLogHandler,
-    InMemoryTaxonomyEventBus, InMemoryTaxonomyRepository, TaxonomyService,
+    ComponentHealth, DomainError, HealthCheck, InMemoryTaxonomyAuditLogHandler,
+    InMemoryTaxonomyEventBus, InMemoryTaxonomyRepository, TaxonomyDomainEventBus, TaxonomyService,
+};
+use env_config::predule::EventBusKind;
+use observability::predule::{
+    InstrumentedTaxonomyEventBus, InstrumentedTaxonomyEventHandler, InstrumentedTaxonomyRepository,
+    Readiness,
 };
-use tokio::sync::Mutex;
+use subgraph_runtime::predule::SubgraphState;
+
+use crate::setup::env::EnvConfig;
+
+pub type ITaxonomyservice =
+    TaxonomyService<InstrumentedTaxonomyRepository<InMemoryTaxonomyRepository>>;
+
+#[derive(Clone)]
 pub struct AppState {
-    pub taxonomy_service: Mutex<ITaxonomyserviceInMemoryTaxonomyRepository>,
+    pub env_config: EnvConfig,
+    pub taxonomy_service: Arc<ITaxonomyservice>,
 }
 
-pub fn build_state() -> AppState {
+pub fn build_state(env_config: &EnvConfig) -> AppState {
     // Taxonomy
-    let taxonomy_repository = InMemoryTaxonomyRepository::new();
+    let taxonomy_repository =
+        InstrumentedTaxonomyRepository::new(InMemoryTaxonomyRepository::new());
 
-    let mut taxonomy_event_bus = InMemoryTaxonomyEventBus::new();
-    taxonomy_event_bus.with(Box::new(InMemoryTaxonomyAuditLogHandler));
+    let taxonomy_event_bus: Box<dyn TaxonomyDomainEventBus> = match env_config.event_bus.kind {
+        EventBusKind::InMemory => {
+            let mut bus = InMemoryTaxonomyEventBus::new();
+            bus.with(Box::new(InstrumentedTaxonomyEventHandler::new(
+                InMemoryTaxonomyAuditLogHandler,
+            )));
+            Box::new(bus)
+        }
+    };
 
-    let taxonomy_service =
-        TaxonomyService::new(taxonomy_repository).with_bus(Box::new(taxonomy_event_bus));
+    let taxonomy_service = TaxonomyService::new(taxonomy_repository).with_bus(Box::new(
+        InstrumentedTaxonomyEventBus::new(taxonomy_event_bus),
+    ));
 
     AppState {
-        taxonomy_service: Mutex::new(taxonomy_service),
+        env_config: env_config.clone(),
+        taxonomy_service: Arc::new(taxonomy_service),
+    }
+}
+
+impl SubgraphState for AppState {
+    async fn shutdown(&self) -> Result<(), DomainError> {
+        self.taxonomy_service.shutdown().await
+    }
+}
+
+impl Readiness for AppState {
+    async fn readiness(&self) -> Vec<ComponentHealth> {
+        let mut components = vec![self.env_config.check().await];
+        components.extend(self.taxonomy_service.health().await);
+        components
     }
 }
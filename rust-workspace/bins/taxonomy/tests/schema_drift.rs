@@ -0,0 +1,13 @@
+use subgraph_runtime::predule::assert_sdl_matches;
+use taxonomy::setup::schema::export_sdl;
+
+#[test]
+fn gateway_schema_matches_generated_sdl() {
+    assert_sdl_matches(
+        concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/../../../gateway/schemas/taxonomy.graphql"
+        ),
+        &export_sdl(),
+    );
+}
@@ -1,4 +1,4 @@
-use async_graphql::InputObject;
+use async_graphql::{InputObject, MaybeUndefined};
 use uuid::Uuid;
 
 #[derive(InputObject)]
@@ -10,11 +10,12 @@ pub struct CreateTermInput {
     pub description: Option<String>,
 }
 
+/// Fields left out are unchanged, `parentId` and `description` are cleared when set to `null`
 #[derive(InputObject)]
 pub struct UpdateTermInput {
     pub taxonomy_id: Option<Uuid>,
-    pub parent_id: Option<Uuid>,
+    pub parent_id: MaybeUndefined<Uuid>,
     pub name: Option<String>,
     pub visible: Option<bool>,
-    pub description: Option<String>,
+    pub description: MaybeUndefined<String>,
 }
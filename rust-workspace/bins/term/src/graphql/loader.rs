@@ -0,0 +1,35 @@
+use std::{collections::HashMap, sync::Arc};
+
+use async_graphql::dataloader::{DataLoader, HashMapCache, Loader};
+use corelib::predule::{DomainError, Term as DomainTerm, TermId};
+
+use crate::setup::state::{AppState, ITermservice};
+
+/// Request-scoped, caching loader stored in the GraphQL context
+pub type TermDataLoader = DataLoader<TermLoader, HashMapCache>;
+
+/// Batches every `TermId` asked for while resolving a request into one `find_by_ids` call
+pub struct TermLoader {
+    term_service: Arc<ITermservice>,
+}
+
+impl Loader<TermId> for TermLoader {
+    type Value = DomainTerm;
+    type Error = Arc<DomainError>;
+
+    async fn load(&self, keys: &[TermId]) -> Result<HashMap<TermId, DomainTerm>, Self::Error> {
+        let found = self.term_service.find_by_ids(keys).await?;
+        Ok(found.into_iter().map(|term| (term.id, term)).collect())
+    }
+}
+
+/// Builds an empty loader; called once per request so nothing is cached across requests
+pub fn term_loader(app_state: &AppState) -> TermDataLoader {
+    DataLoader::with_cache(
+        TermLoader {
+            term_service: app_state.term_service.clone(),
+        },
+        tokio::spawn,
+        HashMapCache::default(),
+    )
+}
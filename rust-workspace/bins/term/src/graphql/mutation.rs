@@ -1,4 +1,5 @@
 use async_graphql::{Context, Object, Result};
+use bin_shared_kernel::predule::IntoPatch;
 use corelib::predule::{TaxonomyId, TermDescription, TermId, TermName};
 use uuid::Uuid;
 
@@ -14,7 +15,7 @@ impl Mutation {
     async fn create_term<'ctx>(&self, ctx: &Context<'ctx>, input: CreateTermInput) -> Result<Term> {
         let app_state = ctx.data::<AppState>().expect("AppState not initialized");
 
-        let mut term_service = app_state.term_service.lock().await;
+        let term_service = &app_state.term_service;
 
         let taxonomy_id = TaxonomyId::from_uuid(input.taxonomy_id);
 
@@ -44,19 +45,23 @@ impl Mutation {
     ) -> Result<Term> {
         let app_state = ctx.data::<AppState>().expect("AppState not initialized");
 
-        let mut term_service = app_state.term_service.lock().await;
+        let term_service = &app_state.term_service;
 
         let t_id = TermId::from_uuid(term_id);
 
         let taxonomy_id = input.taxonomy_id.map(TaxonomyId::from_uuid);
 
-        let parent_id = input.parent_id.map(TermId::from_uuid);
+        let parent_id = input.parent_id.into_patch().map(TermId::from_uuid);
 
         let name = input.name.map(TermName::new).transpose()?;
 
-        let description = input.description.map(TermDescription::new).transpose()?;
+        let description = input
+            .description
+            .into_patch()
+            .map(TermDescription::new)
+            .transpose()?;
 
-        let visible = input.visible.map(|v| v);
+        let visible = input.visible;
         // Save term via service
         let domain_term = term_service
             .update_term(t_id, taxonomy_id, parent_id, name, visible, description)
@@ -71,7 +76,7 @@ impl Mutation {
     async fn soft_delete_term<'ctx>(&self, ctx: &Context<'ctx>, term_id: Uuid) -> Result<Uuid> {
         let app_state = ctx.data::<AppState>().expect("AppState not initialized");
 
-        let mut term_service = app_state.term_service.lock().await;
+        let term_service = &app_state.term_service;
 
         let t_id = TermId::from_uuid(term_id);
 
@@ -88,7 +93,7 @@ impl Mutation {
     ) -> Result<&'static str> {
         let app_state = ctx.data::<AppState>().expect("AppState not initialized");
 
-        let mut term_service = app_state.term_service.lock().await;
+        let term_service = &app_state.term_service;
 
         let t_id = TermId::from_uuid(term_id);
 
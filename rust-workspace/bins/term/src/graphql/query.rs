@@ -4,7 +4,7 @@ use async_graphql::*;
 use corelib::predule::TermId;
 use uuid::Uuid;
 
-use crate::{graphql::types::Term, setup::state::AppState};
+use crate::graphql::{loader::TermDataLoader, types::Term};
 
 pub struct Query;
 
@@ -13,29 +13,23 @@ impl Query {
     /// Get a single Term Entity
     #[graphql(entity)]
     async fn by_id<'ctx>(&self, ctx: &Context<'ctx>, id: ID) -> Result<Option<Term>, Error> {
-        let app_state = ctx
-            .data::<AppState>()
-            .map_err(|_| Error::new("AppState not available"))?;
-
-        let term_service = app_state.term_service.lock().await;
+        let loader = ctx
+            .data::<TermDataLoader>()
+            .map_err(|_| Error::new("TermDataLoader not available"))?;
 
         let t_id = Uuid::from_str(id.as_str())?;
-        let term_id = TermId::from_uuid(t_id);
-        let domain_term = term_service.find_by_id(&term_id).await?;
+        let domain_term = loader.load_one(TermId::from_uuid(t_id)).await?;
 
         Ok(domain_term.map(Term::from))
     }
     /// Get a single Term
     async fn get_term<'ctx>(&self, ctx: &Context<'ctx>, id: ID) -> Result<Option<Term>, Error> {
-        let app_state = ctx
-            .data::<AppState>()
-            .map_err(|_| Error::new("AppState not available"))?;
-
-        let term_service = app_state.term_service.lock().await;
+        let loader = ctx
+            .data::<TermDataLoader>()
+            .map_err(|_| Error::new("TermDataLoader not available"))?;
 
         let t_id = Uuid::from_str(id.as_str())?;
-        let term_id = TermId::from_uuid(t_id);
-        let domain_term = term_service.find_by_id(&term_id).await?;
+        let domain_term = loader.load_one(TermId::from_uuid(t_id)).await?;
 
         Ok(domain_term.map(Term::from))
     }
@@ -1,4 +1,5 @@
 use async_graphql::{ID, SimpleObject};
+use bin_shared_kernel::predule::TaxonomyRef;
 use corelib::predule::Term as DomainTerm;
 use uuid::Uuid;
 
@@ -6,7 +7,7 @@ use uuid::Uuid;
 pub struct Term {
     pub id: ID,
 
-    pub taxonomy_id: Uuid,
+    pub taxonomy: TaxonomyRef,
     pub parent_id: Option<Uuid>,
     pub name: String,
     pub visible: bool,
@@ -23,7 +24,7 @@ impl From<DomainTerm> for Term {
     fn from(value: DomainTerm) -> Self {
         Self {
             id: value.id.as_uuid().into(),
-            taxonomy_id: value.taxonomy_id.as_uuid(),
+            taxonomy: value.taxonomy_id.into(),
             parent_id: value.parent_id.map(|v| v.as_uuid()),
             name: value.name.as_str().to_string(),
             visible: value.visible,
@@ -1,18 +1,51 @@
-use env_config::predule::{ ServerConfig};
-
+use corelib::predule::{ComponentHealth, HealthCheck};
+use env_config::predule::{
+    ConfigError, ConfigLoader, EventBusConfig, GraphqlConfig, LoggingConfig, ServerConfig, Settings,
+};
 
+#[derive(Clone)]
 pub struct EnvConfig {
     pub server: ServerConfig,
-    // pub database: DatabaseConfig,
+    pub logging: LoggingConfig,
+    pub event_bus: EventBusConfig,
+    pub graphql: GraphqlConfig,
 }
 
 impl EnvConfig {
-    pub fn load() -> Self {
+    /// Loads from every layer, CLI flags included
+    pub fn load() -> Result<Self, ConfigError> {
+        Self::from_loader(|loader| loader.load())
+    }
+
+    /// Loads from defaults, the config file and env vars only, for processes
+    /// hosting several subgraphs where a `--server.port` flag would be ambiguous
+    pub fn load_from_env() -> Result<Self, ConfigError> {
+        Self::from_loader(|loader| loader.load_from(std::iter::empty(), std::env::vars()))
+    }
+
+    fn from_loader(
+        load: impl FnOnce(ConfigLoader) -> Result<Settings, ConfigError>,
+    ) -> Result<Self, ConfigError> {
         dotenvy::dotenv().ok();
 
-        Self {
-            server: ServerConfig::load("TERM_SUBGRAPH"),
-            // database: DatabaseConfig::load(),
-        }
+        let settings = load(Settings::loader("TERM_SUBGRAPH").with_default("server.port", "3002"))?;
+
+        Ok(Self {
+            server: settings.server,
+            logging: settings.logging,
+            event_bus: settings.event_bus,
+            graphql: settings.graphql,
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl HealthCheck for EnvConfig {
+    async fn check(&self) -> ComponentHealth {
+        // every value is checked when loading, so a loaded config can be served
+        ComponentHealth::up(
+            "config",
+            format!("listening on {}", self.server.bind_addr()),
+        )
     }
 }
@@ -1,21 +1,58 @@
+use std::sync::Arc;
+
 use corelib::predule::{
-    ITermserviceInMemoryTermRepository, InMemoryTermAuditLogHandler, InMemoryTermEventBus, InMemoryTermRepository, TermService
+    ComponentHealth, DomainError, HealthCheck, InMemoryTermAuditLogHandler, InMemoryTermEventBus,
+    InMemoryTermRepository, TermDomainEventBus, TermService,
+};
+use env_config::predule::EventBusKind;
+use observability::predule::{
+    InstrumentedTermEventBus, InstrumentedTermEventHandler, InstrumentedTermRepository, Readiness,
 };
-use tokio::sync::Mutex;
+use subgraph_runtime::predule::SubgraphState;
+
+use crate::setup::env::EnvConfig;
+
+pub type ITermservice = TermService<InstrumentedTermRepository<InMemoryTermRepository>>;
+
+#[derive(Clone)]
 pub struct AppState {
-    pub term_service: Mutex<ITermserviceInMemoryTermRepository>,
+    pub env_config: EnvConfig,
+    pub term_service: Arc<ITermservice>,
 }
 
-pub fn build_state() -> AppState {
+pub fn build_state(env_config: &EnvConfig) -> AppState {
     // Term
-    let term_repository = InMemoryTermRepository::new();
+    let term_repository = InstrumentedTermRepository::new(InMemoryTermRepository::new());
 
-    let mut term_event_bus = InMemoryTermEventBus::new();
-    term_event_bus.with(Box::new(InMemoryTermAuditLogHandler));
+    let term_event_bus: Box<dyn TermDomainEventBus> = match env_config.event_bus.kind {
+        EventBusKind::InMemory => {
+            let mut bus = InMemoryTermEventBus::new();
+            bus.with(Box::new(InstrumentedTermEventHandler::new(
+                InMemoryTermAuditLogHandler,
+            )));
+            Box::new(bus)
+        }
+    };
 
-    let term_service = TermService::new(term_repository).with_bus(Box::new(term_event_bus));
+    let term_service = TermService::new(term_repository)
+        .with_bus(Box::new(InstrumentedTermEventBus::new(term_event_bus)));
 
     AppState {
-        term_service: Mutex::new(term_service),
+        env_config: env_config.clone(),
+        term_service: Arc::new(term_service),
+    }
+}
+
+impl SubgraphState for AppState {
+    async fn shutdown(&self) -> Result<(), DomainError> {
+        self.term_service.shutdown().await
+    }
+}
+
+impl Readiness for AppState {
+    async fn readiness(&self) -> Vec<ComponentHealth> {
+        let mut components = vec![self.env_config.check().await];
+        components.extend(self.term_service.health().await);
+        components
     }
 }
@@ -1,7 +1,10 @@
-use std::collections::HashMap;
-
-use async_graphql::InputObject;
-use bin_shared_kernel::predule::SocialProfileInput;
+use async_graphql::{InputObject, MaybeUndefined};
+use bin_shared_kernel::predule::{
+    DemographicEntryInput, SocialProfileInput, SocialProfilePlatform,
+};
+use corelib::predule::{
+    CriterionWeights, DomainError, FollowerRange, MatchTarget, TaxonomyId, TermId,
+};
 use uuid::Uuid;
 
 #[derive(InputObject)]
@@ -10,14 +13,85 @@ pub struct CreateUserInput {
     pub last_name: String,
     pub country_term_id: Uuid,
     pub social_profiles: Option<Vec<SocialProfileInput>>,
-    pub demographics: Option<HashMap<Uuid, Vec<Uuid>>>,
+    pub demographics: Option<Vec<DemographicEntryInput>>,
 }
 
+/// Fields left out are unchanged, `socialProfiles` and `demographics` are cleared when set to `null`
 #[derive(InputObject)]
 pub struct UpdateUserInput {
     pub first_name: Option<String>,
     pub last_name: Option<String>,
     pub country_term_id: Option<Uuid>,
-    pub social_profiles: Option<Vec<SocialProfileInput>>,
-    pub demographics: Option<HashMap<Uuid, Vec<Uuid>>>,
+    pub social_profiles: MaybeUndefined<Vec<SocialProfileInput>>,
+    pub demographics: MaybeUndefined<Vec<DemographicEntryInput>>,
+}
+
+/// A term the target audience should have, weighted against the other terms
+#[derive(InputObject)]
+pub struct TargetTermInput {
+    pub taxonomy_id: Uuid,
+    pub term_id: Uuid,
+    #[graphql(default = 1.0)]
+    pub weight: f64,
+}
+
+/// Inclusive follower bounds, either side may be left out
+#[derive(InputObject)]
+pub struct FollowerRangeInput {
+    pub min: Option<u64>,
+    pub max: Option<u64>,
+}
+
+/// Weight of each criterion in the overall score, relative to the others
+#[derive(InputObject)]
+pub struct CriterionWeightsInput {
+    #[graphql(default = 1.0)]
+    pub demographics: f64,
+    #[graphql(default = 1.0)]
+    pub platforms: f64,
+    #[graphql(default = 1.0)]
+    pub followers: f64,
+}
+
+/// Audience to match influencers against; set at least one of `demographics`, `platforms` and `followers`
+#[derive(InputObject)]
+pub struct MatchTargetInput {
+    #[graphql(default)]
+    pub demographics: Vec<TargetTermInput>,
+    /// Any platform when empty; followers are counted on these platforms only
+    #[graphql(default)]
+    pub platforms: Vec<SocialProfilePlatform>,
+    pub followers: Option<FollowerRangeInput>,
+    pub weights: Option<CriterionWeightsInput>,
+}
+
+impl MatchTargetInput {
+    pub fn into_target(self) -> Result<MatchTarget, DomainError> {
+        let followers = self
+            .followers
+            .map(|range| FollowerRange::new(range.min, range.max))
+            .transpose()?;
+
+        let weights = self
+            .weights
+            .map(|weights| CriterionWeights {
+                demographics: weights.demographics,
+                platforms: weights.platforms,
+                followers: weights.followers,
+            })
+            .unwrap_or_default();
+
+        MatchTarget::new(
+            self.demographics.into_iter().map(|term| {
+                (
+                    TaxonomyId::from_uuid(term.taxonomy_id),
+                    TermId::from_uuid(term.term_id),
+                    term.weight,
+                )
+            }),
+            self.platforms.into_iter().map(Into::into),
+            followers,
+            weights,
+        )
+    }
 }
@@ -0,0 +1,52 @@
+use std::{collections::HashMap, sync::Arc};
+
+use async_graphql::dataloader::{DataLoader, HashMapCache, Loader};
+use corelib::predule::{DomainError, IdentityId, User as DomainUser, UserId};
+
+use crate::setup::state::{AppState, IUserservice};
+
+/// Request-scoped, caching loader stored in the GraphQL context
+pub type UserDataLoader = DataLoader<UserLoader, HashMapCache>;
+
+/// Batches every `UserId` (or `IdentityId`) asked for while resolving a request into one
+/// `find_by_ids` (or `find_by_identities`) call
+pub struct UserLoader {
+    user_service: Arc<IUserservice>,
+}
+
+impl Loader<UserId> for UserLoader {
+    type Value = DomainUser;
+    type Error = Arc<DomainError>;
+
+    async fn load(&self, keys: &[UserId]) -> Result<HashMap<UserId, DomainUser>, Self::Error> {
+        let found = self.user_service.find_by_ids(keys).await?;
+        Ok(found.into_iter().map(|user| (user.id, user)).collect())
+    }
+}
+
+impl Loader<IdentityId> for UserLoader {
+    type Value = DomainUser;
+    type Error = Arc<DomainError>;
+
+    async fn load(
+        &self,
+        keys: &[IdentityId],
+    ) -> Result<HashMap<IdentityId, DomainUser>, Self::Error> {
+        let found = self.user_service.find_by_identities(keys).await?;
+        Ok(found
+            .into_iter()
+            .map(|user| (user.identity_id.clone(), user))
+            .collect())
+    }
+}
+
+/// Builds an empty loader; called once per request so nothing is cached across requests
+pub fn user_loader(app_state: &AppState) -> UserDataLoader {
+    DataLoader::with_cache(
+        UserLoader {
+            user_service: app_state.user_service.clone(),
+        },
+        tokio::spawn,
+        HashMapCache::default(),
+    )
+}
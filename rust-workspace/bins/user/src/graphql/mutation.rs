@@ -1,10 +1,12 @@
-use std::collections::HashMap;
-
 use async_graphql::{Context, Object, Result};
+use bin_shared_kernel::predule::{
+    DemographicEntryInput, IntoPatch, SocialProfileInput, SocialProfilePlatform,
+};
 use corelib::predule::{
-    DomainError, FirstName, LastName, SocialMediaMetadata, SocialMediaProfiles, TaxonomyId, TermId,
-    UserId,
+    DomainError, FirstName, IdentityId, LastName, SocialMediaProfiles, TermId, UserActor,
+    UserChanges, UserId,
 };
+use subgraph_runtime::predule::{ADMIN_ROLE, AuthenticatedIdentity, RoleGuard};
 use uuid::Uuid;
 
 use crate::graphql::inputs::{CreateUserInput, UpdateUserInput};
@@ -13,72 +15,61 @@ use crate::setup::state::AppState;
 
 pub struct Mutation;
 
+/// Who the caller acts as: an admin by role, else the user of their identity
+async fn calling_actor(ctx: &Context<'_>) -> Result<UserActor> {
+    let Some(identity) = ctx.data_opt::<AuthenticatedIdentity>() else {
+        return Ok(UserActor::Anonymous);
+    };
+    if identity.has_role(ADMIN_ROLE) {
+        return Ok(UserActor::Admin);
+    }
+    let app_state = ctx.data::<AppState>().expect("AppState not initialized");
+
+    let identity_id = IdentityId::new(identity.id.as_str())?;
+    let user = app_state
+        .user_service
+        .find_by_identity(&identity_id)
+        .await?;
+    Ok(user.map_or(UserActor::Anonymous, |user| UserActor::User(user.id)))
+}
+
 #[Object]
 impl Mutation {
-    /// Create a User
+    /// Create the User of the calling Identity
     async fn create_user<'ctx>(&self, ctx: &Context<'ctx>, input: CreateUserInput) -> Result<User> {
         let app_state = ctx.data::<AppState>().expect("AppState not initialized");
 
-        let mut user_service = app_state.user_service.lock().await;
+        let user_service = &app_state.user_service;
+
+        let identity = ctx.data_opt::<AuthenticatedIdentity>().ok_or_else(|| {
+            DomainError::forbidden("creating a user requires an authenticated identity")
+        })?;
+        let identity_id = IdentityId::new(identity.id.as_str())?;
 
         let first_name = FirstName::new(input.first_name)?;
 
         let last_name = LastName::new(input.last_name)?;
 
         let country_term_id = TermId::from_uuid(input.country_term_id);
-        let social_media = if let Some(sm) = input.social_profiles {
-            let social_media_meta_data = sm
-                .into_iter()
-                .map(|v| {
-                    SocialMediaMetadata::new(
-                        v.platform.into(),
-                        v.profile_name,
-                        v.profile_link,
-                        v.mark_for_verification,
-                        v.is_verified,
-                        v.follower_count,
-                        v.demographics.map(|k| {
-                            k.into_iter()
-                                .map(|(o, p)| {
-                                    (
-                                        TaxonomyId::from_uuid(o),
-                                        p.into_iter()
-                                            .map(|elem| TermId::from_uuid(elem))
-                                            .collect::<Vec<TermId>>(),
-                                    )
-                                })
-                                .collect::<HashMap<TaxonomyId, Vec<TermId>>>()
-                        }),
-                    )
-                })
-                .collect::<Result<Vec<_>, DomainError>>()?; // <-- FIX HERE
-
-            let profiles = SocialMediaProfiles::new(social_media_meta_data);
-            Some(profiles)
-        } else {
-            None
-        };
-
-        let demographics = if let Some(demograph) = input.demographics {
-            Some(
-                demograph
-                    .into_iter()
-                    .map(|(k, v)| {
-                        (
-                            TaxonomyId::from_uuid(k),
-                            v.into_iter()
-                                .map(|elem| TermId::from_uuid(elem))
-                                .collect::<Vec<TermId>>(),
-                        )
-                    })
-                    .collect::<HashMap<TaxonomyId, Vec<TermId>>>(),
-            )
-        } else {
-            None
-        };
+        let social_media = input
+            .social_profiles
+            .map(|sm| {
+                sm.into_iter()
+                    .enumerate()
+                    .map(|(i, profile)| profile.into_metadata(&format!("socialProfiles.{}.", i)))
+                    .collect::<Result<Vec<_>, DomainError>>()
+                    .map(SocialMediaProfiles::new)
+            })
+            .transpose()?;
+
+        let demographics = input
+            .demographics
+            .map(DemographicEntryInput::into_demographics)
+            .transpose()?;
         // Save user via service
         let domain_user = user_service
             .create_user(
+                identity_id,
                 first_name,
                 last_name,
                 country_term_id,
@@ -92,7 +83,7 @@ impl Mutation {
         Ok(user)
     }
 
-    /// Update a User
+    /// Update a User, as that user or an admin
     async fn update_user<'ctx>(
         &self,
         ctx: &Context<'ctx>,
@@ -101,7 +92,7 @@ impl Mutation {
     ) -> Result<User> {
         let app_state = ctx.data::<AppState>().expect("AppState not initialized");
 
-        let mut user_service = app_state.user_service.lock().await;
+        let user_service = &app_state.user_service;
 
         let u_id = UserId::from_uuid(user_id);
 
@@ -111,65 +102,35 @@ impl Mutation {
 
         let country_term_id = input.country_term_id.map(TermId::from_uuid);
 
-        let social_media = if let Some(sm) = input.social_profiles {
-            let social_media_meta_data = sm
-                .into_iter()
-                .map(|v| {
-                    SocialMediaMetadata::new(
-                        v.platform.into(),
-                        v.profile_name,
-                        v.profile_link,
-                        v.mark_for_verification,
-                        v.is_verified,
-                        v.follower_count,
-                        v.demographics.map(|k| {
-                            k.into_iter()
-                                .map(|(o, p)| {
-                                    (
-                                        TaxonomyId::from_uuid(o),
-                                        p.into_iter()
-                                            .map(|elem| TermId::from_uuid(elem))
-                                            .collect::<Vec<TermId>>(),
-                                    )
-                                })
-                                .collect::<HashMap<TaxonomyId, Vec<TermId>>>()
-                        }),
-                    )
-                })
-                .collect::<Result<Vec<_>, DomainError>>()?;
-
-            let profiles = SocialMediaProfiles::new(social_media_meta_data);
-            Some(profiles)
-        } else {
-            None
-        };
-
-        let demographics = if let Some(demograph) = input.demographics {
-            Some(
-                demograph
-                    .into_iter()
-                    .map(|(k, v)| {
-                        (
-                            TaxonomyId::from_uuid(k),
-                            v.into_iter()
-                                .map(|elem| TermId::from_uuid(elem))
-                                .collect::<Vec<TermId>>(),
-                        )
-                    })
-                    .collect::<HashMap<TaxonomyId, Vec<TermId>>>(),
-            )
-        } else {
-            None
-        };
+        let social_media = input
+            .social_profiles
+            .into_patch()
+            .map(|sm| {
+                sm.into_iter()
+                    .enumerate()
+                    .map(|(i, profile)| profile.into_metadata(&format!("socialProfiles.{}.", i)))
+                    .collect::<Result<Vec<_>, DomainError>>()
+                    .map(SocialMediaProfiles::new)
+            })
+            .transpose()?;
+
+        let demographics = input
+            .demographics
+            .into_patch()
+            .map(DemographicEntryInput::into_demographics)
+            .transpose()?;
         // Save user via service
         let domain_user = user_service
             .update_user(
                 u_id,
-                first_name,
-                last_name,
-                country_term_id,
-                social_media,
-                demographics,
+                calling_actor(ctx).await?,
+                UserChanges {
+                    first_name,
+                    last_name,
+                    country_term_id,
+                    social_profiles: social_media,
+                    demographics,
+                },
             )
             .await?;
 
@@ -178,11 +139,138 @@ impl Mutation {
         Ok(user)
     }
 
+    /// Add or replace the profile of one platform, as that user or an admin
+    async fn upsert_social_profile<'ctx>(
+        &self,
+        ctx: &Context<'ctx>,
+        user_id: Uuid,
+        input: SocialProfileInput,
+    ) -> Result<User> {
+        let app_state = ctx.data::<AppState>().expect("AppState not initialized");
+
+        let user_service = &app_state.user_service;
+
+        let profile = input.into_metadata("")?;
+
+        let domain_user = user_service
+            .upsert_social_profile(
+                UserId::from_uuid(user_id),
+                calling_actor(ctx).await?,
+                profile,
+            )
+            .await?;
+
+        Ok(User::from(domain_user))
+    }
+
+    /// Remove the profile of one platform, as that user or an admin
+    async fn remove_social_profile<'ctx>(
+        &self,
+        ctx: &Context<'ctx>,
+        user_id: Uuid,
+        platform: SocialProfilePlatform,
+    ) -> Result<User> {
+        let app_state = ctx.data::<AppState>().expect("AppState not initialized");
+
+        let user_service = &app_state.user_service;
+
+        let domain_user = user_service
+            .remove_social_profile(
+                UserId::from_uuid(user_id),
+                calling_actor(ctx).await?,
+                platform.into(),
+            )
+            .await?;
+
+        Ok(User::from(domain_user))
+    }
+
+    /// Ask for the profile of one platform to be verified, as that user or an admin
+    async fn request_profile_verification<'ctx>(
+        &self,
+        ctx: &Context<'ctx>,
+        user_id: Uuid,
+        platform: SocialProfilePlatform,
+    ) -> Result<User> {
+        let app_state = ctx.data::<AppState>().expect("AppState not initialized");
+
+        let user_service = &app_state.user_service;
+
+        let domain_user = user_service
+            .request_profile_verification(
+                UserId::from_uuid(user_id),
+                calling_actor(ctx).await?,
+                platform.into(),
+            )
+            .await?;
+
+        Ok(User::from(domain_user))
+    }
+
+    /// Verify the pending profile of one platform, reviewers only
+    #[graphql(guard = "RoleGuard::new(ADMIN_ROLE)")]
+    async fn approve_profile_verification<'ctx>(
+        &self,
+        ctx: &Context<'ctx>,
+        user_id: Uuid,
+        platform: SocialProfilePlatform,
+    ) -> Result<User> {
+        let app_state = ctx.data::<AppState>().expect("AppState not initialized");
+
+        let user_service = &app_state.user_service;
+
+        let domain_user = user_service
+            .approve_profile_verification(UserId::from_uuid(user_id), platform.into())
+            .await?;
+
+        Ok(User::from(domain_user))
+    }
+
+    /// Turn down the pending verification of one platform, reviewers only
+    #[graphql(guard = "RoleGuard::new(ADMIN_ROLE)")]
+    async fn reject_profile_verification<'ctx>(
+        &self,
+        ctx: &Context<'ctx>,
+        user_id: Uuid,
+        platform: SocialProfilePlatform,
+        reason: String,
+    ) -> Result<User> {
+        let app_state = ctx.data::<AppState>().expect("AppState not initialized");
+
+        let user_service = &app_state.user_service;
+
+        let domain_user = user_service
+            .reject_profile_verification(UserId::from_uuid(user_id), platform.into(), reason)
+            .await?;
+
+        Ok(User::from(domain_user))
+    }
+
+    /// Record a follower count reported by metrics ingestion, admins only
+    #[graphql(guard = "RoleGuard::new(ADMIN_ROLE)")]
+    async fn ingest_follower_count<'ctx>(
+        &self,
+        ctx: &Context<'ctx>,
+        user_id: Uuid,
+        platform: SocialProfilePlatform,
+        follower_count: u64,
+    ) -> Result<User> {
+        let app_state = ctx.data::<AppState>().expect("AppState not initialized");
+
+        let user_service = &app_state.user_service;
+
+        let domain_user = user_service
+            .record_follower_count(UserId::from_uuid(user_id), platform.into(), follower_count)
+            .await?;
+
+        Ok(User::from(domain_user))
+    }
+
     /// Soft Delete a User
     async fn soft_delete_user<'ctx>(&self, ctx: &Context<'ctx>, user_id: Uuid) -> Result<Uuid> {
         let app_state = ctx.data::<AppState>().expect("AppState not initialized");
 
-        let mut user_service = app_state.user_service.lock().await;
+        let user_service = &app_state.user_service;
 
         let u_id = UserId::from_uuid(user_id);
 
@@ -199,7 +287,7 @@ impl Mutation {
     ) -> Result<&'static str> {
         let app_state = ctx.data::<AppState>().expect("AppState not initialized");
 
-        let mut user_service = app_state.user_service.lock().await;
+        let user_service = &app_state.user_service;
 
         let u_id = UserId::from_uuid(user_id);
 
@@ -1,10 +1,16 @@
 use std::str::FromStr;
 
 use async_graphql::*;
-use corelib::predule::UserId;
+use corelib::predule::{IdentityId, UserId};
+use subgraph_runtime::predule::{ADMIN_ROLE, RoleGuard};
 use uuid::Uuid;
 
-use crate::{graphql::types::User, setup::state::AppState};
+use crate::graphql::{
+    inputs::MatchTargetInput,
+    loader::UserDataLoader,
+    types::{Identity, InfluencerMatch, ProfileVerificationRequest, User},
+};
+use crate::setup::state::AppState;
 
 pub struct Query;
 
@@ -13,36 +19,89 @@ impl Query {
     /// Get a single User Entity
     #[graphql(entity)]
     async fn by_id<'ctx>(&self, ctx: &Context<'ctx>, id: ID) -> Result<Option<User>, Error> {
-        let app_state = ctx
-            .data::<AppState>()
-            .map_err(|_| Error::new("AppState not available"))?;
-
-        let user_service = app_state.user_service.lock().await;
+        let loader = ctx
+            .data::<UserDataLoader>()
+            .map_err(|_| Error::new("UserDataLoader not available"))?;
 
         let u_id = Uuid::from_str(id.as_str())?;
-        let user_id = UserId::from_uuid(u_id);
-
-        let domain_user = user_service.find_by_id(&user_id).await?;
+        let domain_user = loader.load_one(UserId::from_uuid(u_id)).await?;
 
         Ok(domain_user.map(User::from))
     }
 
     /// Get a single User
     async fn get_user<'ctx>(&self, ctx: &Context<'ctx>, id: ID) -> Result<Option<User>, Error> {
-        let app_state = ctx
-            .data::<AppState>()
-            .map_err(|_| Error::new("AppState not available"))?;
-
-        let user_service = app_state.user_service.lock().await;
+        let loader = ctx
+            .data::<UserDataLoader>()
+            .map_err(|_| Error::new("UserDataLoader not available"))?;
 
         let u_id = Uuid::from_str(id.as_str())?;
-        let user_id = UserId::from_uuid(u_id);
+        let domain_user = loader.load_one(UserId::from_uuid(u_id)).await?;
+
+        Ok(domain_user.map(User::from))
+    }
+
+    /// Resolve an Identity entity, the router asks for its user here
+    #[graphql(entity)]
+    async fn identity_by_id(&self, id: ID) -> Identity {
+        Identity { id }
+    }
 
-        let domain_user = user_service.find_by_id(&user_id).await?;
+    /// Get the User of an Identity
+    async fn user_by_identity<'ctx>(
+        &self,
+        ctx: &Context<'ctx>,
+        identity_id: ID,
+    ) -> Result<Option<User>, Error> {
+        let loader = ctx
+            .data::<UserDataLoader>()
+            .map_err(|_| Error::new("UserDataLoader not available"))?;
+
+        let identity_id = IdentityId::new(identity_id.as_str())?;
+        let domain_user = loader.load_one(identity_id).await?;
 
         Ok(domain_user.map(User::from))
     }
 
+    /// Social profiles waiting for a reviewer, oldest user first
+    #[graphql(guard = "RoleGuard::new(ADMIN_ROLE)")]
+    async fn profile_verification_queue<'ctx>(
+        &self,
+        ctx: &Context<'ctx>,
+        #[graphql(default = 20, validator(minimum = 1, maximum = 100))] limit: i32,
+        #[graphql(default = 0, validator(minimum = 0))] offset: i32,
+    ) -> Result<Vec<ProfileVerificationRequest>, Error> {
+        let app_state = ctx.data::<AppState>().expect("AppState not initialized");
+
+        let users = app_state
+            .user_service
+            .pending_profile_verifications(limit as usize, offset as usize)
+            .await?;
+
+        Ok(users
+            .into_iter()
+            .flat_map(ProfileVerificationRequest::from_user)
+            .collect())
+    }
+
+    /// Users ranked by how well they fit a target audience, best first
+    async fn match_influencers<'ctx>(
+        &self,
+        ctx: &Context<'ctx>,
+        target: MatchTargetInput,
+        #[graphql(default = 20, validator(minimum = 1, maximum = 100))] limit: i32,
+        #[graphql(default = 0, validator(minimum = 0, maximum = 10000))] offset: i32,
+    ) -> Result<Vec<InfluencerMatch>, Error> {
+        let app_state = ctx.data::<AppState>().expect("AppState not initialized");
+
+        let matches = app_state
+            .user_service
+            .match_influencers(&target.into_target()?, limit as usize, offset as usize)
+            .await?;
+
+        Ok(matches.into_iter().map(InfluencerMatch::from).collect())
+    }
+
     /// Get many  User
     async fn get_users(&self) -> &'static str {
         "get users"
@@ -1,21 +1,54 @@
-use std::collections::HashMap;
+use std::str::FromStr;
 
-use async_graphql::{ID, SimpleObject};
-use bin_shared_kernel::predule::SocialProfile;
-use corelib::predule::User as DomainUser;
+use async_graphql::{ComplexObject, Context, Enum, ID, Result, SimpleObject};
+use bin_shared_kernel::predule::{DemographicEntry, SocialProfile, SocialProfilePlatform, TermRef};
+use chrono::{DateTime, Duration, Utc};
+use corelib::predule::{
+    CriterionScore as DomainCriterionScore, FollowerCountPoint as DomainFollowerCountPoint,
+    FollowerGrowth as DomainFollowerGrowth, FollowerSnapshot as DomainFollowerSnapshot, IdentityId,
+    InfluencerMatch as DomainInfluencerMatch, MatchCriterion as DomainMatchCriterion,
+    User as DomainUser, UserId,
+};
 use uuid::Uuid;
 
+use crate::{graphql::loader::UserDataLoader, setup::state::AppState};
+
+// `Identity` of the identity subgraph, extended with the user it owns.
+// Kept free of doc comments so the owner's description wins in the supergraph.
+#[derive(SimpleObject, Clone)]
+#[graphql(complex)]
+pub struct Identity {
+    pub id: ID,
+}
+
+#[ComplexObject]
+impl Identity {
+    /// The User this Identity created, if any
+    async fn user<'ctx>(&self, ctx: &Context<'ctx>) -> Result<Option<User>> {
+        let loader = ctx.data::<UserDataLoader>()?;
+
+        let identity_id = IdentityId::new(self.id.as_str())?;
+        let domain_user = loader.load_one(identity_id).await?;
+
+        Ok(domain_user.map(User::from))
+    }
+}
+
 #[derive(SimpleObject)]
+#[graphql(complex)]
 pub struct User {
     pub id: ID,
+    pub identity: Identity,
 
     pub first_name: String,
     pub last_name: String,
-    pub country_term_id: Uuid,
+    pub country: TermRef,
 
     pub social_profiles: Option<Vec<SocialProfile>>,
+    /// Followers across all social profiles
+    pub total_followers: u64,
 
-    pub demographics: Option<HashMap<String, Vec<String>>>,
+    pub demographics: Option<Vec<DemographicEntry>>,
     pub created_at: String,
     pub updated_at: String,
     pub deleted: bool,
@@ -27,22 +60,23 @@ impl From<DomainUser> for User {
     fn from(value: DomainUser) -> Self {
         Self {
             id: value.id.as_uuid().into(),
+            identity: Identity {
+                id: value.identity_id.as_str().into(),
+            },
             first_name: value.first_name.as_str().to_string(),
             last_name: value.last_name.as_str().to_string(),
-            country_term_id: value.country_term_id.as_uuid(),
-            social_profiles: value.social_profiles.map(|v| {
-                v.all()
-                    .iter()
-                    .map(|item| SocialProfile::from(item))
-                    .collect::<Vec<SocialProfile>>()
-            }),
-            demographics: value.demographics.map(|v| {
-                v.iter()
-                    .map(|(tax_id, terms)| {
-                        (tax_id.as_str(), terms.iter().map(|t| t.as_str()).collect())
-                    })
-                    .collect::<HashMap<String, Vec<String>>>()
-            }),
+            country: value.country_term_id.into(),
+            total_followers: value
+                .social_profiles
+                .as_ref()
+                .map_or(0, |profiles| profiles.total_followers()),
+            social_profiles: value
+                .social_profiles
+                .map(|v| v.all().iter().map(SocialProfile::from).collect()),
+            demographics: value
+                .demographics
+                .as_ref()
+                .map(DemographicEntry::from_demographics),
             created_at: value.timestamps.created_human(),
             updated_at: value.timestamps.updated_human(),
             deleted: value.deleted.is_deleted(),
@@ -51,3 +85,229 @@ impl From<DomainUser> for User {
         }
     }
 }
+
+#[ComplexObject]
+impl User {
+    /// Follower counts of one social profile over time, oldest first
+    async fn follower_history<'ctx>(
+        &self,
+        ctx: &Context<'ctx>,
+        platform: SocialProfilePlatform,
+        since: Option<DateTime<Utc>>,
+        until: Option<DateTime<Utc>>,
+    ) -> Result<Vec<FollowerSnapshot>> {
+        let app_state = ctx.data::<AppState>().expect("AppState not initialized");
+
+        let snapshots = app_state
+            .user_service
+            .follower_history(
+                &self.user_id()?,
+                platform.into(),
+                since,
+                until.unwrap_or_else(Utc::now),
+            )
+            .await?;
+
+        Ok(snapshots.into_iter().map(FollowerSnapshot::from).collect())
+    }
+
+    /// Followers across all social profiles over time, oldest first
+    async fn total_followers_history<'ctx>(
+        &self,
+        ctx: &Context<'ctx>,
+        since: Option<DateTime<Utc>>,
+        until: Option<DateTime<Utc>>,
+    ) -> Result<Vec<FollowerCountPoint>> {
+        let app_state = ctx.data::<AppState>().expect("AppState not initialized");
+
+        let points = app_state
+            .user_service
+            .total_followers_history(&self.user_id()?, since, until.unwrap_or_else(Utc::now))
+            .await?;
+
+        Ok(points.into_iter().map(FollowerCountPoint::from).collect())
+    }
+
+    /// Follower growth over the last `windowDays` days, of one profile or of the total
+    async fn follower_growth<'ctx>(
+        &self,
+        ctx: &Context<'ctx>,
+        platform: Option<SocialProfilePlatform>,
+        #[graphql(default = 30, validator(minimum = 1, maximum = 365))] window_days: i32,
+    ) -> Result<Option<FollowerGrowth>> {
+        let app_state = ctx.data::<AppState>().expect("AppState not initialized");
+
+        let to = Utc::now();
+        let from = to - Duration::days(window_days as i64);
+
+        let growth = app_state
+            .user_service
+            .follower_growth(&self.user_id()?, platform.map(Into::into), from, to)
+            .await?;
+
+        Ok(growth.map(Into::into))
+    }
+}
+
+impl User {
+    fn user_id(&self) -> Result<UserId> {
+        Ok(UserId::from_uuid(Uuid::from_str(self.id.as_str())?))
+    }
+}
+
+/// Follower count of one social profile at a point in time
+#[derive(SimpleObject)]
+pub struct FollowerSnapshot {
+    pub platform: SocialProfilePlatform,
+    pub follower_count: u64,
+    pub recorded_at: DateTime<Utc>,
+}
+
+impl From<DomainFollowerSnapshot> for FollowerSnapshot {
+    fn from(value: DomainFollowerSnapshot) -> Self {
+        Self {
+            platform: value.platform.into(),
+            follower_count: value.follower_count,
+            recorded_at: value.recorded_at,
+        }
+    }
+}
+
+/// Followers across all social profiles at a point in time
+#[derive(SimpleObject)]
+pub struct FollowerCountPoint {
+    pub follower_count: u64,
+    pub recorded_at: DateTime<Utc>,
+}
+
+impl From<DomainFollowerCountPoint> for FollowerCountPoint {
+    fn from(value: DomainFollowerCountPoint) -> Self {
+        Self {
+            follower_count: value.follower_count,
+            recorded_at: value.recorded_at,
+        }
+    }
+}
+
+/// Change of a follower count over a window
+#[derive(SimpleObject)]
+pub struct FollowerGrowth {
+    pub from: DateTime<Utc>,
+    pub to: DateTime<Utc>,
+    pub start_count: u64,
+    pub end_count: u64,
+    /// Followers gained, negative when followers were lost
+    pub change: i64,
+    /// Relative change, 0.25 for 25% growth; null when the window starts at zero followers
+    pub rate: Option<f64>,
+}
+
+impl From<DomainFollowerGrowth> for FollowerGrowth {
+    fn from(value: DomainFollowerGrowth) -> Self {
+        Self {
+            from: value.from,
+            to: value.to,
+            start_count: value.start_count,
+            end_count: value.end_count,
+            change: value.change(),
+            rate: value.rate(),
+        }
+    }
+}
+
+/// A social profile waiting for a reviewer, with the User it belongs to
+#[derive(SimpleObject)]
+pub struct ProfileVerificationRequest {
+    pub user: User,
+    pub profile: SocialProfile,
+}
+
+impl ProfileVerificationRequest {
+    /// One request per pending profile of `user`
+    pub fn from_user(user: DomainUser) -> Vec<Self> {
+        let profiles: Vec<SocialProfile> = user
+            .social_profiles
+            .as_ref()
+            .map(|profiles| {
+                profiles
+                    .pending()
+                    .into_iter()
+                    .map(SocialProfile::from)
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        profiles
+            .into_iter()
+            .map(|profile| Self {
+                user: User::from(user.clone()),
+                profile,
+            })
+            .collect()
+    }
+}
+
+/// Part of a match target that users are scored on
+#[derive(Enum, Clone, Copy, PartialEq, Eq)]
+pub enum MatchCriterion {
+    Demographics,
+    Platforms,
+    Followers,
+}
+
+impl From<DomainMatchCriterion> for MatchCriterion {
+    fn from(value: DomainMatchCriterion) -> Self {
+        match value {
+            DomainMatchCriterion::Demographics => MatchCriterion::Demographics,
+            DomainMatchCriterion::Platforms => MatchCriterion::Platforms,
+            DomainMatchCriterion::Followers => MatchCriterion::Followers,
+        }
+    }
+}
+
+/// How one criterion scored
+#[derive(SimpleObject)]
+pub struct CriterionScore {
+    pub criterion: MatchCriterion,
+    /// From 0 to 1
+    pub score: f64,
+    /// Share of the overall score given to this criterion, the weights add up to 1
+    pub weight: f64,
+    /// `score * weight`, the contributions add up to the overall score
+    pub contribution: f64,
+}
+
+impl From<DomainCriterionScore> for CriterionScore {
+    fn from(value: DomainCriterionScore) -> Self {
+        Self {
+            criterion: value.criterion.into(),
+            score: value.score,
+            weight: value.weight,
+            contribution: value.contribution,
+        }
+    }
+}
+
+/// A User ranked against a match target
+#[derive(SimpleObject)]
+pub struct InfluencerMatch {
+    pub user: User,
+    /// From 0 to 1, higher is a better fit
+    pub score: f64,
+    pub breakdown: Vec<CriterionScore>,
+}
+
+impl From<DomainInfluencerMatch> for InfluencerMatch {
+    fn from(value: DomainInfluencerMatch) -> Self {
+        Self {
+            user: value.user.into(),
+            score: value.score.score,
+            breakdown: value
+                .score
+                .breakdown
+                .into_iter()
+                .map(CriterionScore::from)
+                .collect(),
+        }
+    }
+}
@@ -1,15 +1,32 @@
-use async_graphql::{EmptySubscription, Schema};
+use async_graphql::{EmptySubscription, Schema, SchemaBuilder};
+use bin_shared_kernel::predule::PerRequestData;
+use observability::predule::MetricsExtension;
+use subgraph_runtime::predule::{apply_profile, federation_sdl};
 
 use crate::{
-    graphql::{mutation::Mutation, query::Query},
+    graphql::{loader::user_loader, mutation::Mutation, query::Query},
     setup::state::AppState,
 };
 
 pub type AppSchema = Schema<Query, Mutation, EmptySubscription>;
 
+fn schema_builder() -> SchemaBuilder<Query, Mutation, EmptySubscription> {
+    Schema::build(Query, Mutation, EmptySubscription).enable_federation()
+}
+
 pub fn build_schema(app_state: AppState) -> AppSchema {
-    Schema::build(Query, Mutation, EmptySubscription)
-        .enable_federation()
+    apply_profile(schema_builder(), &app_state.env_config.graphql)
+        .extension(MetricsExtension::new("user"))
+        .extension(PerRequestData::new({
+            let app_state = app_state.clone();
+            move || user_loader(&app_state)
+        }))
         .data(app_state)
         .finish()
 }
+
+/// Federation SDL of the schema, without any state; `--print-schema` prints it and the
+/// `schema_drift` test compares it with `gateway/schemas/user.graphql`
+pub fn export_sdl() -> String {
+    federation_sdl(&schema_builder().finish())
+}
@@ -1,24 +1,91 @@
+use std::{sync::Arc, time::Duration};
+
 use corelib::predule::{
-    IUserserviceInMemoryUserRepository, InMemoryAuditLogHandler, InMemoryUserEventBus,
-    InMemoryUserRepository, InMemoryWelcomeEmailHandler, UserService,
+    ComponentHealth, DomainError, HealthCheck, HttpTermSelectionValidator, InMemoryAuditLogHandler,
+    InMemoryFollowerHistoryRepository, InMemoryUserEventBus, InMemoryUserRepository,
+    InMemoryWelcomeEmailHandler, TermSelectionValidator, UserDomainEventBus, UserService,
+};
+use env_config::predule::EventBusKind;
+use observability::predule::{
+    InstrumentedFollowerHistoryRepository, InstrumentedUserEventBus, InstrumentedUserEventHandler,
+    InstrumentedUserRepository, Readiness,
 };
-use tokio::sync::Mutex;
+use subgraph_runtime::predule::SubgraphState;
+
+use crate::setup::env::EnvConfig;
+
+pub type IUserservice = UserService<InstrumentedUserRepository<InMemoryUserRepository>>;
+
+#[derive(Clone)]
 pub struct AppState {
-    pub user_service: Mutex<IUserserviceInMemoryUserRepository>,
+    pub env_config: EnvConfig,
+    pub user_service: Arc<IUserservice>,
+}
+
+/// Checks demographics against the catalog subgraphs over HTTP when `catalog` is configured
+pub fn build_state(env_config: &EnvConfig) -> Result<AppState, DomainError> {
+    let demographics_validator = match &env_config.catalog {
+        Some(catalog) => Some(Box::new(HttpTermSelectionValidator::new(
+            &catalog.taxonomy_url,
+            &catalog.term_url,
+            Duration::from_secs(catalog.timeout_secs),
+        )?) as Box<dyn TermSelectionValidator>),
+        None => None,
+    };
+
+    Ok(build_state_with_validator(
+        env_config,
+        demographics_validator,
+    ))
 }
 
-pub fn build_state() -> AppState {
+/// Builds the state around a given demographics validator, e.g. an in-process one
+/// when the taxonomy and term subgraphs run in the same process
+pub fn build_state_with_validator(
+    env_config: &EnvConfig,
+    demographics_validator: Option<Box<dyn TermSelectionValidator>>,
+) -> AppState {
     // User
-    let user_repository = InMemoryUserRepository::new();
+    let user_repository = InstrumentedUserRepository::new(InMemoryUserRepository::new());
+    let follower_history =
+        InstrumentedFollowerHistoryRepository::new(InMemoryFollowerHistoryRepository::new());
 
-    let mut user_event_bus = InMemoryUserEventBus::new();
-    user_event_bus
-        .with(Box::new(InMemoryWelcomeEmailHandler))
-        .with(Box::new(InMemoryAuditLogHandler));
+    let user_event_bus: Box<dyn UserDomainEventBus> = match env_config.event_bus.kind {
+        EventBusKind::InMemory => {
+            let mut bus = InMemoryUserEventBus::new();
+            bus.with(Box::new(InstrumentedUserEventHandler::new(
+                InMemoryWelcomeEmailHandler,
+            )))
+            .with(Box::new(InstrumentedUserEventHandler::new(
+                InMemoryAuditLogHandler,
+            )));
+            Box::new(bus)
+        }
+    };
 
-    let user_service = UserService::new(user_repository).with_bus(Box::new(user_event_bus));
+    let mut user_service = UserService::new(user_repository)
+        .with_bus(Box::new(InstrumentedUserEventBus::new(user_event_bus)))
+        .with_follower_history(Box::new(follower_history));
+    if let Some(validator) = demographics_validator {
+        user_service = user_service.with_demographics_validator(validator);
+    }
 
     AppState {
-        user_service: Mutex::new(user_service),
+        env_config: env_config.clone(),
+        user_service: Arc::new(user_service),
+    }
+}
+
+impl SubgraphState for AppState {
+    async fn shutdown(&self) -> Result<(), DomainError> {
+        self.user_service.shutdown().await
+    }
+}
+
+impl Readiness for AppState {
+    async fn readiness(&self) -> Vec<ComponentHealth> {
+        let mut components = vec![self.env_config.check().await];
+        components.extend(self.user_service.health().await);
+        components
     }
 }
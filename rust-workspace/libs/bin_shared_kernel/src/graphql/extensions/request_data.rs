@@ -0,0 +1,57 @@
+use std::sync::Arc;
+
+use async_graphql::{
+    Request, ServerResult,
+    extensions::{Extension, ExtensionContext, ExtensionFactory, NextPrepareRequest},
+};
+
+/// Schema extension inserting a fresh value into every request's data
+///
+/// Used for state that must not outlive a request, such as caching DataLoaders.
+pub struct PerRequestData<F> {
+    make: Arc<F>,
+}
+
+impl<F, T> PerRequestData<F>
+where
+    F: Fn() -> T + Send + Sync + 'static,
+    T: Send + Sync + 'static,
+{
+    pub fn new(make: F) -> Self {
+        Self {
+            make: Arc::new(make),
+        }
+    }
+}
+
+impl<F, T> ExtensionFactory for PerRequestData<F>
+where
+    F: Fn() -> T + Send + Sync + 'static,
+    T: Send + Sync + 'static,
+{
+    fn create(&self) -> Arc<dyn Extension> {
+        Arc::new(PerRequestDataImpl {
+            make: self.make.clone(),
+        })
+    }
+}
+
+struct PerRequestDataImpl<F> {
+    make: Arc<F>,
+}
+
+#[async_trait::async_trait]
+impl<F, T> Extension for PerRequestDataImpl<F>
+where
+    F: Fn() -> T + Send + Sync + 'static,
+    T: Send + Sync + 'static,
+{
+    async fn prepare_request(
+        &self,
+        ctx: &ExtensionContext<'_>,
+        request: Request,
+        next: NextPrepareRequest<'_>,
+    ) -> ServerResult<Request> {
+        next.run(ctx, request.data((self.make)())).await
+    }
+}
@@ -0,0 +1,56 @@
+use async_graphql::InputObject;
+use chrono::{NaiveDate, Utc};
+use corelib::predule::{
+    AudienceDemographics, DomainError, FieldError, Percentage, TaxonomyId, TermId,
+};
+use uuid::Uuid;
+
+use crate::predule::AudienceSource;
+
+/// Part of the audience in one term, `percentage` from 0 to 100
+#[derive(InputObject)]
+pub struct AudienceShareInput {
+    pub taxonomy_id: Uuid,
+    pub term_id: Uuid,
+    pub percentage: f64,
+}
+
+/// The shares of one taxonomy add up to at most 100, `asOf` cannot be in the future
+#[derive(InputObject)]
+pub struct AudienceInput {
+    pub source: AudienceSource,
+    pub as_of: NaiveDate,
+    pub shares: Vec<AudienceShareInput>,
+}
+
+impl AudienceInput {
+    /// Validates the input into the domain value object, errors are reported under `field`
+    pub fn into_audience(self, field: &str) -> Result<AudienceDemographics, DomainError> {
+        let mut shares = Vec::with_capacity(self.shares.len());
+        let mut errors = Vec::new();
+        for (i, share) in self.shares.into_iter().enumerate() {
+            match Percentage::new(share.percentage) {
+                Ok(percentage) => shares.push((
+                    TaxonomyId::from_uuid(share.taxonomy_id),
+                    TermId::from_uuid(share.term_id),
+                    percentage,
+                )),
+                Err(_) => errors.push(FieldError::new(
+                    format!("{}.shares.{}.percentage", field, i),
+                    "must be between 0 and 100",
+                )),
+            }
+        }
+        if !errors.is_empty() {
+            return Err(DomainError::invalid_fields(errors));
+        }
+
+        AudienceDemographics::new(
+            field,
+            shares,
+            self.source.into(),
+            self.as_of,
+            Utc::now().date_naive(),
+        )
+    }
+}
@@ -0,0 +1,25 @@
+use async_graphql::InputObject;
+use corelib::predule::{DomainError, TaxonomyId, TermId, TermSelection};
+use uuid::Uuid;
+
+/// Terms picked in one taxonomy; repeated terms are kept once
+#[derive(InputObject)]
+pub struct DemographicEntryInput {
+    pub taxonomy_id: Uuid,
+    pub term_ids: Vec<Uuid>,
+}
+
+impl DemographicEntryInput {
+    /// Validates the entries into the domain value object, a taxonomy listed twice has its terms merged
+    pub fn into_demographics(entries: Vec<Self>) -> Result<TermSelection, DomainError> {
+        TermSelection::new(
+            "demographics",
+            entries.into_iter().map(|entry| {
+                (
+                    TaxonomyId::from_uuid(entry.taxonomy_id),
+                    entry.term_ids.into_iter().map(TermId::from_uuid),
+                )
+            }),
+        )
+    }
+}
@@ -1 +1,4 @@
+pub mod audience;
+pub mod demographics;
+pub mod patch;
 pub mod social_media;
\ No newline at end of file
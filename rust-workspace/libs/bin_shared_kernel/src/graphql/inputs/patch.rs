@@ -0,0 +1,19 @@
+use async_graphql::MaybeUndefined;
+use corelib::predule::Patch;
+
+/// Converts a tri-state GraphQL input field into a domain `Patch`
+///
+/// An omitted field leaves the value unchanged, an explicit `null` clears it.
+pub trait IntoPatch<T> {
+    fn into_patch(self) -> Patch<T>;
+}
+
+impl<T> IntoPatch<T> for MaybeUndefined<T> {
+    fn into_patch(self) -> Patch<T> {
+        match self {
+            MaybeUndefined::Undefined => Patch::Unchanged,
+            MaybeUndefined::Null => Patch::Clear,
+            MaybeUndefined::Value(value) => Patch::Set(value),
+        }
+    }
+}
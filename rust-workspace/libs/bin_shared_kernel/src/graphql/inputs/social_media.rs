@@ -1,17 +1,103 @@
-use std::collections::HashMap;
-
 use async_graphql::InputObject;
-use uuid::Uuid;
+use corelib::predule::{DomainError, SocialMediaMetadata};
 
-use crate::predule::SocialProfilePlatform;
+use crate::predule::{AudienceInput, DemographicEntryInput, SocialProfilePlatform};
 
+/// Profiles are saved unverified, use `requestProfileVerification` to have one verified
 #[derive(InputObject)]
 pub struct SocialProfileInput {
     pub platform: SocialProfilePlatform,
     pub profile_name: String,
     pub profile_link: String,
-    pub mark_for_verification: bool,
-    pub is_verified: bool,
     pub follower_count: u64,
-    pub demographics: Option<HashMap<Uuid, Vec<Uuid>>>,
+    pub demographics: Option<Vec<DemographicEntryInput>>,
+    pub audience: Option<AudienceInput>,
+}
+
+impl SocialProfileInput {
+    /// Validates the input into the domain value object
+    ///
+    /// `prefix` locates the profile in the request, e.g. `socialProfiles.2.`.
+    pub fn into_metadata(self, prefix: &str) -> Result<SocialMediaMetadata, DomainError> {
+        let audience = self
+            .audience
+            .map(|audience| audience.into_audience(&format!("{}audience", prefix)))
+            .transpose()?;
+
+        let metadata = SocialMediaMetadata::new(
+            self.platform.into(),
+            self.profile_name,
+            self.profile_link,
+            self.follower_count,
+            self.demographics
+                .map(DemographicEntryInput::into_demographics)
+                .transpose()?,
+        )?;
+        Ok(metadata.with_audience(audience))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Days, NaiveDate, Utc};
+    use uuid::Uuid;
+
+    use super::*;
+    use crate::predule::{AudienceShareInput, AudienceSource};
+
+    fn profile(as_of: NaiveDate, percentages: &[f64]) -> SocialProfileInput {
+        let taxonomy_id = Uuid::new_v4();
+        SocialProfileInput {
+            platform: SocialProfilePlatform::Instagram,
+            profile_name: "name".to_string(),
+            profile_link: "https://instagram.com/name".to_string(),
+            follower_count: 10,
+            demographics: None,
+            audience: Some(AudienceInput {
+                source: AudienceSource::SelfReported,
+                as_of,
+                shares: percentages
+                    .iter()
+                    .map(|&percentage| AudienceShareInput {
+                        taxonomy_id,
+                        term_id: Uuid::new_v4(),
+                        percentage,
+                    })
+                    .collect(),
+            }),
+        }
+    }
+
+    fn fields(input: SocialProfileInput) -> Vec<String> {
+        let error = input.into_metadata("socialProfiles.3.").unwrap_err();
+        error
+            .field_errors()
+            .iter()
+            .map(|e| e.field.clone())
+            .collect()
+    }
+
+    #[test]
+    fn audience_errors_are_reported_by_position_under_the_profile() {
+        let today = Utc::now().date_naive();
+
+        assert_eq!(
+            fields(profile(today, &[20.0, 150.0])),
+            vec!["socialProfiles.3.audience.shares.1.percentage"]
+        );
+        assert_eq!(
+            fields(profile(today, &[60.0, 0.001, 50.0])),
+            vec![
+                "socialProfiles.3.audience.shares.1.percentage",
+                "socialProfiles.3.audience.shares.2.percentage",
+            ]
+        );
+        assert_eq!(
+            fields(profile(
+                today.checked_add_days(Days::new(1)).unwrap(),
+                &[10.0]
+            )),
+            vec!["socialProfiles.3.audience.asOf"]
+        );
+    }
 }
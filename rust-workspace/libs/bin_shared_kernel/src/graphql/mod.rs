@@ -1,2 +1,3 @@
+pub mod extensions;
 pub mod types;
 pub mod inputs;
\ No newline at end of file
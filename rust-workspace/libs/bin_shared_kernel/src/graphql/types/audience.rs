@@ -0,0 +1,81 @@
+use async_graphql::{Enum, SimpleObject};
+use chrono::NaiveDate;
+use corelib::predule::{AudienceDemographics, AudienceSource as DomainAudienceSource};
+
+use crate::graphql::types::references::{TaxonomyRef, TermRef};
+
+/// Where audience figures come from
+#[derive(Enum, Clone, Copy, PartialEq, Eq)]
+pub enum AudienceSource {
+    SelfReported,
+    PlatformInsights,
+    ThirdParty,
+}
+
+impl From<DomainAudienceSource> for AudienceSource {
+    fn from(source: DomainAudienceSource) -> Self {
+        match source {
+            DomainAudienceSource::SelfReported => AudienceSource::SelfReported,
+            DomainAudienceSource::PlatformInsights => AudienceSource::PlatformInsights,
+            DomainAudienceSource::ThirdParty => AudienceSource::ThirdParty,
+        }
+    }
+}
+
+impl From<AudienceSource> for DomainAudienceSource {
+    fn from(source: AudienceSource) -> Self {
+        match source {
+            AudienceSource::SelfReported => DomainAudienceSource::SelfReported,
+            AudienceSource::PlatformInsights => DomainAudienceSource::PlatformInsights,
+            AudienceSource::ThirdParty => DomainAudienceSource::ThirdParty,
+        }
+    }
+}
+
+/// Part of the audience in one term, `percentage` from 0 to 100
+#[derive(SimpleObject)]
+pub struct AudienceShare {
+    pub term: TermRef,
+    pub percentage: f64,
+}
+
+/// Audience shares within one taxonomy
+#[derive(SimpleObject)]
+pub struct AudienceSegment {
+    pub taxonomy: TaxonomyRef,
+    pub shares: Vec<AudienceShare>,
+    /// Part of the audience the shares cover, at most 100
+    pub total_percentage: f64,
+}
+
+/// Weighted composition of a profile's audience
+#[derive(SimpleObject)]
+pub struct Audience {
+    pub source: AudienceSource,
+    /// Day the figures were taken
+    pub as_of: NaiveDate,
+    pub segments: Vec<AudienceSegment>,
+}
+
+impl From<&AudienceDemographics> for Audience {
+    fn from(value: &AudienceDemographics) -> Self {
+        Self {
+            source: value.source().into(),
+            as_of: value.as_of(),
+            segments: value
+                .iter()
+                .map(|(taxonomy_id, shares)| AudienceSegment {
+                    taxonomy: (*taxonomy_id).into(),
+                    shares: shares
+                        .iter()
+                        .map(|(term_id, share)| AudienceShare {
+                            term: (*term_id).into(),
+                            percentage: share.as_percent(),
+                        })
+                        .collect(),
+                    total_percentage: value.taxonomy_total(taxonomy_id).as_percent(),
+                })
+                .collect(),
+        }
+    }
+}
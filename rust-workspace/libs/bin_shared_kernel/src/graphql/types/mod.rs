@@ -1 +1,3 @@
+pub mod audience;
+pub mod references;
 pub mod social_media;
\ No newline at end of file
@@ -0,0 +1,54 @@
+use async_graphql::{ID, SimpleObject};
+use corelib::predule::{TaxonomyId, TermId, TermSelection};
+
+// Stub of a `Term` owned by the term subgraph, the router resolves its other fields.
+// Kept free of doc comments so the owner's description wins in the supergraph.
+#[derive(SimpleObject, Clone)]
+#[graphql(name = "Term", unresolvable = "id")]
+pub struct TermRef {
+    pub id: ID,
+}
+
+impl From<TermId> for TermRef {
+    fn from(value: TermId) -> Self {
+        Self {
+            id: value.as_uuid().into(),
+        }
+    }
+}
+
+// Stub of a `Taxonomy` owned by the taxonomy subgraph, the router resolves its other fields.
+// Kept free of doc comments so the owner's description wins in the supergraph.
+#[derive(SimpleObject, Clone)]
+#[graphql(name = "Taxonomy", unresolvable = "id")]
+pub struct TaxonomyRef {
+    pub id: ID,
+}
+
+impl From<TaxonomyId> for TaxonomyRef {
+    fn from(value: TaxonomyId) -> Self {
+        Self {
+            id: value.as_uuid().into(),
+        }
+    }
+}
+
+/// One taxonomy of the demographics with the terms picked in it
+#[derive(SimpleObject)]
+pub struct DemographicEntry {
+    pub taxonomy: TaxonomyRef,
+    pub terms: Vec<TermRef>,
+}
+
+impl DemographicEntry {
+    /// One entry per taxonomy, in the stable order `TermSelection` keeps
+    pub fn from_demographics(demographics: &TermSelection) -> Vec<Self> {
+        demographics
+            .iter()
+            .map(|(taxonomy_id, term_ids)| Self {
+                taxonomy: (*taxonomy_id).into(),
+                terms: term_ids.iter().copied().map(TermRef::from).collect(),
+            })
+            .collect()
+    }
+}
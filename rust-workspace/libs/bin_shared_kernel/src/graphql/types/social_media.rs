@@ -1,8 +1,7 @@
-use std::collections::HashMap;
-
 use async_graphql::{Enum, SimpleObject};
-use corelib::predule::{SocialMediaMetadata, SocialMediaPlatform};
-use uuid::Uuid;
+use corelib::predule::{SocialMediaMetadata, SocialMediaPlatform, VerificationStatus};
+
+use crate::graphql::types::{audience::Audience, references::DemographicEntry};
 
 #[derive(Enum, Clone, Copy, PartialEq, Eq)]
 pub enum SocialProfilePlatform {
@@ -40,15 +39,38 @@ impl From<SocialProfilePlatform> for SocialMediaPlatform {
     }
 }
 
+/// Where a social profile stands in the verification workflow
+#[derive(Enum, Clone, Copy, PartialEq, Eq)]
+pub enum ProfileVerificationStatus {
+    Unverified,
+    Pending,
+    Verified,
+    Rejected,
+}
+
+impl From<&VerificationStatus> for ProfileVerificationStatus {
+    fn from(status: &VerificationStatus) -> Self {
+        match status {
+            VerificationStatus::Unverified => ProfileVerificationStatus::Unverified,
+            VerificationStatus::Pending => ProfileVerificationStatus::Pending,
+            VerificationStatus::Verified => ProfileVerificationStatus::Verified,
+            VerificationStatus::Rejected { .. } => ProfileVerificationStatus::Rejected,
+        }
+    }
+}
+
 #[derive(SimpleObject)]
 pub struct SocialProfile {
     pub platform: SocialProfilePlatform,
     pub profile_name: String,
     pub profile_link: String,
-    pub mark_for_verification: bool,
+    pub verification_status: ProfileVerificationStatus,
+    /// Reviewer's reason, set when the verification was rejected
+    pub rejection_reason: Option<String>,
     pub is_verified: bool,
     pub follower_count: u64,
-    pub demographics: Option<HashMap<Uuid, Vec<Uuid>>>,
+    pub demographics: Option<Vec<DemographicEntry>>,
+    pub audience: Option<Audience>,
 }
 
 // From implementation for owned value
@@ -65,19 +87,17 @@ impl From<&SocialMediaMetadata> for SocialProfile {
             platform: value.platform().into(),
             profile_name: value.profile_name().to_string(),
             profile_link: value.profile_link().to_string(),
-            mark_for_verification: value.mark_for_verification(),
+            verification_status: value.verification().into(),
+            rejection_reason: match value.verification() {
+                VerificationStatus::Rejected { reason } => Some(reason.clone()),
+                _ => None,
+            },
             is_verified: value.is_verified(),
             follower_count: value.follower_count(),
-            demographics: value.demographics().map(|v| {
-                v.iter()
-                    .map(|(tax_id, terms)| {
-                        (
-                            tax_id.as_uuid(),
-                            terms.iter().map(|t| t.as_uuid()).collect(),
-                        )
-                    })
-                    .collect::<HashMap<Uuid, Vec<Uuid>>>()
-            }),
+            demographics: value
+                .demographics()
+                .map(DemographicEntry::from_demographics),
+            audience: value.audience().map(Audience::from),
         }
     }
 }
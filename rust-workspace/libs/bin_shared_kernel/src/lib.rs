@@ -1,7 +1,20 @@
 mod graphql;
 
 pub mod predule {
+    pub use crate::graphql::extensions::request_data::PerRequestData;
+    pub use crate::graphql::types::audience::Audience;
+    pub use crate::graphql::types::audience::AudienceSegment;
+    pub use crate::graphql::types::audience::AudienceShare;
+    pub use crate::graphql::types::audience::AudienceSource;
+    pub use crate::graphql::types::references::DemographicEntry;
+    pub use crate::graphql::types::references::TaxonomyRef;
+    pub use crate::graphql::types::references::TermRef;
+    pub use crate::graphql::types::social_media::ProfileVerificationStatus;
     pub use crate::graphql::types::social_media::SocialProfile;
     pub use crate::graphql::types::social_media::SocialProfilePlatform;
+    pub use crate::graphql::inputs::audience::AudienceInput;
+    pub use crate::graphql::inputs::audience::AudienceShareInput;
+    pub use crate::graphql::inputs::demographics::DemographicEntryInput;
+    pub use crate::graphql::inputs::patch::IntoPatch;
     pub use crate::graphql::inputs::social_media::SocialProfileInput;
 }
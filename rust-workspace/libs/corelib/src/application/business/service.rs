@@ -1,24 +1,60 @@
+use crate::application::shared::locks::AggregateLocks;
 use crate::domain::{
     business::{
         Business,
+        claim::ClaimMethod,
         events::BusinessDomainEvent,
-        ports::{event::BusinessDomainEventBus, respository::BusinessRepository},
+        membership::MemberRole,
+        ports::{
+            claim::{ClaimCodeMailer, WebsiteFetcher},
+            event::BusinessDomainEventBus,
+            respository::BusinessRepository,
+        },
+        specs::{ClaimAwaitingReviewSpec, InCategoriesSpec},
         value_objects::{
             BusinessDescription, BusinessFeatures, BusinessId, BusinessName, ContactInfo,
         },
     },
     shared::{
-        error::DomainError,
+        error::{DomainError, FieldError},
         event::EventMeta,
-        value_object::{EventId, OcurredAt, SocialMedia},
+        geo::{GeoArea, Nearby},
+        health::ComponentHealth,
+        patch::Patch,
+        ports::term_selection::TermSelectionValidator,
+        term_selection::TermSelection,
+        value_object::{EmailAddress, EventId, OcurredAt, SocialMedia},
+    },
+    user::{
+        ports::directory::UserDirectory,
+        value_object::{IdentityId, UserId},
     },
 };
 
 use crate::infrastructure::business::event_bus::in_memory_bus::InMemoryBusinessEventBus;
 
+/// Fields of a business to change, `None` and `Patch::Unchanged` leave a field as it is
+#[derive(Debug, Default)]
+pub struct BusinessChanges {
+    pub name: Option<BusinessName>,
+    pub description: Patch<BusinessDescription>,
+    pub contact_info: Patch<ContactInfo>,
+    pub social_media: Patch<SocialMedia>,
+    pub features: Patch<BusinessFeatures>,
+}
+
 pub struct BusinessService<R: BusinessRepository> {
     repo: R,
     bus: Box<dyn BusinessDomainEventBus>,
+    /// `Email` claims cannot be requested without it
+    claim_mailer: Option<Box<dyn ClaimCodeMailer>>,
+    /// `Website` claims cannot be proven without it
+    website_fetcher: Option<Box<dyn WebsiteFetcher>>,
+    /// Categories are stored unchecked when no validator is set
+    category_validator: Option<Box<dyn TermSelectionValidator>>,
+    /// `user_of` fails without it, so no caller can act as a member
+    user_directory: Option<Box<dyn UserDirectory>>,
+    locks: AggregateLocks<BusinessId>,
 }
 
 impl<R: BusinessRepository> BusinessService<R> {
@@ -26,6 +62,11 @@ impl<R: BusinessRepository> BusinessService<R> {
         Self {
             repo,
             bus: Box::new(InMemoryBusinessEventBus::default()),
+            claim_mailer: None,
+            website_fetcher: None,
+            category_validator: None,
+            user_directory: None,
+            locks: AggregateLocks::new(),
         }
     }
 
@@ -34,64 +75,433 @@ impl<R: BusinessRepository> BusinessService<R> {
         self
     }
 
+    pub fn with_claim_mailer(mut self, claim_mailer: Box<dyn ClaimCodeMailer>) -> Self {
+        self.claim_mailer = Some(claim_mailer);
+        self
+    }
+
+    pub fn with_website_fetcher(mut self, website_fetcher: Box<dyn WebsiteFetcher>) -> Self {
+        self.website_fetcher = Some(website_fetcher);
+        self
+    }
+
+    pub fn with_category_validator(mut self, validator: Box<dyn TermSelectionValidator>) -> Self {
+        self.category_validator = Some(validator);
+        self
+    }
+
+    pub fn with_user_directory(mut self, user_directory: Box<dyn UserDirectory>) -> Self {
+        self.user_directory = Some(user_directory);
+        self
+    }
+
+    /// Health of the repository, event bus, claim checks, category validator and user directory behind this service
+    pub async fn health(&self) -> Vec<ComponentHealth> {
+        let mut components = vec![self.repo.check().await, self.bus.check().await];
+        if let Some(mailer) = &self.claim_mailer {
+            components.push(mailer.check().await);
+        }
+        if let Some(fetcher) = &self.website_fetcher {
+            components.push(fetcher.check().await);
+        }
+        if let Some(validator) = &self.category_validator {
+            components.push(validator.check().await);
+        }
+        if let Some(directory) = &self.user_directory {
+            components.push(directory.check().await);
+        }
+        components
+    }
+
+    /// Flushes pending events and closes the repository, called once on shutdown
+    pub async fn shutdown(&self) -> Result<(), DomainError> {
+        self.bus
+            .flush()
+            .map_err(|e| DomainError::unavailable(format!("failed to flush events: {}", e)))?;
+        self.repo.close().await
+    }
+
+    /// User of the calling identity, `None` when it has not created one
+    ///
+    /// # Errors
+    /// `unavailable` when no user directory is set or it cannot be reached
+    pub async fn user_of(&self, identity_id: &IdentityId) -> Result<Option<UserId>, DomainError> {
+        let directory = self
+            .user_directory
+            .as_ref()
+            .ok_or(DomainError::unavailable("no user directory configured"))?;
+        directory.user_of(identity_id).await
+    }
+
     pub async fn find_by_id(&self, id: &BusinessId) -> Result<Option<Business>, DomainError> {
         let business = self.repo.find_by_id(id).await?;
         Ok(business)
     }
 
+    pub async fn find_by_ids(&self, ids: &[BusinessId]) -> Result<Vec<Business>, DomainError> {
+        self.repo.find_by_ids(ids).await
+    }
+
+    /// Live businesses `user_ids` are members of, oldest first
+    pub async fn find_by_members(&self, user_ids: &[UserId]) -> Result<Vec<Business>, DomainError> {
+        let mut businesses: Vec<Business> = self
+            .repo
+            .find_by_member_ids(user_ids)
+            .await?
+            .into_iter()
+            .filter(|business| !business.deleted.is_deleted())
+            .collect();
+        businesses.sort_by(|a, b| {
+            a.timestamps
+                .created_at()
+                .cmp(b.timestamps.created_at())
+                .then_with(|| a.id.cmp(&b.id))
+        });
+        Ok(businesses)
+    }
+
+    /// Businesses with a proven claim waiting for an admin, oldest first
+    pub async fn pending_claims(
+        &self,
+        limit: usize,
+        offset: usize,
+    ) -> Result<Vec<Business>, DomainError> {
+        self.repo
+            .query(&ClaimAwaitingReviewSpec, limit, offset)
+            .await
+    }
+
+    /// Live businesses filed under `categories`, see `InCategoriesSpec`, oldest first
+    pub async fn search(
+        &self,
+        categories: TermSelection,
+        limit: usize,
+        offset: usize,
+    ) -> Result<Vec<Business>, DomainError> {
+        self.repo
+            .query(&InCategoriesSpec { categories }, limit, offset)
+            .await
+    }
+
+    /// Businesses located in `area`, nearest first
+    pub async fn find_in_area(
+        &self,
+        area: &GeoArea,
+        limit: usize,
+        offset: usize,
+    ) -> Result<Vec<Nearby<Business>>, DomainError> {
+        self.repo.find_in_area(area, limit, offset).await
+    }
+
     pub async fn create_business(
-        &mut self,
+        &self,
         name: BusinessName,
         description: Option<BusinessDescription>,
         contact_info: Option<ContactInfo>,
         social_media: Option<SocialMedia>,
         features: Option<BusinessFeatures>,
+        owner: Option<UserId>,
     ) -> Result<Business, DomainError> {
         let id = BusinessId::new();
 
-        let mut business =
-            Business::new(id, name, description, contact_info, social_media, features);
+        let mut business = Business::new(
+            id,
+            name,
+            description,
+            contact_info,
+            social_media,
+            features,
+            owner,
+        );
 
         self.repo.save(&business).await?;
 
         let events = business.take_events();
         self.bus
             .publish(&events)
-            .map_err(|e| DomainError::conflict(format!("failed to publish events: {}", e)))?;
+            .map_err(|e| DomainError::unavailable(format!("failed to publish events: {}", e)))?;
         Ok(business)
     }
 
+    /// Applies `changes` on behalf of `actor`, who must be a member once the business has an owner
+    ///
+    /// Contact details are only ever changed by members, see `authorize_contact_edit`.
     pub async fn update_business(
-        &mut self,
+        &self,
         business_id: BusinessId,
-        name: Option<BusinessName>,
-        description: Option<BusinessDescription>,
-        contact_info: Option<ContactInfo>,
-        social_media: Option<SocialMedia>,
-        features: Option<BusinessFeatures>,
+        actor: Option<UserId>,
+        changes: BusinessChanges,
     ) -> Result<Business, DomainError> {
+        let _guard = self.locks.lock(&business_id).await;
+
         let mut business = self
             .repo
             .find_by_id(&business_id)
             .await?
             .ok_or(DomainError::not_found("business"))?;
 
-        if let Some(v) = name {
+        business.members.authorize_edit(actor.as_ref())?;
+        if !matches!(changes.contact_info, Patch::Unchanged) {
+            business.members.authorize_contact_edit(actor.as_ref())?;
+        }
+
+        if let Some(v) = changes.name {
             business.set_name(v);
         }
 
-        if let Some(v) = description {
-            business.set_description(Some(v));
+        if let Some(v) = changes.description.into_change() {
+            business.set_description(v);
         }
 
-        if let Some(v) = contact_info {
-            business.set_contact_info(Some(v));
+        if let Some(v) = changes.contact_info.into_change() {
+            business.set_contact_info(v);
         }
-        if let Some(v) = social_media {
-            business.set_social_media(Some(v));
+        if let Some(v) = changes.social_media.into_change() {
+            business.set_social_media(v);
         }
-        if let Some(v) = features {
-            business.set_features(Some(v));
+        if let Some(v) = changes.features.into_change() {
+            business.set_features(v);
+        }
+
+        self.repo.save(&business).await?;
+
+        let events = business.take_events();
+        self.bus
+            .publish(&events)
+            .map_err(|e| DomainError::unavailable(format!("failed to publish events: {}", e)))?;
+        Ok(business)
+    }
+
+    /// Replaces the categories of a business, empty `categories` clear them
+    pub async fn set_categories(
+        &self,
+        business_id: BusinessId,
+        actor: Option<UserId>,
+        categories: TermSelection,
+    ) -> Result<Business, DomainError> {
+        self.validate_categories(&categories).await?;
+
+        let _guard = self.locks.lock(&business_id).await;
+
+        let mut business = self
+            .repo
+            .find_by_id(&business_id)
+            .await?
+            .ok_or(DomainError::not_found("business"))?;
+
+        business.members.authorize_edit(actor.as_ref())?;
+
+        business.set_categories(categories);
+
+        self.repo.save(&business).await?;
+
+        let events = business.take_events();
+        self.bus
+            .publish(&events)
+            .map_err(|e| DomainError::unavailable(format!("failed to publish events: {}", e)))?;
+        Ok(business)
+    }
+
+    pub async fn invite_member(
+        &self,
+        business_id: BusinessId,
+        actor: UserId,
+        user_id: UserId,
+        role: MemberRole,
+    ) -> Result<Business, DomainError> {
+        let _guard = self.locks.lock(&business_id).await;
+
+        let mut business = self
+            .repo
+            .find_by_id(&business_id)
+            .await?
+            .ok_or(DomainError::not_found("business"))?;
+
+        business.invite_member(actor, user_id, role)?;
+
+        self.repo.save(&business).await?;
+
+        let events = business.take_events();
+        self.bus
+            .publish(&events)
+            .map_err(|e| DomainError::unavailable(format!("failed to publish events: {}", e)))?;
+        Ok(business)
+    }
+
+    pub async fn accept_invitation(
+        &self,
+        business_id: BusinessId,
+        user_id: UserId,
+    ) -> Result<Business, DomainError> {
+        let _guard = self.locks.lock(&business_id).await;
+
+        let mut business = self
+            .repo
+            .find_by_id(&business_id)
+            .await?
+            .ok_or(DomainError::not_found("business"))?;
+
+        business.accept_invitation(user_id)?;
+
+        self.repo.save(&business).await?;
+
+        let events = business.take_events();
+        self.bus
+            .publish(&events)
+            .map_err(|e| DomainError::unavailable(format!("failed to publish events: {}", e)))?;
+        Ok(business)
+    }
+
+    pub async fn decline_invitation(
+        &self,
+        business_id: BusinessId,
+        user_id: UserId,
+    ) -> Result<Business, DomainError> {
+        let _guard = self.locks.lock(&business_id).await;
+
+        let mut business = self
+            .repo
+            .find_by_id(&business_id)
+            .await?
+            .ok_or(DomainError::not_found("business"))?;
+
+        business.decline_invitation(user_id)?;
+
+        self.repo.save(&business).await?;
+
+        let events = business.take_events();
+        self.bus
+            .publish(&events)
+            .map_err(|e| DomainError::unavailable(format!("failed to publish events: {}", e)))?;
+        Ok(business)
+    }
+
+    pub async fn revoke_invitation(
+        &self,
+        business_id: BusinessId,
+        actor: UserId,
+        user_id: UserId,
+    ) -> Result<Business, DomainError> {
+        let _guard = self.locks.lock(&business_id).await;
+
+        let mut business = self
+            .repo
+            .find_by_id(&business_id)
+            .await?
+            .ok_or(DomainError::not_found("business"))?;
+
+        business.revoke_invitation(actor, user_id)?;
+
+        self.repo.save(&business).await?;
+
+        let events = business.take_events();
+        self.bus
+            .publish(&events)
+            .map_err(|e| DomainError::unavailable(format!("failed to publish events: {}", e)))?;
+        Ok(business)
+    }
+
+    pub async fn change_member_role(
+        &self,
+        business_id: BusinessId,
+        actor: UserId,
+        user_id: UserId,
+        role: MemberRole,
+    ) -> Result<Business, DomainError> {
+        let _guard = self.locks.lock(&business_id).await;
+
+        let mut business = self
+            .repo
+            .find_by_id(&business_id)
+            .await?
+            .ok_or(DomainError::not_found("business"))?;
+
+        business.change_member_role(actor, user_id, role)?;
+
+        self.repo.save(&business).await?;
+
+        let events = business.take_events();
+        self.bus
+            .publish(&events)
+            .map_err(|e| DomainError::unavailable(format!("failed to publish events: {}", e)))?;
+        Ok(business)
+    }
+
+    pub async fn remove_member(
+        &self,
+        business_id: BusinessId,
+        actor: UserId,
+        user_id: UserId,
+    ) -> Result<Business, DomainError> {
+        let _guard = self.locks.lock(&business_id).await;
+
+        let mut business = self
+            .repo
+            .find_by_id(&business_id)
+            .await?
+            .ok_or(DomainError::not_found("business"))?;
+
+        business.remove_member(actor, user_id)?;
+
+        self.repo.save(&business).await?;
+
+        let events = business.take_events();
+        self.bus
+            .publish(&events)
+            .map_err(|e| DomainError::unavailable(format!("failed to publish events: {}", e)))?;
+        Ok(business)
+    }
+
+    pub async fn transfer_ownership(
+        &self,
+        business_id: BusinessId,
+        actor: UserId,
+        to: UserId,
+    ) -> Result<Business, DomainError> {
+        let _guard = self.locks.lock(&business_id).await;
+
+        let mut business = self
+            .repo
+            .find_by_id(&business_id)
+            .await?
+            .ok_or(DomainError::not_found("business"))?;
+
+        business.transfer_ownership(actor, to)?;
+
+        self.repo.save(&business).await?;
+
+        let events = business.take_events();
+        self.bus
+            .publish(&events)
+            .map_err(|e| DomainError::unavailable(format!("failed to publish events: {}", e)))?;
+        Ok(business)
+    }
+
+    /// Opens a claim by `claimant` and, for `Email` claims, mails them the code
+    pub async fn request_claim(
+        &self,
+        business_id: BusinessId,
+        claimant: UserId,
+        method: ClaimMethod,
+        email: Option<EmailAddress>,
+    ) -> Result<Business, DomainError> {
+        let _guard = self.locks.lock(&business_id).await;
+
+        let mut business = self
+            .repo
+            .find_by_id(&business_id)
+            .await?
+            .ok_or(DomainError::not_found("business"))?;
+
+        let claim = business.request_claim(claimant, method, email)?.clone();
+        if let Some(email) = claim.email() {
+            let mailer = self
+                .claim_mailer
+                .as_ref()
+                .ok_or(DomainError::unavailable("no claim mailer configured"))?;
+            mailer
+                .send_claim_code(email, &business.name, claim.code())
+                .await?;
         }
 
         self.repo.save(&business).await?;
@@ -99,20 +509,116 @@ impl<R: BusinessRepository> BusinessService<R> {
         let events = business.take_events();
         self.bus
             .publish(&events)
-            .map_err(|e| DomainError::conflict(format!("failed to publish events: {}", e)))?;
+            .map_err(|e| DomainError::unavailable(format!("failed to publish events: {}", e)))?;
+        Ok(business)
+    }
+
+    /// Proves the open claim of `claimant`
+    ///
+    /// `Email` claims are proven by the mailed `code`, `Website` claims by the
+    /// code showing up on the website the claim was opened against, fetched here.
+    pub async fn prove_claim(
+        &self,
+        business_id: BusinessId,
+        claimant: UserId,
+        code: Option<String>,
+    ) -> Result<Business, DomainError> {
+        let _guard = self.locks.lock(&business_id).await;
+
+        let mut business = self
+            .repo
+            .find_by_id(&business_id)
+            .await?
+            .ok_or(DomainError::not_found("business"))?;
+
+        let claim = business
+            .claim
+            .as_ref()
+            .ok_or(DomainError::not_found("claim"))?;
+        let evidence = match claim.method() {
+            ClaimMethod::Email => {
+                code.ok_or(DomainError::validation("the mailed code is needed"))?
+            }
+            ClaimMethod::Website => {
+                let website = claim.website().ok_or(DomainError::validation(
+                    "business has no website to claim it by",
+                ))?;
+                let fetcher = self
+                    .website_fetcher
+                    .as_ref()
+                    .ok_or(DomainError::unavailable("no website fetcher configured"))?;
+                fetcher.fetch(website).await?
+            }
+        };
+        business.prove_claim(claimant, &evidence)?;
+
+        self.repo.save(&business).await?;
+
+        let events = business.take_events();
+        self.bus
+            .publish(&events)
+            .map_err(|e| DomainError::unavailable(format!("failed to publish events: {}", e)))?;
+        Ok(business)
+    }
+
+    pub async fn approve_claim(&self, business_id: BusinessId) -> Result<Business, DomainError> {
+        let _guard = self.locks.lock(&business_id).await;
+
+        let mut business = self
+            .repo
+            .find_by_id(&business_id)
+            .await?
+            .ok_or(DomainError::not_found("business"))?;
+
+        business.approve_claim()?;
+
+        self.repo.save(&business).await?;
+
+        let events = business.take_events();
+        self.bus
+            .publish(&events)
+            .map_err(|e| DomainError::unavailable(format!("failed to publish events: {}", e)))?;
+        Ok(business)
+    }
+
+    pub async fn reject_claim(
+        &self,
+        business_id: BusinessId,
+        reason: String,
+    ) -> Result<Business, DomainError> {
+        let _guard = self.locks.lock(&business_id).await;
+
+        let mut business = self
+            .repo
+            .find_by_id(&business_id)
+            .await?
+            .ok_or(DomainError::not_found("business"))?;
+
+        business.reject_claim(reason)?;
+
+        self.repo.save(&business).await?;
+
+        let events = business.take_events();
+        self.bus
+            .publish(&events)
+            .map_err(|e| DomainError::unavailable(format!("failed to publish events: {}", e)))?;
         Ok(business)
     }
 
     pub async fn soft_delete_business(
-        &mut self,
+        &self,
         business_id: BusinessId,
+        actor: Option<UserId>,
     ) -> Result<Business, DomainError> {
+        let _guard = self.locks.lock(&business_id).await;
+
         let mut business = self
             .repo
             .find_by_id(&business_id)
             .await?
             .ok_or(DomainError::not_found("business"))?;
 
+        business.members.authorize_delete(actor.as_ref())?;
         business.mark_as_deleted();
 
         self.repo.save(&business).await?;
@@ -120,20 +626,24 @@ impl<R: BusinessRepository> BusinessService<R> {
         let events = business.take_events();
         self.bus
             .publish(&events)
-            .map_err(|e| DomainError::conflict(format!("failed to publish events: {}", e)))?;
+            .map_err(|e| DomainError::unavailable(format!("failed to publish events: {}", e)))?;
         Ok(business)
     }
 
     pub async fn restore_soft_deleted_business(
-        &mut self,
+        &self,
         business_id: BusinessId,
+        actor: Option<UserId>,
     ) -> Result<Business, DomainError> {
+        let _guard = self.locks.lock(&business_id).await;
+
         let mut business = self
             .repo
             .find_by_id(&business_id)
             .await?
             .ok_or(DomainError::not_found("business"))?;
 
+        business.members.authorize_delete(actor.as_ref())?;
         business.restore_from_deleted();
 
         self.repo.save(&business).await?;
@@ -141,20 +651,25 @@ impl<R: BusinessRepository> BusinessService<R> {
         let events = business.take_events();
         self.bus
             .publish(&events)
-            .map_err(|e| DomainError::conflict(format!("failed to publish events: {}", e)))?;
+            .map_err(|e| DomainError::unavailable(format!("failed to publish events: {}", e)))?;
         Ok(business)
     }
 
     pub async fn permanetly_delete_business(
-        &mut self,
+        &self,
         business_id: BusinessId,
+        actor: Option<UserId>,
     ) -> Result<(), DomainError> {
+        let _guard = self.locks.lock(&business_id).await;
+
         let business = self
             .repo
             .find_by_id(&business_id)
             .await?
             .ok_or(DomainError::not_found("business"))?;
 
+        business.members.authorize_delete(actor.as_ref())?;
+
         self.repo.delete(&business.id).await?;
 
         let events = vec![BusinessDomainEvent::BusinessDeleted {
@@ -169,7 +684,232 @@ impl<R: BusinessRepository> BusinessService<R> {
 
         self.bus
             .publish(&events)
-            .map_err(|e| DomainError::conflict(format!("failed to publish events: {}", e)))?;
+            .map_err(|e| DomainError::unavailable(format!("failed to publish events: {}", e)))?;
         Ok(())
     }
+
+    /// Rejects categories that reference unknown, deleted, hidden or mismatched catalogue entries
+    async fn validate_categories(&self, categories: &TermSelection) -> Result<(), DomainError> {
+        let Some(validator) = &self.category_validator else {
+            return Ok(());
+        };
+
+        let violations: Vec<FieldError> = validator.validate("categories", categories).await?;
+        if violations.is_empty() {
+            Ok(())
+        } else {
+            Err(DomainError::invalid_fields(violations))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        domain::{business::claim::ClaimStatus, shared::value_object::WebsiteUrl},
+        infrastructure::business::{
+            claim::in_memory_impl::{InMemoryClaimCodeMailer, InMemoryWebsiteFetcher},
+            repository::in_memory_impl::InMemoryBusinessRepository,
+        },
+    };
+
+    const WEBSITE: &str = "https://cafe.example/";
+
+    struct Claims {
+        service: BusinessService<InMemoryBusinessRepository>,
+        mailer: InMemoryClaimCodeMailer,
+        fetcher: InMemoryWebsiteFetcher,
+    }
+
+    /// A service over one listed, unowned business with a contact email and website
+    async fn claims() -> (Claims, BusinessId) {
+        let mailer = InMemoryClaimCodeMailer::new();
+        let fetcher = InMemoryWebsiteFetcher::new();
+        let service = BusinessService::new(InMemoryBusinessRepository::new())
+            .with_claim_mailer(Box::new(mailer.clone()))
+            .with_website_fetcher(Box::new(fetcher.clone()));
+
+        let contact_info = ContactInfo::new(
+            Some(EmailAddress::new("info@cafe.example").unwrap()),
+            None,
+            None,
+            Some(WebsiteUrl::new(WEBSITE).unwrap()),
+        );
+        let business = service
+            .create_business(
+                BusinessName::new("Cafe").unwrap(),
+                None,
+                Some(contact_info),
+                None,
+                None,
+                None,
+            )
+            .await
+            .unwrap();
+
+        (
+            Claims {
+                service,
+                mailer,
+                fetcher,
+            },
+            business.id,
+        )
+    }
+
+    fn mailbox() -> EmailAddress {
+        EmailAddress::new("owner@cafe.example").unwrap()
+    }
+
+    #[tokio::test]
+    async fn an_approved_email_claim_verifies_the_business_and_adds_the_owner() {
+        let (claims, id) = claims().await;
+        let claimant = UserId::new();
+
+        claims
+            .service
+            .request_claim(id, claimant, ClaimMethod::Email, Some(mailbox()))
+            .await
+            .unwrap();
+        let code = claims.mailer.last_code_for(&mailbox()).unwrap();
+        let proven = claims
+            .service
+            .prove_claim(id, claimant, Some(code))
+            .await
+            .unwrap();
+        let claim = proven.claim.as_ref().unwrap();
+        assert_eq!(claim.status(), &ClaimStatus::AwaitingReview);
+        assert_eq!(claim.email_domain(), Some("cafe.example"));
+        let queue = claims.service.pending_claims(10, 0).await.unwrap();
+        assert_eq!(queue.iter().map(|b| b.id).collect::<Vec<_>>(), [id]);
+
+        let approved = claims.service.approve_claim(id).await.unwrap();
+
+        assert!(approved.verification.is_verified());
+        assert!(approved.claim.is_none());
+        assert_eq!(approved.members.role_of(&claimant), Some(MemberRole::Owner));
+        assert!(matches!(
+            claims
+                .service
+                .update_business(id, None, BusinessChanges::default())
+                .await,
+            Err(DomainError::Forbidden { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn a_website_claim_is_proven_on_the_website_it_was_opened_against() {
+        let (claims, id) = claims().await;
+        let claimant = UserId::new();
+
+        let requested = claims
+            .service
+            .request_claim(id, claimant, ClaimMethod::Website, None)
+            .await
+            .unwrap();
+        let code = requested.claim.as_ref().unwrap().code().to_owned();
+        claims.fetcher.set_page(
+            WEBSITE,
+            format!("<meta name=\"claim\" content=\"{}\">", code),
+        );
+
+        // The claimant cannot point the unowned listing at a site they control
+        let elsewhere = ContactInfo::new(
+            None,
+            None,
+            None,
+            Some(WebsiteUrl::new("https://elsewhere.example/").unwrap()),
+        );
+        let changes = BusinessChanges {
+            contact_info: Patch::Set(elsewhere),
+            ..BusinessChanges::default()
+        };
+        assert!(matches!(
+            claims
+                .service
+                .update_business(id, Some(claimant), changes)
+                .await,
+            Err(DomainError::Forbidden { .. })
+        ));
+
+        let proven = claims
+            .service
+            .prove_claim(id, claimant, None)
+            .await
+            .unwrap();
+        let claim = proven.claim.as_ref().unwrap();
+        assert_eq!(claim.website().map(WebsiteUrl::as_str), Some(WEBSITE));
+        assert!(claim.is_awaiting_review());
+
+        let rejected = claims
+            .service
+            .reject_claim(id, "not the owner".to_owned())
+            .await
+            .unwrap();
+
+        assert_eq!(
+            rejected.claim.as_ref().unwrap().status(),
+            &ClaimStatus::Rejected {
+                reason: "not the owner".to_owned()
+            }
+        );
+        assert!(!rejected.verification.is_verified());
+        assert!(!rejected.members.has_owner());
+    }
+
+    #[tokio::test]
+    async fn a_wrong_code_leaves_the_claim_awaiting_proof() {
+        let (claims, id) = claims().await;
+        let claimant = UserId::new();
+        claims
+            .service
+            .request_claim(id, claimant, ClaimMethod::Email, Some(mailbox()))
+            .await
+            .unwrap();
+
+        let result = claims
+            .service
+            .prove_claim(id, claimant, Some("000000000000".to_owned()))
+            .await;
+
+        assert!(matches!(result, Err(DomainError::Validation { .. })));
+        let business = claims.service.find_by_id(&id).await.unwrap().unwrap();
+        assert_eq!(
+            business.claim.as_ref().unwrap().status(),
+            &ClaimStatus::AwaitingProof
+        );
+        assert!(
+            claims
+                .service
+                .pending_claims(10, 0)
+                .await
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[tokio::test]
+    async fn another_users_open_claim_can_be_neither_replaced_nor_proven() {
+        let (claims, id) = claims().await;
+        let (claimant, other) = (UserId::new(), UserId::new());
+        claims
+            .service
+            .request_claim(id, claimant, ClaimMethod::Email, Some(mailbox()))
+            .await
+            .unwrap();
+        let code = claims.mailer.last_code_for(&mailbox()).unwrap();
+
+        let replaced = claims
+            .service
+            .request_claim(id, other, ClaimMethod::Website, None)
+            .await;
+        let proven = claims.service.prove_claim(id, other, Some(code)).await;
+
+        assert!(matches!(replaced, Err(DomainError::Conflict { .. })));
+        assert!(matches!(proven, Err(DomainError::Forbidden { .. })));
+        assert_eq!(claims.mailer.sent().len(), 1);
+        let business = claims.service.find_by_id(&id).await.unwrap().unwrap();
+        assert_eq!(business.claim.as_ref().unwrap().claimant(), claimant);
+    }
 }
@@ -1,3 +1,4 @@
+pub mod shared;
 pub mod user;
 pub mod term;
 pub mod taxonomy;
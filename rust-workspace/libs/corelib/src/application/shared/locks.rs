@@ -0,0 +1,109 @@
+use std::{hash::Hash, sync::Arc};
+
+use dashmap::DashMap;
+use tokio::sync::{Mutex, OwnedMutexGuard};
+
+/// Serialises writes to the same aggregate while every other call runs concurrently
+///
+/// Services load, change and save an aggregate in separate repository calls;
+/// holding the aggregate's lock across them keeps two concurrent updates from
+/// overwriting each other. A lock is dropped from the map once its last holder
+/// or waiter is gone, so the map only grows with the aggregates in flight.
+pub struct AggregateLocks<K: Eq + Hash> {
+    locks: Arc<DashMap<K, Arc<Mutex<()>>>>,
+}
+
+impl<K: Eq + Hash + Clone> AggregateLocks<K> {
+    pub fn new() -> Self {
+        Self {
+            locks: Arc::new(DashMap::new()),
+        }
+    }
+
+    /// Waits for the write lock of aggregate `id`, held until the guard is dropped
+    pub async fn lock(&self, id: &K) -> AggregateLockGuard<K> {
+        let lock = self.locks.entry(id.clone()).or_default().clone();
+        let guard = lock.lock_owned().await;
+        AggregateLockGuard {
+            locks: self.locks.clone(),
+            id: id.clone(),
+            guard: Some(guard),
+        }
+    }
+}
+
+impl<K: Eq + Hash + Clone> Default for AggregateLocks<K> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Holds the write lock of one aggregate, see `AggregateLocks::lock`
+pub struct AggregateLockGuard<K: Eq + Hash> {
+    locks: Arc<DashMap<K, Arc<Mutex<()>>>>,
+    id: K,
+    guard: Option<OwnedMutexGuard<()>>,
+}
+
+impl<K: Eq + Hash> Drop for AggregateLockGuard<K> {
+    fn drop(&mut self) {
+        // release the mutex first so its Arc is only shared with the map and any waiters
+        self.guard.take();
+        // waiters hold a clone, and `remove_if` holds the shard lock, so nobody can
+        // pick the entry up between the check and the removal
+        self.locks
+            .remove_if(&self.id, |_, lock| Arc::strong_count(lock) == 1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn evicts_the_lock_once_released() {
+        let locks = AggregateLocks::new();
+
+        let guard = locks.lock(&1).await;
+        assert_eq!(locks.locks.len(), 1);
+
+        drop(guard);
+        assert!(locks.locks.is_empty());
+    }
+
+    #[tokio::test]
+    async fn keeps_the_lock_while_someone_waits_for_it() {
+        let locks = Arc::new(AggregateLocks::new());
+        let guard = locks.lock(&1).await;
+
+        let waiter = tokio::spawn({
+            let locks = locks.clone();
+            async move {
+                let _guard = locks.lock(&1).await;
+            }
+        });
+        // let the waiter clone the lock and park on it
+        tokio::time::sleep(Duration::from_millis(20)).await;
+
+        drop(guard);
+        assert_eq!(locks.locks.len(), 1);
+
+        waiter.await.unwrap();
+        assert!(locks.locks.is_empty());
+    }
+
+    #[tokio::test]
+    async fn serialises_the_same_aggregate_only() {
+        let locks = AggregateLocks::new();
+
+        let first = locks.lock(&1).await;
+        let other = tokio::time::timeout(Duration::from_millis(20), locks.lock(&2)).await;
+        let same = tokio::time::timeout(Duration::from_millis(20), locks.lock(&1)).await;
+
+        assert!(other.is_ok());
+        assert!(same.is_err());
+        drop(first);
+    }
+}
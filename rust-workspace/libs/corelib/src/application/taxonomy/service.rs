@@ -1,7 +1,10 @@
+use crate::application::shared::locks::AggregateLocks;
 use crate::domain::{
     shared::{
         error::DomainError,
         event::EventMeta,
+        health::ComponentHealth,
+        patch::Patch,
         value_object::{EventId, OcurredAt},
     },
     taxonomy::{
@@ -17,6 +20,7 @@ use crate::infrastructure::taxonomy::event_bus::in_memory_bus::InMemoryTaxonomyE
 pub struct TaxonomyService<R: TaxonomyRepository> {
     repo: R,
     bus: Box<dyn TaxonomyDomainEventBus>,
+    locks: AggregateLocks<TaxonomyId>,
 }
 
 impl<R: TaxonomyRepository> TaxonomyService<R> {
@@ -24,6 +28,7 @@ impl<R: TaxonomyRepository> TaxonomyService<R> {
         Self {
             repo,
             bus: Box::new(InMemoryTaxonomyEventBus::default()),
+            locks: AggregateLocks::new(),
         }
     }
 
@@ -32,13 +37,30 @@ impl<R: TaxonomyRepository> TaxonomyService<R> {
         self
     }
 
+    /// Health of the repository and event bus behind this service
+    pub async fn health(&self) -> Vec<ComponentHealth> {
+        vec![self.repo.check().await, self.bus.check().await]
+    }
+
+    /// Flushes pending events and closes the repository, called once on shutdown
+    pub async fn shutdown(&self) -> Result<(), DomainError> {
+        self.bus
+            .flush()
+            .map_err(|e| DomainError::unavailable(format!("failed to flush events: {}", e)))?;
+        self.repo.close().await
+    }
+
     pub async fn find_by_id(&self, id: &TaxonomyId) -> Result<Option<Taxonomy>, DomainError> {
         let taxonomy = self.repo.find_by_id(id).await?;
         Ok(taxonomy)
     }
 
+    pub async fn find_by_ids(&self, ids: &[TaxonomyId]) -> Result<Vec<Taxonomy>, DomainError> {
+        self.repo.find_by_ids(ids).await
+    }
+
     pub async fn create_taxonomy(
-        &mut self,
+        &self,
         parent_id: Option<TaxonomyId>,
         name: TaxonomyName,
         visible: bool,
@@ -53,25 +75,27 @@ impl<R: TaxonomyRepository> TaxonomyService<R> {
         let events = taxonomy.take_events();
         self.bus
             .publish(&events)
-            .map_err(|e| DomainError::conflict(format!("failed to publish events: {}", e)))?;
+            .map_err(|e| DomainError::unavailable(format!("failed to publish events: {}", e)))?;
         Ok(taxonomy)
     }
 
     pub async fn update_taxonomy(
-        &mut self,
+        &self,
         taxonomy_id: TaxonomyId,
-        parent_id: Option<TaxonomyId>,
+        parent_id: Patch<TaxonomyId>,
         name: Option<TaxonomyName>,
         visible: Option<bool>,
-        description: Option<TaxonomyDescription>,
+        description: Patch<TaxonomyDescription>,
     ) -> Result<Taxonomy, DomainError> {
+        let _guard = self.locks.lock(&taxonomy_id).await;
+
         let mut taxonomy = self
             .repo
             .find_by_id(&taxonomy_id)
             .await?
             .ok_or(DomainError::not_found("taxonomy"))?;
 
-        if let Some(v) = parent_id {
+        if let Some(v) = parent_id.into_change() {
             taxonomy.set_parent_id(v);
         }
 
@@ -81,7 +105,7 @@ impl<R: TaxonomyRepository> TaxonomyService<R> {
         if let Some(v) = visible {
             taxonomy.set_visible(v);
         }
-        if let Some(v) = description {
+        if let Some(v) = description.into_change() {
             taxonomy.set_description(v);
         }
 
@@ -90,14 +114,16 @@ impl<R: TaxonomyRepository> TaxonomyService<R> {
         let events = taxonomy.take_events();
         self.bus
             .publish(&events)
-            .map_err(|e| DomainError::conflict(format!("failed to publish events: {}", e)))?;
+            .map_err(|e| DomainError::unavailable(format!("failed to publish events: {}", e)))?;
         Ok(taxonomy)
     }
 
     pub async fn soft_delete_taxonomy(
-        &mut self,
+        &self,
         taxonomy_id: TaxonomyId,
     ) -> Result<Taxonomy, DomainError> {
+        let _guard = self.locks.lock(&taxonomy_id).await;
+
         let mut taxonomy = self
             .repo
             .find_by_id(&taxonomy_id)
@@ -111,14 +137,16 @@ impl<R: TaxonomyRepository> TaxonomyService<R> {
         let events = taxonomy.take_events();
         self.bus
             .publish(&events)
-            .map_err(|e| DomainError::conflict(format!("failed to publish events: {}", e)))?;
+            .map_err(|e| DomainError::unavailable(format!("failed to publish events: {}", e)))?;
         Ok(taxonomy)
     }
 
     pub async fn restore_soft_deleted_taxonomy(
-        &mut self,
+        &self,
         taxonomy_id: TaxonomyId,
     ) -> Result<Taxonomy, DomainError> {
+        let _guard = self.locks.lock(&taxonomy_id).await;
+
         let mut taxonomy = self
             .repo
             .find_by_id(&taxonomy_id)
@@ -132,14 +160,16 @@ impl<R: TaxonomyRepository> TaxonomyService<R> {
         let events = taxonomy.take_events();
         self.bus
             .publish(&events)
-            .map_err(|e| DomainError::conflict(format!("failed to publish events: {}", e)))?;
+            .map_err(|e| DomainError::unavailable(format!("failed to publish events: {}", e)))?;
         Ok(taxonomy)
     }
 
     pub async fn permanetly_delete_taxonomy(
-        &mut self,
+        &self,
         taxonomy_id: TaxonomyId,
     ) -> Result<(), DomainError> {
+        let _guard = self.locks.lock(&taxonomy_id).await;
+
         let taxonomy = self
             .repo
             .find_by_id(&taxonomy_id)
@@ -160,7 +190,7 @@ impl<R: TaxonomyRepository> TaxonomyService<R> {
 
         self.bus
             .publish(&events)
-            .map_err(|e| DomainError::conflict(format!("failed to publish events: {}", e)))?;
+            .map_err(|e| DomainError::unavailable(format!("failed to publish events: {}", e)))?;
         Ok(())
     }
 }
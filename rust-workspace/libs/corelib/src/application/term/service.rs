@@ -1,7 +1,10 @@
+use crate::application::shared::locks::AggregateLocks;
 use crate::domain::{
     shared::{
         error::DomainError,
         event::EventMeta,
+        health::ComponentHealth,
+        patch::Patch,
         value_object::{EventId, OcurredAt},
     },
     taxonomy::value_objects::TaxonomyId,
@@ -18,6 +21,7 @@ use crate::infrastructure::term::event_bus::in_memory_bus::InMemoryTermEventBus;
 pub struct TermService<R: TermRepository> {
     repo: R,
     bus: Box<dyn TermDomainEventBus>,
+    locks: AggregateLocks<TermId>,
 }
 
 impl<R: TermRepository> TermService<R> {
@@ -25,6 +29,7 @@ impl<R: TermRepository> TermService<R> {
         Self {
             repo,
             bus: Box::new(InMemoryTermEventBus::default()),
+            locks: AggregateLocks::new(),
         }
     }
 
@@ -33,13 +38,30 @@ impl<R: TermRepository> TermService<R> {
         self
     }
 
+    /// Health of the repository and event bus behind this service
+    pub async fn health(&self) -> Vec<ComponentHealth> {
+        vec![self.repo.check().await, self.bus.check().await]
+    }
+
+    /// Flushes pending events and closes the repository, called once on shutdown
+    pub async fn shutdown(&self) -> Result<(), DomainError> {
+        self.bus
+            .flush()
+            .map_err(|e| DomainError::unavailable(format!("failed to flush events: {}", e)))?;
+        self.repo.close().await
+    }
+
     pub async fn find_by_id(&self, id: &TermId) -> Result<Option<Term>, DomainError> {
         let term = self.repo.find_by_id(id).await?;
         Ok(term)
     }
 
+    pub async fn find_by_ids(&self, ids: &[TermId]) -> Result<Vec<Term>, DomainError> {
+        self.repo.find_by_ids(ids).await
+    }
+
     pub async fn create_term(
-        &mut self,
+        &self,
         taxonomy_id: TaxonomyId,
         parent_id: Option<TermId>,
         name: TermName,
@@ -55,19 +77,21 @@ impl<R: TermRepository> TermService<R> {
         let events = term.take_events();
         self.bus
             .publish(&events)
-            .map_err(|e| DomainError::conflict(format!("failed to publish events: {}", e)))?;
+            .map_err(|e| DomainError::unavailable(format!("failed to publish events: {}", e)))?;
         Ok(term)
     }
 
     pub async fn update_term(
-        &mut self,
+        &self,
         term_id: TermId,
         taxonomy_id: Option<TaxonomyId>,
-        parent_id: Option<TermId>,
+        parent_id: Patch<TermId>,
         name: Option<TermName>,
         visible: Option<bool>,
-        description: Option<TermDescription>,
+        description: Patch<TermDescription>,
     ) -> Result<Term, DomainError> {
+        let _guard = self.locks.lock(&term_id).await;
+
         let mut term = self
             .repo
             .find_by_id(&term_id)
@@ -78,7 +102,7 @@ impl<R: TermRepository> TermService<R> {
             term.set_taxonomy_id(v);
         }
 
-        if let Some(v) = parent_id {
+        if let Some(v) = parent_id.into_change() {
             term.set_parent_id(v);
         }
 
@@ -88,7 +112,7 @@ impl<R: TermRepository> TermService<R> {
         if let Some(v) = visible {
             term.set_visible(v);
         }
-        if let Some(v) = description {
+        if let Some(v) = description.into_change() {
             term.set_description(v);
         }
 
@@ -97,11 +121,13 @@ impl<R: TermRepository> TermService<R> {
         let events = term.take_events();
         self.bus
             .publish(&events)
-            .map_err(|e| DomainError::conflict(format!("failed to publish events: {}", e)))?;
+            .map_err(|e| DomainError::unavailable(format!("failed to publish events: {}", e)))?;
         Ok(term)
     }
 
-    pub async fn soft_delete_term(&mut self, term_id: TermId) -> Result<Term, DomainError> {
+    pub async fn soft_delete_term(&self, term_id: TermId) -> Result<Term, DomainError> {
+        let _guard = self.locks.lock(&term_id).await;
+
         let mut term = self
             .repo
             .find_by_id(&term_id)
@@ -115,14 +141,16 @@ impl<R: TermRepository> TermService<R> {
         let events = term.take_events();
         self.bus
             .publish(&events)
-            .map_err(|e| DomainError::conflict(format!("failed to publish events: {}", e)))?;
+            .map_err(|e| DomainError::unavailable(format!("failed to publish events: {}", e)))?;
         Ok(term)
     }
 
     pub async fn restore_soft_deleted_term(
-        &mut self,
+        &self,
         term_id: TermId,
     ) -> Result<Term, DomainError> {
+        let _guard = self.locks.lock(&term_id).await;
+
         let mut term = self
             .repo
             .find_by_id(&term_id)
@@ -136,11 +164,13 @@ impl<R: TermRepository> TermService<R> {
         let events = term.take_events();
         self.bus
             .publish(&events)
-            .map_err(|e| DomainError::conflict(format!("failed to publish events: {}", e)))?;
+            .map_err(|e| DomainError::unavailable(format!("failed to publish events: {}", e)))?;
         Ok(term)
     }
 
-    pub async fn permanetly_delete_term(&mut self, term_id: TermId) -> Result<(), DomainError> {
+    pub async fn permanetly_delete_term(&self, term_id: TermId) -> Result<(), DomainError> {
+        let _guard = self.locks.lock(&term_id).await;
+
         let term = self
             .repo
             .find_by_id(&term_id)
@@ -161,7 +191,7 @@ impl<R: TermRepository> TermService<R> {
 
         self.bus
             .publish(&events)
-            .map_err(|e| DomainError::conflict(format!("failed to publish events: {}", e)))?;
+            .map_err(|e| DomainError::unavailable(format!("failed to publish events: {}", e)))?;
         Ok(())
     }
 }
@@ -1,24 +1,57 @@
+use std::{borrow::Cow, cmp::Ordering, collections::BinaryHeap};
+
+use chrono::{DateTime, Utc};
+
 use crate::{
+    application::shared::locks::AggregateLocks,
     domain::{
         shared::{
-            error::DomainError,
+            error::{DomainError, FieldError},
             event::EventMeta,
-            value_object::{Demographics, EventId, OcurredAt, SocialMediaProfiles},
+            health::ComponentHealth,
+            patch::Patch,
+            ports::term_selection::TermSelectionValidator,
+            term_selection::TermSelection,
+            value_object::{
+                EventId, OcurredAt, SocialMediaMetadata, SocialMediaPlatform, SocialMediaProfiles,
+            },
         },
         term::value_objects::TermId,
         user::{
             User,
+            actor::UserActor,
             events::UserDomainEvent,
-            ports::{event::UserDomainEventBus, respository::UserRepository},
-            value_object::{FirstName, LastName, UserId},
+            follower_history::{FollowerCountPoint, FollowerGrowth, FollowerSnapshot},
+            matching::{InfluencerMatch, MatchTarget},
+            ports::{
+                event::UserDomainEventBus, follower_history::FollowerHistoryRepository,
+                respository::UserRepository,
+            },
+            specs::PendingProfileVerificationSpec,
+            value_object::{FirstName, IdentityId, LastName, UserId},
         },
     },
+    infrastructure::user::follower_history::in_memory_impl::InMemoryFollowerHistoryRepository,
     predule::InMemoryUserEventBus,
 };
 
+/// Fields of a user to change, `None` and `Patch::Unchanged` leave a field as it is
+#[derive(Debug, Default)]
+pub struct UserChanges {
+    pub first_name: Option<FirstName>,
+    pub last_name: Option<LastName>,
+    pub country_term_id: Option<TermId>,
+    pub social_profiles: Patch<SocialMediaProfiles>,
+    pub demographics: Patch<TermSelection>,
+}
+
 pub struct UserService<R: UserRepository> {
     repo: R,
     bus: Box<dyn UserDomainEventBus>,
+    follower_history: Box<dyn FollowerHistoryRepository>,
+    /// Demographics are stored unchecked when no validator is set
+    demographics_validator: Option<Box<dyn TermSelectionValidator>>,
+    locks: AggregateLocks<UserId>,
 }
 
 impl<R: UserRepository> UserService<R> {
@@ -26,6 +59,9 @@ impl<R: UserRepository> UserService<R> {
         Self {
             repo,
             bus: Box::new(InMemoryUserEventBus::default()),
+            follower_history: Box::new(InMemoryFollowerHistoryRepository::default()),
+            demographics_validator: None,
+            locks: AggregateLocks::new(),
         }
     }
 
@@ -34,23 +70,108 @@ impl<R: UserRepository> UserService<R> {
         self
     }
 
+    pub fn with_follower_history(
+        mut self,
+        follower_history: Box<dyn FollowerHistoryRepository>,
+    ) -> Self {
+        self.follower_history = follower_history;
+        self
+    }
+
+    pub fn with_demographics_validator(
+        mut self,
+        validator: Box<dyn TermSelectionValidator>,
+    ) -> Self {
+        self.demographics_validator = Some(validator);
+        self
+    }
+
+    /// Health of the repositories, event bus and demographics validator behind this service
+    pub async fn health(&self) -> Vec<ComponentHealth> {
+        let mut components = vec![
+            self.repo.check().await,
+            self.follower_history.check().await,
+            self.bus.check().await,
+        ];
+        if let Some(validator) = &self.demographics_validator {
+            components.push(validator.check().await);
+        }
+        components
+    }
+
+    /// Flushes pending events and closes the repositories, called once on shutdown
+    pub async fn shutdown(&self) -> Result<(), DomainError> {
+        self.bus
+            .flush()
+            .map_err(|e| DomainError::unavailable(format!("failed to flush events: {}", e)))?;
+        self.follower_history.close().await?;
+        self.repo.close().await
+    }
+
     pub async fn find_by_id(&self, id: &UserId) -> Result<Option<User>, DomainError> {
         let user = self.repo.find_by_id(id).await?;
         Ok(user)
     }
 
+    pub async fn find_by_ids(&self, ids: &[UserId]) -> Result<Vec<User>, DomainError> {
+        self.repo.find_by_ids(ids).await
+    }
+
+    /// The user owned by `identity_id`, if one was created
+    pub async fn find_by_identity(
+        &self,
+        identity_id: &IdentityId,
+    ) -> Result<Option<User>, DomainError> {
+        let users = self
+            .repo
+            .find_by_identity_ids(std::slice::from_ref(identity_id))
+            .await?;
+        Ok(users.into_iter().next())
+    }
+
+    pub async fn find_by_identities(
+        &self,
+        identity_ids: &[IdentityId],
+    ) -> Result<Vec<User>, DomainError> {
+        self.repo.find_by_identity_ids(identity_ids).await
+    }
+
+    /// Users with a social profile waiting for a reviewer, oldest first
+    pub async fn pending_profile_verifications(
+        &self,
+        limit: usize,
+        offset: usize,
+    ) -> Result<Vec<User>, DomainError> {
+        self.repo
+            .query(&PendingProfileVerificationSpec, limit, offset)
+            .await
+    }
+
+    /// Creates the user of `identity_id`; an identity owns at most one user
     pub async fn create_user(
-        &mut self,
+        &self,
+        identity_id: IdentityId,
         first_name: FirstName,
         last_name: LastName,
         country_term_id: TermId,
         social_profiles: Option<SocialMediaProfiles>,
-        demographics: Option<Demographics>,
+        demographics: Option<TermSelection>,
     ) -> Result<User, DomainError> {
+        if self.find_by_identity(&identity_id).await?.is_some() {
+            return Err(DomainError::conflict(format!(
+                "identity {} already has a user",
+                identity_id.as_str()
+            )));
+        }
+
+        self.validate_demographics(demographics.as_ref(), social_profiles.as_ref())
+            .await?;
+
         let id = UserId::new();
 
         let mut user = User::new(
             id,
+            identity_id,
             first_name,
             last_name,
             country_term_id,
@@ -59,78 +180,362 @@ impl<R: UserRepository> UserService<R> {
         );
 
         self.repo.save(&user).await?;
+        self.record_follower_changes(&user, None).await?;
 
         let events = user.take_events();
         self.bus
             .publish(&events)
-            .map_err(|e| DomainError::conflict(format!("failed to publish events: {}", e)))?;
+            .map_err(|e| DomainError::unavailable(format!("failed to publish events: {}", e)))?;
         Ok(user)
     }
 
+    /// Applies the given changes on behalf of `actor`, who must be the user or an admin
     pub async fn update_user(
-        &mut self,
+        &self,
         user_id: UserId,
-        first_name: Option<FirstName>,
-        last_name: Option<LastName>,
-        country_term_id: Option<TermId>,
-        social_profiles: Option<SocialMediaProfiles>,
-        demographics: Option<Demographics>,
+        actor: UserActor,
+        changes: UserChanges,
     ) -> Result<User, DomainError> {
+        actor.authorize_change(&user_id)?;
+
+        self.validate_demographics(
+            changes.demographics.value(),
+            changes.social_profiles.value(),
+        )
+        .await?;
+
+        let _guard = self.locks.lock(&user_id).await;
+
         let mut user = self
             .repo
             .find_by_id(&user_id)
             .await?
             .ok_or(DomainError::not_found("user"))?;
 
-        if let Some(f) = first_name {
+        let previous_profiles = user.social_profiles.clone();
+
+        if let Some(f) = changes.first_name {
             user.set_first_name(f);
         }
 
-        if let Some(l) = last_name {
+        if let Some(l) = changes.last_name {
             user.set_last_name(l);
         }
 
-        if let Some(c) = country_term_id {
+        if let Some(c) = changes.country_term_id {
             user.set_country(c);
         }
-        if let Some(s) = social_profiles {
+        if let Some(s) = changes.social_profiles.into_change() {
             user.set_social_profiles(s);
         }
-        if let Some(d) = demographics {
+        if let Some(d) = changes.demographics.into_change() {
             user.set_demographics(d);
         }
 
         self.repo.save(&user).await?;
+        self.record_follower_changes(&user, previous_profiles.as_ref())
+            .await?;
 
         let events = user.take_events();
         self.bus
             .publish(&events)
-            .map_err(|e| DomainError::conflict(format!("failed to publish events: {}", e)))?;
+            .map_err(|e| DomainError::unavailable(format!("failed to publish events: {}", e)))?;
         Ok(user)
     }
 
-    pub async fn soft_delete_user(&mut self, user_id: UserId) -> Result<User, DomainError> {
+    /// Adds or replaces the profile of one platform, on behalf of the user or an admin
+    pub async fn upsert_social_profile(
+        &self,
+        user_id: UserId,
+        actor: UserActor,
+        profile: SocialMediaMetadata,
+    ) -> Result<User, DomainError> {
+        actor.authorize_change(&user_id)?;
+
+        self.check_demographics(profile_demographics("", &profile))
+            .await?;
+
+        let _guard = self.locks.lock(&user_id).await;
+
         let mut user = self
             .repo
             .find_by_id(&user_id)
             .await?
             .ok_or(DomainError::not_found("user"))?;
 
-        user.mark_as_deleted();
+        let previous_profiles = user.social_profiles.clone();
+        user.upsert_social_profile(profile)?;
+
+        self.repo.save(&user).await?;
+        self.record_follower_changes(&user, previous_profiles.as_ref())
+            .await?;
+
+        let events = user.take_events();
+        self.bus
+            .publish(&events)
+            .map_err(|e| DomainError::unavailable(format!("failed to publish events: {}", e)))?;
+        Ok(user)
+    }
+
+    /// Records a follower count observed by metrics ingestion
+    pub async fn record_follower_count(
+        &self,
+        user_id: UserId,
+        platform: SocialMediaPlatform,
+        follower_count: u64,
+    ) -> Result<User, DomainError> {
+        let _guard = self.locks.lock(&user_id).await;
+
+        let mut user = self
+            .repo
+            .find_by_id(&user_id)
+            .await?
+            .ok_or(DomainError::not_found("user"))?;
+
+        user.record_follower_count(platform, follower_count)?;
+
+        self.repo.save(&user).await?;
+        self.follower_history
+            .append(&[FollowerSnapshot {
+                user_id,
+                platform,
+                follower_count,
+                recorded_at: Utc::now(),
+            }])
+            .await?;
+
+        let events = user.take_events();
+        self.bus
+            .publish(&events)
+            .map_err(|e| DomainError::unavailable(format!("failed to publish events: {}", e)))?;
+        Ok(user)
+    }
+
+    /// Follower snapshots of one platform profile within `[since, until]`, oldest first
+    pub async fn follower_history(
+        &self,
+        user_id: &UserId,
+        platform: SocialMediaPlatform,
+        since: Option<DateTime<Utc>>,
+        until: DateTime<Utc>,
+    ) -> Result<Vec<FollowerSnapshot>, DomainError> {
+        self.follower_history
+            .history(user_id, Some(platform), since, until)
+            .await
+    }
+
+    /// Total followers across platforms within `[since, until]`, oldest first
+    pub async fn total_followers_history(
+        &self,
+        user_id: &UserId,
+        since: Option<DateTime<Utc>>,
+        until: DateTime<Utc>,
+    ) -> Result<Vec<FollowerCountPoint>, DomainError> {
+        // Totals depend on every earlier snapshot, so `since` is applied afterwards
+        let snapshots = self
+            .follower_history
+            .history(user_id, None, None, until)
+            .await?;
+
+        Ok(FollowerCountPoint::totals(&snapshots)
+            .into_iter()
+            .filter(|point| since.is_none_or(|since| point.recorded_at >= since))
+            .collect())
+    }
+
+    /// Follower growth over `[from, to]` of one platform, or of the total when `platform` is `None`
+    pub async fn follower_growth(
+        &self,
+        user_id: &UserId,
+        platform: Option<SocialMediaPlatform>,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> Result<Option<FollowerGrowth>, DomainError> {
+        let snapshots = self
+            .follower_history
+            .history(user_id, platform, None, to)
+            .await?;
+
+        let points = match platform {
+            Some(_) => FollowerCountPoint::from_snapshots(&snapshots),
+            None => FollowerCountPoint::totals(&snapshots),
+        };
+        Ok(FollowerGrowth::over(&points, from, to))
+    }
+
+    /// Users ranked by how well they fit `target`, best first
+    ///
+    /// Users scoring 0 are left out; ties go to the user with more followers.
+    /// Every user passing `target.candidates()` is loaded to be scored, so
+    /// memory grows with the number of candidates; only the kept matches are
+    /// bounded by `offset + limit`.
+    pub async fn match_influencers(
+        &self,
+        target: &MatchTarget,
+        limit: usize,
+        offset: usize,
+    ) -> Result<Vec<InfluencerMatch>, DomainError> {
+        let candidates = self.repo.query(&target.candidates(), usize::MAX, 0).await?;
+
+        // keep only the best `offset + limit` while scoring, the worst kept match on top
+        let keep = offset.saturating_add(limit);
+        let mut best: BinaryHeap<Ranked> =
+            BinaryHeap::with_capacity(keep.min(candidates.len()).saturating_add(1));
+        for user in candidates {
+            let score = target.score(&user);
+            if score.score <= 0.0 {
+                continue;
+            }
+            best.push(Ranked {
+                followers: total_followers(&user),
+                found: InfluencerMatch { user, score },
+            });
+            if best.len() > keep {
+                best.pop();
+            }
+        }
+
+        Ok(best
+            .into_sorted_vec()
+            .into_iter()
+            .skip(offset)
+            .map(|ranked| ranked.found)
+            .collect())
+    }
+
+    /// Removes the profile of one platform, on behalf of the user or an admin
+    pub async fn remove_social_profile(
+        &self,
+        user_id: UserId,
+        actor: UserActor,
+        platform: SocialMediaPlatform,
+    ) -> Result<User, DomainError> {
+        actor.authorize_change(&user_id)?;
+
+        let _guard = self.locks.lock(&user_id).await;
+
+        let mut user = self
+            .repo
+            .find_by_id(&user_id)
+            .await?
+            .ok_or(DomainError::not_found("user"))?;
+
+        let previous_profiles = user.social_profiles.clone();
+        user.remove_social_profile(platform)?;
+
+        self.repo.save(&user).await?;
+        self.record_follower_changes(&user, previous_profiles.as_ref())
+            .await?;
+
+        let events = user.take_events();
+        self.bus
+            .publish(&events)
+            .map_err(|e| DomainError::unavailable(format!("failed to publish events: {}", e)))?;
+        Ok(user)
+    }
+
+    /// Asks reviewers to verify the profile of one platform, on behalf of the user or an admin
+    pub async fn request_profile_verification(
+        &self,
+        user_id: UserId,
+        actor: UserActor,
+        platform: SocialMediaPlatform,
+    ) -> Result<User, DomainError> {
+        actor.authorize_change(&user_id)?;
+
+        let _guard = self.locks.lock(&user_id).await;
+
+        let mut user = self
+            .repo
+            .find_by_id(&user_id)
+            .await?
+            .ok_or(DomainError::not_found("user"))?;
+
+        user.request_profile_verification(platform)?;
+
+        self.repo.save(&user).await?;
+
+        let events = user.take_events();
+        self.bus
+            .publish(&events)
+            .map_err(|e| DomainError::unavailable(format!("failed to publish events: {}", e)))?;
+        Ok(user)
+    }
+
+    /// Verifies the pending profile of one platform
+    pub async fn approve_profile_verification(
+        &self,
+        user_id: UserId,
+        platform: SocialMediaPlatform,
+    ) -> Result<User, DomainError> {
+        let _guard = self.locks.lock(&user_id).await;
+
+        let mut user = self
+            .repo
+            .find_by_id(&user_id)
+            .await?
+            .ok_or(DomainError::not_found("user"))?;
+
+        user.approve_profile_verification(platform)?;
 
         self.repo.save(&user).await?;
 
         let events = user.take_events();
         self.bus
             .publish(&events)
-            .map_err(|e| DomainError::conflict(format!("failed to publish events: {}", e)))?;
+            .map_err(|e| DomainError::unavailable(format!("failed to publish events: {}", e)))?;
         Ok(user)
     }
 
-    pub async fn restore_soft_deleted_user(
-        &mut self,
+    /// Turns down the pending verification of one platform, `reason` is shown to the user
+    pub async fn reject_profile_verification(
+        &self,
         user_id: UserId,
+        platform: SocialMediaPlatform,
+        reason: String,
     ) -> Result<User, DomainError> {
+        let _guard = self.locks.lock(&user_id).await;
+
+        let mut user = self
+            .repo
+            .find_by_id(&user_id)
+            .await?
+            .ok_or(DomainError::not_found("user"))?;
+
+        user.reject_profile_verification(platform, reason)?;
+
+        self.repo.save(&user).await?;
+
+        let events = user.take_events();
+        self.bus
+            .publish(&events)
+            .map_err(|e| DomainError::unavailable(format!("failed to publish events: {}", e)))?;
+        Ok(user)
+    }
+
+    pub async fn soft_delete_user(&self, user_id: UserId) -> Result<User, DomainError> {
+        let _guard = self.locks.lock(&user_id).await;
+
+        let mut user = self
+            .repo
+            .find_by_id(&user_id)
+            .await?
+            .ok_or(DomainError::not_found("user"))?;
+
+        user.mark_as_deleted();
+
+        self.repo.save(&user).await?;
+
+        let events = user.take_events();
+        self.bus
+            .publish(&events)
+            .map_err(|e| DomainError::unavailable(format!("failed to publish events: {}", e)))?;
+        Ok(user)
+    }
+
+    pub async fn restore_soft_deleted_user(&self, user_id: UserId) -> Result<User, DomainError> {
+        let _guard = self.locks.lock(&user_id).await;
+
         let mut user = self
             .repo
             .find_by_id(&user_id)
@@ -144,11 +549,13 @@ impl<R: UserRepository> UserService<R> {
         let events = user.take_events();
         self.bus
             .publish(&events)
-            .map_err(|e| DomainError::conflict(format!("failed to publish events: {}", e)))?;
+            .map_err(|e| DomainError::unavailable(format!("failed to publish events: {}", e)))?;
         Ok(user)
     }
 
-    pub async fn permanetly_delete_user(&mut self, user_id: UserId) -> Result<(), DomainError> {
+    pub async fn permanetly_delete_user(&self, user_id: UserId) -> Result<(), DomainError> {
+        let _guard = self.locks.lock(&user_id).await;
+
         let user = self
             .repo
             .find_by_id(&user_id)
@@ -169,7 +576,331 @@ impl<R: UserRepository> UserService<R> {
 
         self.bus
             .publish(&events)
-            .map_err(|e| DomainError::conflict(format!("failed to publish events: {}", e)))?;
+            .map_err(|e| DomainError::unavailable(format!("failed to publish events: {}", e)))?;
         Ok(())
     }
+
+    /// Appends a snapshot for every profile whose follower count changed or that was removed
+    async fn record_follower_changes(
+        &self,
+        user: &User,
+        previous_profiles: Option<&SocialMediaProfiles>,
+    ) -> Result<(), DomainError> {
+        let snapshots = FollowerSnapshot::changes(
+            user.id,
+            previous_profiles,
+            user.social_profiles.as_ref(),
+            Utc::now(),
+        );
+        if snapshots.is_empty() {
+            return Ok(());
+        }
+        self.follower_history.append(&snapshots).await
+    }
+
+    /// Rejects demographics of the user or of its profiles that reference
+    /// unknown, deleted, hidden or mismatched catalogue entries
+    async fn validate_demographics(
+        &self,
+        demographics: Option<&TermSelection>,
+        social_profiles: Option<&SocialMediaProfiles>,
+    ) -> Result<(), DomainError> {
+        let mut checks: Vec<(String, Cow<'_, TermSelection>)> = Vec::new();
+        if let Some(demographics) = demographics {
+            checks.push(("demographics".to_string(), Cow::Borrowed(demographics)));
+        }
+        for (i, profile) in social_profiles
+            .into_iter()
+            .flat_map(SocialMediaProfiles::all)
+            .enumerate()
+        {
+            checks.extend(profile_demographics(
+                &format!("socialProfiles.{}.", i),
+                profile,
+            ));
+        }
+
+        self.check_demographics(checks).await
+    }
+
+    async fn check_demographics(
+        &self,
+        checks: Vec<(String, Cow<'_, TermSelection>)>,
+    ) -> Result<(), DomainError> {
+        let Some(validator) = &self.demographics_validator else {
+            return Ok(());
+        };
+
+        let mut violations: Vec<FieldError> = Vec::new();
+        for (field, demographics) in &checks {
+            violations.extend(validator.validate(field, demographics).await?);
+        }
+
+        if violations.is_empty() {
+            Ok(())
+        } else {
+            Err(DomainError::invalid_fields(violations))
+        }
+    }
+}
+
+/// Demographics of a profile to check, the audience by its terms alone
+fn profile_demographics<'a>(
+    prefix: &str,
+    profile: &'a SocialMediaMetadata,
+) -> Vec<(String, Cow<'a, TermSelection>)> {
+    let mut checks = Vec::new();
+    if let Some(demographics) = profile.demographics() {
+        checks.push((
+            format!("{}demographics", prefix),
+            Cow::Borrowed(demographics),
+        ));
+    }
+    if let Some(audience) = profile.audience() {
+        checks.push((format!("{}audience", prefix), Cow::Owned(audience.terms())));
+    }
+    checks
+}
+
+/// A match ordered by rank, the best match first
+struct Ranked {
+    followers: u64,
+    found: InfluencerMatch,
+}
+
+impl Ord for Ranked {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .found
+            .score
+            .score
+            .total_cmp(&self.found.score.score)
+            .then_with(|| other.followers.cmp(&self.followers))
+            .then_with(|| self.found.user.id.cmp(&other.found.user.id))
+    }
+}
+
+impl PartialOrd for Ranked {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for Ranked {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Ranked {}
+
+fn total_followers(user: &User) -> u64 {
+    user.social_profiles
+        .as_ref()
+        .map(SocialMediaProfiles::total_followers)
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        domain::user::matching::FollowerRange,
+        infrastructure::user::repository::in_memory_impl::InMemoryUserRepository,
+    };
+
+    async fn user_with(
+        service: &UserService<InMemoryUserRepository>,
+        identity: &str,
+        counts: &[(SocialMediaPlatform, u64)],
+    ) -> User {
+        let profiles = counts
+            .iter()
+            .map(|&(platform, followers)| {
+                SocialMediaMetadata::new(platform, "name", "https://example.com", followers, None)
+                    .unwrap()
+            })
+            .collect();
+        service
+            .create_user(
+                IdentityId::new(identity).unwrap(),
+                FirstName::new("Ada").unwrap(),
+                LastName::new("Lovelace").unwrap(),
+                TermId::new(),
+                Some(SocialMediaProfiles::new(profiles)),
+                None,
+            )
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn removing_a_profile_zeroes_its_follower_history() {
+        let service = UserService::new(InMemoryUserRepository::new());
+        let user = user_with(
+            &service,
+            "identity",
+            &[
+                (SocialMediaPlatform::Instagram, 100),
+                (SocialMediaPlatform::TikTok, 50),
+            ],
+        )
+        .await;
+
+        service
+            .remove_social_profile(
+                user.id,
+                UserActor::User(user.id),
+                SocialMediaPlatform::TikTok,
+            )
+            .await
+            .unwrap();
+
+        let tiktok = service
+            .follower_history(&user.id, SocialMediaPlatform::TikTok, None, Utc::now())
+            .await
+            .unwrap();
+        assert_eq!(
+            tiktok.iter().map(|s| s.follower_count).collect::<Vec<_>>(),
+            vec![50, 0]
+        );
+
+        let totals = service
+            .total_followers_history(&user.id, None, Utc::now())
+            .await
+            .unwrap();
+        assert_eq!(totals.last().map(|p| p.follower_count), Some(100));
+    }
+
+    #[tokio::test]
+    async fn match_influencers_pages_through_the_best_matches() {
+        let service = UserService::new(InMemoryUserRepository::new());
+        let mut ids = Vec::new();
+        for (i, followers) in [400, 1_000, 0, 200, 800].into_iter().enumerate() {
+            let user = user_with(
+                &service,
+                &format!("identity-{}", i),
+                &[(SocialMediaPlatform::Youtube, followers)],
+            )
+            .await;
+            ids.push(user.id);
+        }
+        let target = MatchTarget::new(
+            [],
+            [],
+            Some(FollowerRange::new(Some(1_000), None).unwrap()),
+            Default::default(),
+        )
+        .unwrap();
+
+        let ranked = |matches: Vec<InfluencerMatch>| -> Vec<UserId> {
+            matches.into_iter().map(|found| found.user.id).collect()
+        };
+
+        // best first, the user without followers scores 0 and is left out
+        let all = service.match_influencers(&target, 10, 0).await.unwrap();
+        assert_eq!(ranked(all), vec![ids[1], ids[4], ids[0], ids[3]]);
+
+        let page = service.match_influencers(&target, 2, 1).await.unwrap();
+        assert_eq!(ranked(page), vec![ids[4], ids[0]]);
+
+        assert!(
+            service
+                .match_influencers(&target, 0, 0)
+                .await
+                .unwrap()
+                .is_empty()
+        );
+        assert!(
+            service
+                .match_influencers(&target, 5, 4)
+                .await
+                .unwrap()
+                .is_empty()
+        );
+        // the heap is sized by the candidates, not by a huge offset
+        assert!(
+            service
+                .match_influencers(&target, 100, usize::MAX)
+                .await
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[tokio::test]
+    async fn only_the_user_or_an_admin_may_change_a_user() {
+        let service = UserService::new(InMemoryUserRepository::new());
+        let user = user_with(
+            &service,
+            "identity",
+            &[(SocialMediaPlatform::Instagram, 100)],
+        )
+        .await;
+        let other = user_with(&service, "other", &[]).await;
+        let profile = || {
+            SocialMediaMetadata::new(
+                SocialMediaPlatform::TikTok,
+                "name",
+                "https://example.com",
+                10,
+                None,
+            )
+            .unwrap()
+        };
+
+        for actor in [UserActor::User(other.id), UserActor::Anonymous] {
+            let forbidden = |result: Result<User, DomainError>| {
+                matches!(result, Err(DomainError::Forbidden { .. }))
+            };
+            assert!(forbidden(
+                service
+                    .update_user(
+                        user.id,
+                        actor,
+                        UserChanges {
+                            first_name: Some(FirstName::new("Eve").unwrap()),
+                            ..Default::default()
+                        },
+                    )
+                    .await
+            ));
+            assert!(forbidden(
+                service
+                    .upsert_social_profile(user.id, actor, profile())
+                    .await
+            ));
+            assert!(forbidden(
+                service
+                    .remove_social_profile(user.id, actor, SocialMediaPlatform::Instagram)
+                    .await
+            ));
+            assert!(forbidden(
+                service
+                    .request_profile_verification(user.id, actor, SocialMediaPlatform::Instagram)
+                    .await
+            ));
+        }
+
+        let unchanged = service.find_by_id(&user.id).await.unwrap().unwrap();
+        assert_eq!(unchanged.first_name, user.first_name);
+        assert_eq!(unchanged.social_profiles, user.social_profiles);
+
+        assert!(
+            service
+                .upsert_social_profile(user.id, UserActor::User(user.id), profile())
+                .await
+                .is_ok()
+        );
+        assert!(
+            service
+                .request_profile_verification(
+                    user.id,
+                    UserActor::Admin,
+                    SocialMediaPlatform::TikTok
+                )
+                .await
+                .is_ok()
+        );
+    }
 }
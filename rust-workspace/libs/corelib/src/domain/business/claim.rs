@@ -0,0 +1,227 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::{
+    shared::{
+        error::DomainError,
+        value_object::{EmailAddress, WebsiteUrl},
+    },
+    user::value_object::UserId,
+};
+
+/// How a claimant proves they control a business
+///
+/// # Variants
+/// - `Email`: A code is mailed to an address on the domain of the business contact email.
+/// - `Website`: The claimant places the code on the business website.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ClaimMethod {
+    Email,
+    Website,
+}
+
+/// Where a claim stands
+///
+/// ```text
+/// request ──▶ AwaitingProof ──prove──▶ AwaitingReview ──approve──▶ business Verified
+///                  ▲                        │
+///                  └──request── Rejected ◀──reject
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum ClaimStatus {
+    AwaitingProof,
+    AwaitingReview,
+    Rejected { reason: String },
+}
+
+impl ClaimStatus {
+    /// Maximum length of a rejection reason
+    pub const MAX_REASON_LEN: usize = 500;
+}
+
+/// A user's request to be recognised as the one running a business
+///
+/// # Fields
+/// - `email`: Address the code was mailed to, `Email` claims only.
+/// - `website`, `email_domain`: Contact website and email domain of the business when the
+///   claim was opened; the claim is proven against them, not against later edits.
+/// - `code`: Secret the claimant proves control with, never put in events.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BusinessClaim {
+    claimant: UserId,
+    method: ClaimMethod,
+    email: Option<EmailAddress>,
+    website: Option<WebsiteUrl>,
+    email_domain: Option<String>,
+    code: String,
+    status: ClaimStatus,
+    requested_at: DateTime<Utc>,
+    proven_at: Option<DateTime<Utc>>,
+}
+
+impl BusinessClaim {
+    /// A claim awaiting proof, with a freshly generated code
+    pub fn new(
+        claimant: UserId,
+        method: ClaimMethod,
+        email: Option<EmailAddress>,
+        website: Option<WebsiteUrl>,
+        email_domain: Option<String>,
+    ) -> Self {
+        Self {
+            claimant,
+            method,
+            email,
+            website,
+            email_domain,
+            code: generate_code(),
+            status: ClaimStatus::AwaitingProof,
+            requested_at: Utc::now(),
+            proven_at: None,
+        }
+    }
+
+    pub fn claimant(&self) -> UserId {
+        self.claimant
+    }
+
+    pub fn method(&self) -> ClaimMethod {
+        self.method
+    }
+
+    pub fn email(&self) -> Option<&EmailAddress> {
+        self.email.as_ref()
+    }
+
+    pub fn website(&self) -> Option<&WebsiteUrl> {
+        self.website.as_ref()
+    }
+
+    pub fn email_domain(&self) -> Option<&str> {
+        self.email_domain.as_deref()
+    }
+
+    pub fn code(&self) -> &str {
+        &self.code
+    }
+
+    pub fn status(&self) -> &ClaimStatus {
+        &self.status
+    }
+
+    pub fn requested_at(&self) -> &DateTime<Utc> {
+        &self.requested_at
+    }
+
+    pub fn proven_at(&self) -> Option<&DateTime<Utc>> {
+        self.proven_at.as_ref()
+    }
+
+    /// Awaiting proof or review, i.e. not yet settled
+    pub fn is_open(&self) -> bool {
+        !matches!(self.status, ClaimStatus::Rejected { .. })
+    }
+
+    pub fn is_awaiting_review(&self) -> bool {
+        self.status == ClaimStatus::AwaitingReview
+    }
+
+    /// Moves the claim to `AwaitingReview` when `evidence` holds the code
+    ///
+    /// `evidence` is the code the claimant typed in for `Email` claims and
+    /// the website page for `Website` claims.
+    ///
+    /// # Errors
+    /// - `claimant` did not make this claim
+    /// - the claim is not awaiting proof
+    /// - `evidence` does not hold the code
+    pub fn prove(&mut self, claimant: UserId, evidence: &str) -> Result<(), DomainError> {
+        self.ensure_claimant(claimant)?;
+        if self.status != ClaimStatus::AwaitingProof {
+            return Err(DomainError::conflict("claim is not awaiting proof"));
+        }
+
+        let proven = match self.method {
+            ClaimMethod::Email => evidence.trim().eq_ignore_ascii_case(&self.code),
+            ClaimMethod::Website => evidence.contains(&self.code),
+        };
+        if !proven {
+            return Err(match self.method {
+                ClaimMethod::Email => DomainError::validation("claim code does not match"),
+                ClaimMethod::Website => {
+                    DomainError::validation("claim code was not found on the business website")
+                }
+            });
+        }
+
+        self.status = ClaimStatus::AwaitingReview;
+        self.proven_at = Some(Utc::now());
+        Ok(())
+    }
+
+    /// Moves an `AwaitingReview` claim to `Rejected`
+    ///
+    /// # Errors
+    /// - reason cannot be empty or longer than `ClaimStatus::MAX_REASON_LEN`
+    /// - the claim must be awaiting review
+    pub fn reject(&mut self, reason: impl Into<String>) -> Result<String, DomainError> {
+        let reason = reason.into().trim().to_owned();
+        if reason.is_empty() {
+            return Err(DomainError::validation("rejection reason cannot be empty"));
+        }
+        if reason.chars().count() > ClaimStatus::MAX_REASON_LEN {
+            return Err(DomainError::validation(format!(
+                "rejection reason cannot exceed {} characters",
+                ClaimStatus::MAX_REASON_LEN
+            )));
+        }
+        self.ensure_awaiting_review()?;
+
+        self.status = ClaimStatus::Rejected {
+            reason: reason.clone(),
+        };
+        Ok(reason)
+    }
+
+    pub fn ensure_awaiting_review(&self) -> Result<(), DomainError> {
+        if !self.is_awaiting_review() {
+            return Err(DomainError::conflict("claim is not awaiting review"));
+        }
+        Ok(())
+    }
+
+    fn ensure_claimant(&self, claimant: UserId) -> Result<(), DomainError> {
+        if self.claimant != claimant {
+            return Err(DomainError::forbidden(
+                "only the claimant can prove a claim",
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Whether a listing was confirmed by an approved claim
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum BusinessVerification {
+    #[default]
+    Unverified,
+    Verified {
+        claimant: UserId,
+        method: ClaimMethod,
+        verified_at: DateTime<Utc>,
+    },
+}
+
+impl BusinessVerification {
+    pub fn is_verified(&self) -> bool {
+        matches!(self, BusinessVerification::Verified { .. })
+    }
+}
+
+/// 12 upper-case hex digits, short enough to type in from an email
+fn generate_code() -> String {
+    Uuid::new_v4().simple().to_string()[..12].to_uppercase()
+}
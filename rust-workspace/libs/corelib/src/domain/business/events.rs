@@ -1,8 +1,13 @@
 use serde::{Deserialize, Serialize};
 
 use crate::domain::{
-    business::value_objects::{BusinessDescription, BusinessFeatures, BusinessName, ContactInfo},
-    shared::{event::EventMeta, value_object::SocialMedia},
+    business::{
+        claim::ClaimMethod,
+        membership::MemberRole,
+        value_objects::{BusinessDescription, BusinessFeatures, BusinessName, ContactInfo},
+    },
+    shared::{event::EventMeta, term_selection::TermSelection, value_object::SocialMedia},
+    user::value_object::UserId,
 };
 
 /// Domain events for the Business aggregate
@@ -19,6 +24,7 @@ pub enum BusinessDomainEvent {
         contact_info: Option<ContactInfo>,
         social_media: Option<SocialMedia>,
         features: Option<BusinessFeatures>,
+        owner: Option<UserId>,
     },
 
     /// Business name or description changed
@@ -60,6 +66,117 @@ pub enum BusinessDomainEvent {
         latest: Option<BusinessFeatures>,
     },
 
+    /// Catalogue categories changed
+    BusinessCategoriesUpdated {
+        meta: EventMeta,
+        event_name: String,
+
+        previous: TermSelection,
+        latest: TermSelection,
+    },
+
+    /// A user was invited to join the business
+    BusinessMemberInvited {
+        meta: EventMeta,
+        event_name: String,
+
+        user_id: UserId,
+        role: MemberRole,
+        invited_by: UserId,
+    },
+
+    /// An invited user accepted and became a member
+    BusinessMemberJoined {
+        meta: EventMeta,
+        event_name: String,
+
+        user_id: UserId,
+        role: MemberRole,
+    },
+
+    /// An invited user turned the invitation down
+    BusinessInvitationDeclined {
+        meta: EventMeta,
+        event_name: String,
+
+        user_id: UserId,
+    },
+
+    /// A member withdrew the invitation of a user
+    BusinessInvitationRevoked {
+        meta: EventMeta,
+        event_name: String,
+
+        user_id: UserId,
+        revoked_by: UserId,
+    },
+
+    /// A member was given another role
+    BusinessMemberRoleChanged {
+        meta: EventMeta,
+        event_name: String,
+
+        user_id: UserId,
+        previous: MemberRole,
+        latest: MemberRole,
+        changed_by: UserId,
+    },
+
+    /// A member left or was removed, `removed_by` is the member themselves when they left
+    BusinessMemberRemoved {
+        meta: EventMeta,
+        event_name: String,
+
+        user_id: UserId,
+        role: MemberRole,
+        removed_by: UserId,
+    },
+
+    /// Ownership moved to another member, the previous owner stays on as an admin
+    BusinessOwnershipTransferred {
+        meta: EventMeta,
+        event_name: String,
+
+        previous_owner: UserId,
+        latest_owner: UserId,
+    },
+
+    /// A user asked to claim the business and was given a code to prove control with
+    BusinessClaimRequested {
+        meta: EventMeta,
+        event_name: String,
+
+        claimant: UserId,
+        method: ClaimMethod,
+    },
+
+    /// The claimant proved control, the claim waits for an admin
+    BusinessClaimProven {
+        meta: EventMeta,
+        event_name: String,
+
+        claimant: UserId,
+        method: ClaimMethod,
+    },
+
+    /// An admin approved the claim, the business is verified and the claimant owns it
+    BusinessClaimApproved {
+        meta: EventMeta,
+        event_name: String,
+
+        claimant: UserId,
+        method: ClaimMethod,
+    },
+
+    /// An admin turned the claim down
+    BusinessClaimRejected {
+        meta: EventMeta,
+        event_name: String,
+
+        claimant: UserId,
+        reason: String,
+    },
+
     /// Business permanently deleted
     BusinessDeleted { meta: EventMeta, event_name: String },
 
@@ -69,3 +186,31 @@ pub enum BusinessDomainEvent {
     /// Business restored from soft-delete
     BusinessRestoredFromSoftDeleted { meta: EventMeta, event_name: String },
 }
+
+impl BusinessDomainEvent {
+    /// Name of the event, e.g. `"business.created"`
+    pub fn event_name(&self) -> &str {
+        match self {
+            BusinessDomainEvent::BusinessCreated { event_name, .. }
+            | BusinessDomainEvent::BusinessDetailsUpdated { event_name, .. }
+            | BusinessDomainEvent::BusinessContactUpdated { event_name, .. }
+            | BusinessDomainEvent::BusinessSocialMediaUpdated { event_name, .. }
+            | BusinessDomainEvent::BusinessFeaturesUpdated { event_name, .. }
+            | BusinessDomainEvent::BusinessCategoriesUpdated { event_name, .. }
+            | BusinessDomainEvent::BusinessMemberInvited { event_name, .. }
+            | BusinessDomainEvent::BusinessMemberJoined { event_name, .. }
+            | BusinessDomainEvent::BusinessInvitationDeclined { event_name, .. }
+            | BusinessDomainEvent::BusinessInvitationRevoked { event_name, .. }
+            | BusinessDomainEvent::BusinessMemberRoleChanged { event_name, .. }
+            | BusinessDomainEvent::BusinessMemberRemoved { event_name, .. }
+            | BusinessDomainEvent::BusinessOwnershipTransferred { event_name, .. }
+            | BusinessDomainEvent::BusinessClaimRequested { event_name, .. }
+            | BusinessDomainEvent::BusinessClaimProven { event_name, .. }
+            | BusinessDomainEvent::BusinessClaimApproved { event_name, .. }
+            | BusinessDomainEvent::BusinessClaimRejected { event_name, .. }
+            | BusinessDomainEvent::BusinessDeleted { event_name, .. }
+            | BusinessDomainEvent::BusinessSoftDeleted { event_name, .. }
+            | BusinessDomainEvent::BusinessRestoredFromSoftDeleted { event_name, .. } => event_name,
+        }
+    }
+}
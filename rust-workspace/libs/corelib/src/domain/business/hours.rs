@@ -0,0 +1,628 @@
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, Datelike, NaiveDate, NaiveTime, Timelike, Utc, Weekday};
+use chrono_tz::Tz;
+use serde::{Deserialize, Serialize};
+
+use crate::domain::shared::error::{DomainError, FieldError};
+
+const DAY_SECONDS: u32 = 24 * 60 * 60;
+const WEEK_SECONDS: u32 = 7 * DAY_SECONDS;
+
+/// One opening interval, in the business's local time
+///
+/// A range closing at or before it opens runs past midnight into the next
+/// day, so `22:00–02:00` is overnight and `00:00–00:00` is open all day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct TimeRange {
+    opens: NaiveTime,
+    closes: NaiveTime,
+}
+
+impl TimeRange {
+    pub const ALL_DAY: TimeRange = TimeRange {
+        opens: NaiveTime::MIN,
+        closes: NaiveTime::MIN,
+    };
+
+    pub fn new(opens: NaiveTime, closes: NaiveTime) -> Self {
+        Self { opens, closes }
+    }
+
+    /// Parses ranges such as `9am–5pm`, `9:30 am - 5 pm` or `09:00-17:30`
+    ///
+    /// Without am/pm, a closing hour from 1 to 11 that would come before the
+    /// opening is read as pm, so `9-5` is `09:00–17:00` and not overnight;
+    /// `22-6` and `09:00-05:00` still run past midnight.
+    pub fn parse(text: &str) -> Result<Self, DomainError> {
+        let normalized = text.trim().to_lowercase().replace(['–', '—'], "-");
+        let (opens, closes) = normalized
+            .split_once('-')
+            .or_else(|| normalized.split_once(" to "))
+            .ok_or_else(|| {
+                DomainError::validation(format!("'{}' is not a time range like 9am-5pm", text))
+            })?;
+
+        let (opens_text, closes_text) = (opens, closes);
+        let opens = parse_time(opens_text)?;
+        let mut closes = parse_time(closes_text)?;
+
+        if closes <= opens && !has_meridiem(opens_text) && is_bare_twelve_hour(closes_text) {
+            let afternoon = closes.with_hour(closes.hour() + 12).expect("valid time");
+            if afternoon > opens {
+                closes = afternoon;
+            }
+        }
+
+        Ok(Self::new(opens, closes))
+    }
+
+    pub fn opens(&self) -> NaiveTime {
+        self.opens
+    }
+
+    pub fn closes(&self) -> NaiveTime {
+        self.closes
+    }
+
+    pub fn is_overnight(&self) -> bool {
+        self.closes <= self.opens
+    }
+
+    /// Whether a moment of the day the range opens on falls inside it
+    fn contains_same_day(&self, time: NaiveTime) -> bool {
+        time >= self.opens && (self.is_overnight() || time < self.closes)
+    }
+
+    /// Whether a moment of the day after the range opens falls inside it
+    fn contains_next_day(&self, time: NaiveTime) -> bool {
+        self.is_overnight() && time < self.closes
+    }
+
+    /// Seconds from the start of the opening day, `end` past `DAY_SECONDS` when overnight
+    fn span(&self) -> (u32, u32) {
+        let start = self.opens.num_seconds_from_midnight();
+        let end = self.closes.num_seconds_from_midnight();
+        if self.is_overnight() {
+            (start, end + DAY_SECONDS)
+        } else {
+            (start, end)
+        }
+    }
+}
+
+impl std::fmt::Display for TimeRange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}–{}",
+            self.opens.format("%H:%M"),
+            self.closes.format("%H:%M")
+        )
+    }
+}
+
+/// Opening hours of one date that replace the weekly schedule, e.g. a holiday
+///
+/// # Fields
+/// - `date`: Local date the exception applies to.
+/// - `intervals`: Opening intervals of that date, closed all day when empty.
+/// - `note`: Why the hours differ, e.g. "New Year's Day".
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HoursException {
+    date: NaiveDate,
+    intervals: Vec<TimeRange>,
+    note: Option<String>,
+}
+
+impl HoursException {
+    pub fn new(date: NaiveDate, mut intervals: Vec<TimeRange>, note: Option<String>) -> Self {
+        intervals.sort();
+        intervals.dedup();
+        Self {
+            date,
+            intervals,
+            note: note
+                .map(|note| note.trim().to_string())
+                .filter(|note| !note.is_empty()),
+        }
+    }
+
+    /// A date the business stays closed
+    pub fn closed(date: NaiveDate, note: Option<String>) -> Self {
+        Self::new(date, Vec::new(), note)
+    }
+
+    pub fn date(&self) -> NaiveDate {
+        self.date
+    }
+
+    pub fn intervals(&self) -> &[TimeRange] {
+        &self.intervals
+    }
+
+    pub fn note(&self) -> Option<&str> {
+        self.note.as_deref()
+    }
+
+    pub fn is_closed(&self) -> bool {
+        self.intervals.is_empty()
+    }
+}
+
+/// Weekly opening hours of a business with dated exceptions
+///
+/// # Fields
+/// - `timezone`: IANA timezone the hours are given in, e.g. `Europe/Paris`.
+/// - `weekly`: Opening intervals of each weekday, Monday first; closed when empty.
+/// - `exceptions`: Hours of single dates that replace the weekly schedule.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BusinessHours {
+    timezone: Tz,
+    weekly: [Vec<TimeRange>; 7],
+    exceptions: BTreeMap<NaiveDate, HoursException>,
+}
+
+impl BusinessHours {
+    /// # Errors
+    /// - `timezone` is not an IANA timezone name
+    /// - two intervals overlap, overnight intervals included
+    /// - two exceptions share a date
+    pub fn new(
+        timezone: &str,
+        weekly: impl IntoIterator<Item = (Weekday, TimeRange)>,
+        exceptions: impl IntoIterator<Item = HoursException>,
+    ) -> Result<Self, DomainError> {
+        let mut errors: Vec<FieldError> = Vec::new();
+
+        let timezone = timezone.trim().parse::<Tz>().unwrap_or_else(|_| {
+            errors.push(FieldError::new(
+                "hours.timezone",
+                format!(
+                    "'{}' is not an IANA timezone such as Europe/Paris",
+                    timezone
+                ),
+            ));
+            Tz::UTC
+        });
+
+        let mut days: [Vec<TimeRange>; 7] = Default::default();
+        for (weekday, range) in weekly {
+            days[weekday.num_days_from_monday() as usize].push(range);
+        }
+        for day in &mut days {
+            day.sort();
+            day.dedup();
+        }
+        if let Some((weekday, range)) = weekly_overlap(&days) {
+            errors.push(FieldError::new(
+                format!("hours.weekly.{}", weekday),
+                format!("{} overlaps another interval", range),
+            ));
+        }
+
+        let mut by_date: BTreeMap<NaiveDate, HoursException> = BTreeMap::new();
+        for exception in exceptions {
+            let field = format!("hours.exceptions.{}", exception.date);
+            if let Some(range) = day_overlap(&exception.intervals) {
+                errors.push(FieldError::new(
+                    field.clone(),
+                    format!("{} overlaps another interval", range),
+                ));
+            }
+            if by_date.insert(exception.date, exception).is_some() {
+                errors.push(FieldError::new(field, "date is listed more than once"));
+            }
+        }
+
+        if !errors.is_empty() {
+            return Err(DomainError::invalid_fields(errors));
+        }
+
+        Ok(Self {
+            timezone,
+            weekly: days,
+            exceptions: by_date,
+        })
+    }
+
+    /// Reads a free-text entry such as `("Mon-Fri", "9am–12pm, 1pm–5pm")`
+    ///
+    /// Days are names or ranges of names (`Mon`, `Tuesday`, `Sat-Sun`), joined
+    /// by commas, or one of `daily`, `weekdays` and `weekends`. Hours are ranges
+    /// joined by commas, `closed`, or `24 hours`.
+    pub fn parse_entry(day: &str, hours: &str) -> Result<Vec<(Weekday, TimeRange)>, DomainError> {
+        let weekdays = parse_weekdays(day)?;
+        let ranges = parse_ranges(hours)?;
+
+        Ok(weekdays
+            .into_iter()
+            .flat_map(|weekday| ranges.iter().map(move |range| (weekday, *range)))
+            .collect())
+    }
+
+    pub fn timezone(&self) -> Tz {
+        self.timezone
+    }
+
+    /// Opening intervals of each weekday, Monday first
+    pub fn weekly(&self) -> impl Iterator<Item = (Weekday, &[TimeRange])> {
+        self.weekly.iter().enumerate().map(|(index, ranges)| {
+            (
+                Weekday::try_from(index as u8).expect("seven weekdays"),
+                ranges.as_slice(),
+            )
+        })
+    }
+
+    /// Exceptions by ascending date
+    pub fn exceptions(&self) -> impl Iterator<Item = &HoursException> {
+        self.exceptions.values()
+    }
+
+    /// Opening intervals of a local date, its exception when it has one
+    pub fn hours_on(&self, date: NaiveDate) -> &[TimeRange] {
+        match self.exceptions.get(&date) {
+            Some(exception) => exception.intervals(),
+            None => &self.weekly[date.weekday().num_days_from_monday() as usize],
+        }
+    }
+
+    /// Whether the business is open at `at`, read in the business's timezone
+    ///
+    /// Overnight intervals count on the day after they open, except on a date
+    /// with its own exception: only the exception's hours apply that day.
+    pub fn is_open_at(&self, at: DateTime<Utc>) -> bool {
+        let local = at.with_timezone(&self.timezone);
+        let date = local.date_naive();
+        let time = local.time();
+
+        let opened_today = self
+            .hours_on(date)
+            .iter()
+            .any(|range| range.contains_same_day(time));
+        let opened_yesterday = !self.exceptions.contains_key(&date)
+            && date.pred_opt().is_some_and(|yesterday| {
+                self.hours_on(yesterday)
+                    .iter()
+                    .any(|range| range.contains_next_day(time))
+            });
+
+        opened_today || opened_yesterday
+    }
+}
+
+/// The first interval overlapping another one on the weekly timeline
+fn weekly_overlap(days: &[Vec<TimeRange>; 7]) -> Option<(Weekday, TimeRange)> {
+    let mut spans: Vec<(u32, u32, usize, TimeRange)> = Vec::new();
+    for (index, ranges) in days.iter().enumerate() {
+        let offset = index as u32 * DAY_SECONDS;
+        for range in ranges {
+            let (start, end) = range.span();
+            let (start, end) = (offset + start, offset + end);
+            if end > WEEK_SECONDS {
+                // Sunday night runs into Monday morning
+                spans.push((start, WEEK_SECONDS, index, *range));
+                spans.push((0, end - WEEK_SECONDS, index, *range));
+            } else {
+                spans.push((start, end, index, *range));
+            }
+        }
+    }
+
+    first_overlap(spans).map(|(index, range)| {
+        (
+            Weekday::try_from(index as u8).expect("seven weekdays"),
+            range,
+        )
+    })
+}
+
+/// The first interval overlapping another one within a single date
+fn day_overlap(ranges: &[TimeRange]) -> Option<TimeRange> {
+    let spans = ranges
+        .iter()
+        .map(|range| {
+            let (start, end) = range.span();
+            (start, end, 0, *range)
+        })
+        .collect();
+    first_overlap(spans).map(|(_, range)| range)
+}
+
+fn first_overlap(mut spans: Vec<(u32, u32, usize, TimeRange)>) -> Option<(usize, TimeRange)> {
+    spans.sort_by_key(|(start, end, _, _)| (*start, *end));
+    spans
+        .windows(2)
+        .find(|pair| pair[1].0 < pair[0].1)
+        .map(|pair| (pair[1].2, pair[1].3))
+}
+
+fn parse_weekdays(text: &str) -> Result<Vec<Weekday>, DomainError> {
+    let normalized = text.trim().to_lowercase().replace(['–', '—'], "-");
+    let all = [
+        Weekday::Mon,
+        Weekday::Tue,
+        Weekday::Wed,
+        Weekday::Thu,
+        Weekday::Fri,
+        Weekday::Sat,
+        Weekday::Sun,
+    ];
+
+    match normalized.as_str() {
+        "daily" | "every day" | "everyday" => return Ok(all.to_vec()),
+        "weekdays" => return Ok(all[..5].to_vec()),
+        "weekends" | "weekend" => return Ok(all[5..].to_vec()),
+        _ => {}
+    }
+
+    let mut weekdays: Vec<Weekday> = Vec::new();
+    for part in normalized.split(',').map(str::trim) {
+        let (first, last) = match part.split_once('-').or_else(|| part.split_once(" to ")) {
+            Some((first, last)) => (parse_weekday(first)?, parse_weekday(last)?),
+            None => {
+                let day = parse_weekday(part)?;
+                (day, day)
+            }
+        };
+
+        let mut day = first;
+        loop {
+            if !weekdays.contains(&day) {
+                weekdays.push(day);
+            }
+            if day == last {
+                break;
+            }
+            day = day.succ();
+        }
+    }
+    Ok(weekdays)
+}
+
+fn parse_weekday(text: &str) -> Result<Weekday, DomainError> {
+    text.trim()
+        .parse::<Weekday>()
+        .map_err(|_| DomainError::validation(format!("'{}' is not a day of the week", text.trim())))
+}
+
+fn parse_ranges(text: &str) -> Result<Vec<TimeRange>, DomainError> {
+    let normalized = text.trim().to_lowercase();
+    match normalized.as_str() {
+        "closed" => return Ok(Vec::new()),
+        "24 hours" | "open 24 hours" | "24h" | "24/7" => return Ok(vec![TimeRange::ALL_DAY]),
+        _ => {}
+    }
+
+    normalized
+        .split([',', ';'])
+        .map(str::trim)
+        .filter(|part| !part.is_empty())
+        .map(TimeRange::parse)
+        .collect()
+}
+
+fn has_meridiem(text: &str) -> bool {
+    let text = text.trim();
+    text.ends_with("am") || text.ends_with("pm")
+}
+
+/// Whether `text` is an hour from 1 to 11 without am/pm or a leading zero, e.g. `5` or `5:30`
+fn is_bare_twelve_hour(text: &str) -> bool {
+    let compact: String = text.chars().filter(|c| !c.is_whitespace()).collect();
+    let hour = compact
+        .split_once(':')
+        .map_or(compact.as_str(), |(hour, _)| hour);
+    !hour.starts_with('0')
+        && compact.chars().all(|c| c.is_ascii_digit() || c == ':')
+        && hour
+            .parse::<u32>()
+            .is_ok_and(|hour| (1..12).contains(&hour))
+}
+
+/// Parses `9`, `9am`, `9:30 pm`, `17:30`, `noon`, `midnight`; `24:00` is midnight
+fn parse_time(text: &str) -> Result<NaiveTime, DomainError> {
+    let invalid = || DomainError::validation(format!("'{}' is not a time of day", text.trim()));
+    let compact: String = text.chars().filter(|c| !c.is_whitespace()).collect();
+
+    match compact.as_str() {
+        "noon" => return Ok(NaiveTime::from_hms_opt(12, 0, 0).expect("valid time")),
+        "midnight" | "24:00" => return Ok(NaiveTime::MIN),
+        _ => {}
+    }
+
+    let (clock, meridiem) = if let Some(clock) = compact.strip_suffix("am") {
+        (clock, Some(false))
+    } else if let Some(clock) = compact.strip_suffix("pm") {
+        (clock, Some(true))
+    } else {
+        (compact.as_str(), None)
+    };
+
+    let (hour, minute) = match clock.split_once(':') {
+        Some((hour, minute)) => (hour, minute),
+        None => (clock, "0"),
+    };
+    let mut hour: u32 = hour.parse().map_err(|_| invalid())?;
+    let minute: u32 = minute.parse().map_err(|_| invalid())?;
+
+    if let Some(pm) = meridiem {
+        if !(1..=12).contains(&hour) {
+            return Err(invalid());
+        }
+        hour = match (hour, pm) {
+            (12, false) => 0,
+            (12, true) => 12,
+            (hour, true) => hour + 12,
+            (hour, false) => hour,
+        };
+    }
+
+    NaiveTime::from_hms_opt(hour, minute, 0).ok_or_else(invalid)
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+
+    use super::*;
+
+    fn time(hour: u32, minute: u32) -> NaiveTime {
+        NaiveTime::from_hms_opt(hour, minute, 0).unwrap()
+    }
+
+    fn range(text: &str) -> TimeRange {
+        TimeRange::parse(text).unwrap()
+    }
+
+    fn date(month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, month, day).unwrap()
+    }
+
+    /// 2026-01-02 is a Friday
+    fn utc(day: u32, hour: u32, minute: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 1, day, hour, minute, 0).unwrap()
+    }
+
+    fn hours(weekly: &[(&str, &str)], exceptions: Vec<HoursException>) -> BusinessHours {
+        let weekly: Vec<(Weekday, TimeRange)> = weekly
+            .iter()
+            .flat_map(|(day, hours)| BusinessHours::parse_entry(day, hours).unwrap())
+            .collect();
+        BusinessHours::new("UTC", weekly, exceptions).unwrap()
+    }
+
+    fn overlap_fields(weekly: &[(&str, &str)]) -> Vec<String> {
+        let weekly: Vec<(Weekday, TimeRange)> = weekly
+            .iter()
+            .flat_map(|(day, hours)| BusinessHours::parse_entry(day, hours).unwrap())
+            .collect();
+        BusinessHours::new("UTC", weekly, [])
+            .unwrap_err()
+            .field_errors()
+            .iter()
+            .map(|e| e.field.clone())
+            .collect()
+    }
+
+    #[test]
+    fn parses_noon_midnight_and_24_00() {
+        assert_eq!(range("12am-12pm"), TimeRange::new(time(0, 0), time(12, 0)));
+        assert_eq!(range("12pm-12am"), TimeRange::new(time(12, 0), time(0, 0)));
+        assert_eq!(range("noon to midnight"), range("12pm-12am"));
+        assert_eq!(
+            range("18:00-24:00"),
+            TimeRange::new(time(18, 0), time(0, 0))
+        );
+        assert_eq!(
+            range("9:30 am – 5 pm"),
+            TimeRange::new(time(9, 30), time(17, 0))
+        );
+        assert!(range("18:00-24:00").is_overnight());
+        assert!(TimeRange::parse("0am-5pm").is_err());
+        assert!(TimeRange::parse("13pm-5pm").is_err());
+        assert!(TimeRange::parse("25:00-26:00").is_err());
+    }
+
+    #[test]
+    fn reads_a_bare_closing_hour_before_the_opening_as_pm() {
+        assert_eq!(range("9-5"), TimeRange::new(time(9, 0), time(17, 0)));
+        assert_eq!(
+            range("9:30 - 5:30"),
+            TimeRange::new(time(9, 30), time(17, 30))
+        );
+        assert!(!range("9-5").is_overnight());
+        // 24-hour clocks and explicit markers keep running past midnight
+        assert!(range("22-6").is_overnight());
+        assert!(range("09:00-05:00").is_overnight());
+        assert!(range("9am-5am").is_overnight());
+        assert!(range("9-5am").is_overnight());
+    }
+
+    #[test]
+    fn parses_day_ranges_wrapping_around_the_week() {
+        let days: Vec<Weekday> = BusinessHours::parse_entry("Fri-Mon", "9-17")
+            .unwrap()
+            .into_iter()
+            .map(|(weekday, _)| weekday)
+            .collect();
+        assert_eq!(
+            days,
+            vec![Weekday::Fri, Weekday::Sat, Weekday::Sun, Weekday::Mon]
+        );
+
+        assert_eq!(
+            BusinessHours::parse_entry("weekends", "closed").unwrap(),
+            Vec::new()
+        );
+        assert_eq!(
+            BusinessHours::parse_entry("Sun", "24 hours").unwrap(),
+            vec![(Weekday::Sun, TimeRange::ALL_DAY)]
+        );
+    }
+
+    #[test]
+    fn rejects_overnight_intervals_running_into_the_next_day() {
+        assert_eq!(
+            overlap_fields(&[("Fri", "10pm-3am"), ("Sat", "2am-6am")]),
+            vec!["hours.weekly.Sat"]
+        );
+        // Sunday night runs into Monday morning
+        assert_eq!(
+            overlap_fields(&[("Sun", "10pm-2am"), ("Mon", "1am-6am")]),
+            vec!["hours.weekly.Mon"]
+        );
+        // touching intervals do not overlap
+        hours(&[("Sun", "10pm-2am"), ("Mon", "2am-6am")], vec![]);
+    }
+
+    #[test]
+    fn overnight_hours_spill_into_the_next_day() {
+        let hours = hours(&[("Sun", "10pm-2am"), ("Fri", "9am-5pm")], vec![]);
+
+        assert!(hours.is_open_at(utc(4, 23, 0)));
+        // Sunday's hours open on Monday morning
+        assert!(hours.is_open_at(utc(5, 1, 59)));
+        assert!(!hours.is_open_at(utc(5, 2, 0)));
+        assert!(hours.is_open_at(utc(2, 9, 0)));
+        assert!(!hours.is_open_at(utc(2, 17, 0)));
+    }
+
+    #[test]
+    fn an_exception_replaces_the_spill_from_the_day_before() {
+        let weekly = [("Fri", "10pm-3am"), ("Sat", "10am-4pm")];
+
+        let regular = hours(&weekly, vec![]);
+        assert!(regular.is_open_at(utc(3, 1, 0)));
+
+        let closed = hours(&weekly, vec![HoursException::closed(date(1, 3), None)]);
+        assert!(closed.is_open_at(utc(2, 23, 0)));
+        assert!(!closed.is_open_at(utc(3, 1, 0)));
+        assert!(!closed.is_open_at(utc(3, 11, 0)));
+
+        let late = hours(
+            &weekly,
+            vec![HoursException::new(
+                date(1, 3),
+                vec![range("2am-4am")],
+                None,
+            )],
+        );
+        assert!(!late.is_open_at(utc(3, 1, 0)));
+        assert!(late.is_open_at(utc(3, 2, 30)));
+    }
+
+    #[test]
+    fn reads_the_hours_in_local_time_across_a_dst_change() {
+        let weekly = BusinessHours::parse_entry("Sun", "9am-5pm").unwrap();
+        let hours = BusinessHours::new("Europe/Paris", weekly, []).unwrap();
+
+        // 07:30 UTC is 08:30 in winter, Paris moves to UTC+2 on 2026-03-29
+        assert!(!hours.is_open_at(Utc.with_ymd_and_hms(2026, 3, 22, 7, 30, 0).unwrap()));
+        assert!(hours.is_open_at(Utc.with_ymd_and_hms(2026, 3, 29, 7, 30, 0).unwrap()));
+        // 15:30 UTC is 16:30 before and 17:30 after the change
+        assert!(hours.is_open_at(Utc.with_ymd_and_hms(2026, 3, 22, 15, 30, 0).unwrap()));
+        assert!(!hours.is_open_at(Utc.with_ymd_and_hms(2026, 3, 29, 15, 30, 0).unwrap()));
+    }
+}
@@ -0,0 +1,430 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::{shared::error::DomainError, user::value_object::UserId};
+
+/// What a member may do on a business
+///
+/// # Variants
+/// - `Owner`: Everything an admin can, manages admins and transfers ownership.
+/// - `Admin`: Edits the business and manages editors.
+/// - `Editor`: Edits the business.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum MemberRole {
+    Owner,
+    Admin,
+    Editor,
+}
+
+impl MemberRole {
+    /// Whether a member with this role may invite, re-role and remove members holding `other`
+    pub fn can_manage(self, other: MemberRole) -> bool {
+        matches!(
+            (self, other),
+            (MemberRole::Owner, MemberRole::Admin | MemberRole::Editor)
+                | (MemberRole::Admin, MemberRole::Editor)
+        )
+    }
+}
+
+/// A user who manages a business
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Membership {
+    user_id: UserId,
+    role: MemberRole,
+    joined_at: DateTime<Utc>,
+}
+
+impl Membership {
+    pub fn user_id(&self) -> UserId {
+        self.user_id
+    }
+
+    pub fn role(&self) -> MemberRole {
+        self.role
+    }
+
+    pub fn joined_at(&self) -> &DateTime<Utc> {
+        &self.joined_at
+    }
+}
+
+/// A user asked to join a business, waiting for them to accept or decline
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Invitation {
+    user_id: UserId,
+    role: MemberRole,
+    invited_by: UserId,
+    invited_at: DateTime<Utc>,
+}
+
+impl Invitation {
+    pub fn user_id(&self) -> UserId {
+        self.user_id
+    }
+
+    pub fn role(&self) -> MemberRole {
+        self.role
+    }
+
+    pub fn invited_by(&self) -> UserId {
+        self.invited_by
+    }
+
+    pub fn invited_at(&self) -> &DateTime<Utc> {
+        &self.invited_at
+    }
+}
+
+/// Members of a business and the invitations still open
+///
+/// Ownership is only ever handed over by `transfer_ownership` or granted to an
+/// approved claimant by `add_owner`, never invited or given by a role change,
+/// and the last owner can neither leave nor be removed. A business that has an
+/// owner therefore always keeps one.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BusinessMembers {
+    members: Vec<Membership>,
+    invitations: Vec<Invitation>,
+}
+
+impl BusinessMembers {
+    /// Members made of `owner` alone
+    pub fn with_owner(owner: UserId) -> Self {
+        Self {
+            members: vec![Membership {
+                user_id: owner,
+                role: MemberRole::Owner,
+                joined_at: Utc::now(),
+            }],
+            invitations: Vec::new(),
+        }
+    }
+
+    pub fn members(&self) -> &[Membership] {
+        &self.members
+    }
+
+    pub fn invitations(&self) -> &[Invitation] {
+        &self.invitations
+    }
+
+    pub fn member(&self, user_id: &UserId) -> Option<&Membership> {
+        self.members.iter().find(|m| &m.user_id == user_id)
+    }
+
+    pub fn role_of(&self, user_id: &UserId) -> Option<MemberRole> {
+        self.member(user_id).map(|m| m.role)
+    }
+
+    pub fn owners(&self) -> impl Iterator<Item = &Membership> {
+        self.members.iter().filter(|m| m.role == MemberRole::Owner)
+    }
+
+    pub fn has_owner(&self) -> bool {
+        self.owners().next().is_some()
+    }
+
+    /// `forbidden` unless `actor` is a member, anyone may edit a business without an owner
+    pub fn authorize_edit(&self, actor: Option<&UserId>) -> Result<(), DomainError> {
+        if !self.has_owner() || actor.is_some_and(|a| self.member(a).is_some()) {
+            return Ok(());
+        }
+        Err(DomainError::forbidden(
+            "only members can edit a business that has an owner",
+        ))
+    }
+
+    /// `forbidden` unless `actor` is a member, even on a business without an owner
+    ///
+    /// Claims are proven against the contact website and email, so nobody may
+    /// change them before the business has been claimed.
+    pub fn authorize_contact_edit(&self, actor: Option<&UserId>) -> Result<(), DomainError> {
+        if actor.is_some_and(|a| self.member(a).is_some()) {
+            return Ok(());
+        }
+        Err(DomainError::forbidden(
+            "only members can change the contact details of a business",
+        ))
+    }
+
+    /// `forbidden` unless `actor` is an owner, anyone may delete a business without an owner
+    pub fn authorize_delete(&self, actor: Option<&UserId>) -> Result<(), DomainError> {
+        if !self.has_owner() || actor.and_then(|a| self.role_of(a)) == Some(MemberRole::Owner) {
+            return Ok(());
+        }
+        Err(DomainError::forbidden(
+            "only an owner can delete a business that has an owner",
+        ))
+    }
+
+    /// Makes `user_id` an owner, promoting them if they are a member already
+    /// and dropping any invitation they had
+    pub fn add_owner(&mut self, user_id: UserId) {
+        self.invitations.retain(|i| i.user_id != user_id);
+        match self.members.iter_mut().find(|m| m.user_id == user_id) {
+            Some(member) => member.role = MemberRole::Owner,
+            None => self.members.push(Membership {
+                user_id,
+                role: MemberRole::Owner,
+                joined_at: Utc::now(),
+            }),
+        }
+    }
+
+    /// Invites `user_id` to join as `role`
+    ///
+    /// # Errors
+    /// - `role` is `Owner`, ownership is transferred instead
+    /// - `actor` is not a member allowed to manage `role`
+    /// - `user_id` is already a member or already invited
+    pub fn invite(
+        &mut self,
+        actor: UserId,
+        user_id: UserId,
+        role: MemberRole,
+    ) -> Result<Invitation, DomainError> {
+        if role == MemberRole::Owner {
+            return Err(DomainError::validation(
+                "owners cannot be invited, transfer ownership to a member instead",
+            ));
+        }
+        self.authorize(&actor, role)?;
+        if self.member(&user_id).is_some() {
+            return Err(DomainError::conflict("user is already a member"));
+        }
+        if self.invitation(&user_id).is_some() {
+            return Err(DomainError::conflict("user is already invited"));
+        }
+
+        let invitation = Invitation {
+            user_id,
+            role,
+            invited_by: actor,
+            invited_at: Utc::now(),
+        };
+        self.invitations.push(invitation.clone());
+        Ok(invitation)
+    }
+
+    /// Turns the invitation of `user_id` into a membership
+    pub fn accept_invitation(&mut self, user_id: UserId) -> Result<Membership, DomainError> {
+        let invitation = self.take_invitation(&user_id)?;
+
+        let membership = Membership {
+            user_id,
+            role: invitation.role,
+            joined_at: Utc::now(),
+        };
+        self.members.push(membership.clone());
+        Ok(membership)
+    }
+
+    /// Drops the invitation of `user_id` at their request
+    pub fn decline_invitation(&mut self, user_id: UserId) -> Result<Invitation, DomainError> {
+        self.take_invitation(&user_id)
+    }
+
+    /// Drops the invitation of `user_id`, `actor` must be allowed to manage its role
+    pub fn revoke_invitation(
+        &mut self,
+        actor: UserId,
+        user_id: UserId,
+    ) -> Result<Invitation, DomainError> {
+        let role = self
+            .invitation(&user_id)
+            .map(|i| i.role)
+            .ok_or(DomainError::not_found("invitation"))?;
+        self.authorize(&actor, role)?;
+        self.take_invitation(&user_id)
+    }
+
+    /// Gives member `user_id` a new role, returning the one they had
+    ///
+    /// # Errors
+    /// - `role` is `Owner`, ownership is transferred instead
+    /// - `user_id` is not a member, or already has `role`
+    /// - `actor` is not allowed to manage both the current and the new role
+    pub fn change_role(
+        &mut self,
+        actor: UserId,
+        user_id: UserId,
+        role: MemberRole,
+    ) -> Result<MemberRole, DomainError> {
+        if role == MemberRole::Owner {
+            return Err(DomainError::validation(
+                "ownership cannot be given by a role change, transfer it instead",
+            ));
+        }
+        let previous = self
+            .role_of(&user_id)
+            .ok_or(DomainError::not_found("member"))?;
+        if previous == role {
+            return Err(DomainError::conflict(format!(
+                "member is already {:?}",
+                role
+            )));
+        }
+        self.authorize(&actor, previous)?;
+        self.authorize(&actor, role)?;
+
+        self.member_mut(&user_id)?.role = role;
+        Ok(previous)
+    }
+
+    /// Removes member `user_id`; members may remove themselves, i.e. leave
+    ///
+    /// # Errors
+    /// - `user_id` is not a member
+    /// - `user_id` is the last owner
+    /// - someone else is removing them without being allowed to manage their role
+    pub fn remove(&mut self, actor: UserId, user_id: UserId) -> Result<Membership, DomainError> {
+        let role = self
+            .role_of(&user_id)
+            .ok_or(DomainError::not_found("member"))?;
+        if role == MemberRole::Owner && self.owners().count() == 1 {
+            return Err(DomainError::conflict(
+                "the last owner cannot leave, transfer ownership first",
+            ));
+        }
+        if actor != user_id {
+            self.authorize(&actor, role)?;
+        }
+
+        let index = self
+            .members
+            .iter()
+            .position(|m| m.user_id == user_id)
+            .ok_or(DomainError::not_found("member"))?;
+        Ok(self.members.remove(index))
+    }
+
+    /// Makes member `to` an owner in place of owner `actor`, who stays on as an admin
+    ///
+    /// # Errors
+    /// - `actor` is not an owner
+    /// - `to` is `actor`, or is not a member
+    pub fn transfer_ownership(&mut self, actor: UserId, to: UserId) -> Result<(), DomainError> {
+        if self.role_of(&actor) != Some(MemberRole::Owner) {
+            return Err(DomainError::forbidden(
+                "only an owner can transfer ownership",
+            ));
+        }
+        if actor == to {
+            return Err(DomainError::validation(
+                "ownership cannot be transferred to its current owner",
+            ));
+        }
+        if self.member(&to).is_none() {
+            return Err(DomainError::not_found("member"));
+        }
+
+        self.member_mut(&to)?.role = MemberRole::Owner;
+        self.member_mut(&actor)?.role = MemberRole::Admin;
+        Ok(())
+    }
+
+    fn invitation(&self, user_id: &UserId) -> Option<&Invitation> {
+        self.invitations.iter().find(|i| &i.user_id == user_id)
+    }
+
+    fn take_invitation(&mut self, user_id: &UserId) -> Result<Invitation, DomainError> {
+        let index = self
+            .invitations
+            .iter()
+            .position(|i| &i.user_id == user_id)
+            .ok_or(DomainError::not_found("invitation"))?;
+        Ok(self.invitations.remove(index))
+    }
+
+    fn member_mut(&mut self, user_id: &UserId) -> Result<&mut Membership, DomainError> {
+        self.members
+            .iter_mut()
+            .find(|m| &m.user_id == user_id)
+            .ok_or(DomainError::not_found("member"))
+    }
+
+    /// `forbidden` unless `actor` is a member whose role can manage `role`
+    fn authorize(&self, actor: &UserId, role: MemberRole) -> Result<(), DomainError> {
+        match self.role_of(actor) {
+            Some(actor_role) if actor_role.can_manage(role) => Ok(()),
+            Some(actor_role) => Err(DomainError::forbidden(format!(
+                "{:?} members cannot manage {:?} members",
+                actor_role, role
+            ))),
+            None => Err(DomainError::forbidden(
+                "only members can manage the members of a business",
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn with_editor() -> (BusinessMembers, UserId, UserId) {
+        let owner = UserId::new();
+        let editor = UserId::new();
+        let mut members = BusinessMembers::with_owner(owner);
+        members.invite(owner, editor, MemberRole::Editor).unwrap();
+        members.accept_invitation(editor).unwrap();
+        (members, owner, editor)
+    }
+
+    #[test]
+    fn anyone_may_edit_and_delete_a_business_without_an_owner() {
+        let members = BusinessMembers::default();
+
+        assert!(members.authorize_edit(None).is_ok());
+        assert!(members.authorize_delete(Some(&UserId::new())).is_ok());
+    }
+
+    #[test]
+    fn only_members_may_edit_an_owned_business() {
+        let (members, owner, editor) = with_editor();
+
+        assert!(members.authorize_edit(Some(&owner)).is_ok());
+        assert!(members.authorize_edit(Some(&editor)).is_ok());
+        assert!(matches!(
+            members.authorize_edit(Some(&UserId::new())),
+            Err(DomainError::Forbidden { .. })
+        ));
+        assert!(matches!(
+            members.authorize_edit(None),
+            Err(DomainError::Forbidden { .. })
+        ));
+    }
+
+    #[test]
+    fn only_members_may_change_contact_details() {
+        let (members, owner, editor) = with_editor();
+
+        assert!(members.authorize_contact_edit(Some(&owner)).is_ok());
+        assert!(members.authorize_contact_edit(Some(&editor)).is_ok());
+        assert!(matches!(
+            members.authorize_contact_edit(Some(&UserId::new())),
+            Err(DomainError::Forbidden { .. })
+        ));
+        // not even on a business without an owner, whose claims are proven on them
+        assert!(matches!(
+            BusinessMembers::default().authorize_contact_edit(Some(&UserId::new())),
+            Err(DomainError::Forbidden { .. })
+        ));
+    }
+
+    #[test]
+    fn only_owners_may_delete_an_owned_business() {
+        let (members, owner, editor) = with_editor();
+
+        assert!(members.authorize_delete(Some(&owner)).is_ok());
+        assert!(matches!(
+            members.authorize_delete(Some(&editor)),
+            Err(DomainError::Forbidden { .. })
+        ));
+        assert!(matches!(
+            members.authorize_delete(None),
+            Err(DomainError::Forbidden { .. })
+        ));
+    }
+}
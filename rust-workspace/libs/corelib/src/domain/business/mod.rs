@@ -1,18 +1,30 @@
+pub mod claim;
 pub mod events;
+pub mod hours;
+pub mod membership;
 pub mod ports;
+pub mod specs;
 pub mod value_objects;
 
+use chrono::Utc;
+
 use crate::domain::{
     business::{
+        claim::{BusinessClaim, BusinessVerification, ClaimMethod},
         events::BusinessDomainEvent,
+        membership::{BusinessMembers, MemberRole},
         value_objects::{
             BusinessDescription, BusinessFeatures, BusinessId, BusinessName, ContactInfo,
         },
     },
     shared::{
+        error::DomainError,
         event::EventMeta,
-        value_object::{Deleted, EventId, OcurredAt, SocialMedia, Timestamp},
+        geo::GeoPoint,
+        term_selection::TermSelection,
+        value_object::{Deleted, EmailAddress, EventId, OcurredAt, SocialMedia, Timestamp},
     },
+    user::value_object::UserId,
 };
 
 #[derive(Debug, Clone)]
@@ -24,6 +36,12 @@ pub struct Business {
     pub contact_info: Option<ContactInfo>,
     pub social_media: Option<SocialMedia>,
     pub features: Option<BusinessFeatures>,
+    /// Catalogue terms filed under each taxonomy, e.g. industry, amenities or price range
+    pub categories: TermSelection,
+    pub members: BusinessMembers,
+    pub verification: BusinessVerification,
+    /// Latest claim, kept once rejected until a new one replaces it
+    pub claim: Option<BusinessClaim>,
 
     pub timestamps: Timestamp,
     pub deleted: Deleted,
@@ -42,6 +60,7 @@ impl Business {
         contact_info: Option<ContactInfo>,
         social_media: Option<SocialMedia>,
         features: Option<BusinessFeatures>,
+        owner: Option<UserId>,
     ) -> Self {
         let mut business = Business {
             id,
@@ -50,6 +69,10 @@ impl Business {
             contact_info: contact_info.clone(),
             social_media: social_media.clone(),
             features: features.clone(),
+            categories: TermSelection::default(),
+            members: owner.map(BusinessMembers::with_owner).unwrap_or_default(),
+            verification: BusinessVerification::Unverified,
+            claim: None,
             version: 0,
             timestamps: Timestamp::new(),
             deleted: Deleted::new(),
@@ -71,14 +94,23 @@ impl Business {
                 contact_info: contact_info.to_owned(),
                 social_media: social_media.to_owned(),
                 features: features.to_owned(),
+                owner,
             });
 
         business
     }
 
+    /// Coordinates of the business address, if it has them
+    pub fn location(&self) -> Option<GeoPoint> {
+        self.contact_info
+            .as_ref()?
+            .address
+            .as_ref()?
+            .location()
+    }
+
     pub fn set_name(&mut self, name: BusinessName) {
-        let previous_name = name.clone();
-        self.name = name.clone();
+        let previous_name = std::mem::replace(&mut self.name, name.clone());
         self.touch();
         self.pending_events
             .push(BusinessDomainEvent::BusinessDetailsUpdated {
@@ -92,8 +124,7 @@ impl Business {
     }
 
     pub fn set_description(&mut self, description: Option<BusinessDescription>) {
-        let previous_description = description.clone();
-        self.description = description.clone();
+        let previous_description = std::mem::replace(&mut self.description, description.clone());
         self.touch();
         self.pending_events
             .push(BusinessDomainEvent::BusinessDetailsUpdated {
@@ -101,14 +132,13 @@ impl Business {
                 event_name: "business.details.update".to_owned(),
                 previous_name: None,
                 latest_name: None,
-                previous_description: previous_description,
+                previous_description,
                 latest_description: description.to_owned(),
             });
     }
 
     pub fn set_contact_info(&mut self, contact_info: Option<ContactInfo>) {
-        let previous_contact_info = contact_info.clone();
-        self.contact_info = contact_info.clone();
+        let previous_contact_info = std::mem::replace(&mut self.contact_info, contact_info.clone());
         self.touch();
         self.pending_events
             .push(BusinessDomainEvent::BusinessContactUpdated {
@@ -120,8 +150,7 @@ impl Business {
     }
 
     pub fn set_social_media(&mut self, social_media: Option<SocialMedia>) {
-        let previous_social_media = social_media.clone();
-        self.social_media = social_media.clone();
+        let previous_social_media = std::mem::replace(&mut self.social_media, social_media.clone());
         self.touch();
         self.pending_events
             .push(BusinessDomainEvent::BusinessSocialMediaUpdated {
@@ -133,8 +162,7 @@ impl Business {
     }
 
     pub fn set_features(&mut self, features: Option<BusinessFeatures>) {
-        let previous_features = features.clone();
-        self.features = features.clone();
+        let previous_features = std::mem::replace(&mut self.features, features.clone());
         self.touch();
         self.pending_events
             .push(BusinessDomainEvent::BusinessFeaturesUpdated {
@@ -145,6 +173,261 @@ impl Business {
             });
     }
 
+    pub fn set_categories(&mut self, categories: TermSelection) {
+        let previous_categories = std::mem::replace(&mut self.categories, categories.clone());
+        self.touch();
+        self.pending_events
+            .push(BusinessDomainEvent::BusinessCategoriesUpdated {
+                meta: self.next_meta(),
+                event_name: "business.categories.update".to_owned(),
+                previous: previous_categories,
+                latest: categories,
+            });
+    }
+
+    /// Invites `user_id` to join as `role`, see `BusinessMembers::invite`
+    pub fn invite_member(
+        &mut self,
+        actor: UserId,
+        user_id: UserId,
+        role: MemberRole,
+    ) -> Result<(), DomainError> {
+        self.members.invite(actor, user_id, role)?;
+        self.touch();
+        self.pending_events
+            .push(BusinessDomainEvent::BusinessMemberInvited {
+                meta: self.next_meta(),
+                event_name: "business.member.invited".to_owned(),
+                user_id,
+                role,
+                invited_by: actor,
+            });
+        Ok(())
+    }
+
+    pub fn accept_invitation(&mut self, user_id: UserId) -> Result<(), DomainError> {
+        let membership = self.members.accept_invitation(user_id)?;
+        self.touch();
+        self.pending_events
+            .push(BusinessDomainEvent::BusinessMemberJoined {
+                meta: self.next_meta(),
+                event_name: "business.member.joined".to_owned(),
+                user_id,
+                role: membership.role(),
+            });
+        Ok(())
+    }
+
+    pub fn decline_invitation(&mut self, user_id: UserId) -> Result<(), DomainError> {
+        self.members.decline_invitation(user_id)?;
+        self.touch();
+        self.pending_events
+            .push(BusinessDomainEvent::BusinessInvitationDeclined {
+                meta: self.next_meta(),
+                event_name: "business.member.invitation_declined".to_owned(),
+                user_id,
+            });
+        Ok(())
+    }
+
+    pub fn revoke_invitation(&mut self, actor: UserId, user_id: UserId) -> Result<(), DomainError> {
+        self.members.revoke_invitation(actor, user_id)?;
+        self.touch();
+        self.pending_events
+            .push(BusinessDomainEvent::BusinessInvitationRevoked {
+                meta: self.next_meta(),
+                event_name: "business.member.invitation_revoked".to_owned(),
+                user_id,
+                revoked_by: actor,
+            });
+        Ok(())
+    }
+
+    pub fn change_member_role(
+        &mut self,
+        actor: UserId,
+        user_id: UserId,
+        role: MemberRole,
+    ) -> Result<(), DomainError> {
+        let previous = self.members.change_role(actor, user_id, role)?;
+        self.touch();
+        self.pending_events
+            .push(BusinessDomainEvent::BusinessMemberRoleChanged {
+                meta: self.next_meta(),
+                event_name: "business.member.role_changed".to_owned(),
+                user_id,
+                previous,
+                latest: role,
+                changed_by: actor,
+            });
+        Ok(())
+    }
+
+    pub fn remove_member(&mut self, actor: UserId, user_id: UserId) -> Result<(), DomainError> {
+        let membership = self.members.remove(actor, user_id)?;
+        self.touch();
+        self.pending_events
+            .push(BusinessDomainEvent::BusinessMemberRemoved {
+                meta: self.next_meta(),
+                event_name: "business.member.removed".to_owned(),
+                user_id,
+                role: membership.role(),
+                removed_by: actor,
+            });
+        Ok(())
+    }
+
+    pub fn transfer_ownership(&mut self, actor: UserId, to: UserId) -> Result<(), DomainError> {
+        self.members.transfer_ownership(actor, to)?;
+        self.touch();
+        self.pending_events
+            .push(BusinessDomainEvent::BusinessOwnershipTransferred {
+                meta: self.next_meta(),
+                event_name: "business.ownership.transferred".to_owned(),
+                previous_owner: actor,
+                latest_owner: to,
+            });
+        Ok(())
+    }
+
+    /// Opens a claim by `claimant`, replacing a rejected claim or their own open one
+    ///
+    /// The claim keeps the contact website and email domain it was opened against.
+    ///
+    /// # Errors
+    /// - the business is verified already, or someone else's claim is open
+    /// - the business has an owner other than `claimant`
+    /// - `Email`: `email` is missing or not on the domain of the contact email
+    /// - `Website`: the business has no website
+    pub fn request_claim(
+        &mut self,
+        claimant: UserId,
+        method: ClaimMethod,
+        email: Option<EmailAddress>,
+    ) -> Result<&BusinessClaim, DomainError> {
+        if self.verification.is_verified() {
+            return Err(DomainError::conflict("business is already verified"));
+        }
+        if self.members.has_owner() && self.members.role_of(&claimant) != Some(MemberRole::Owner) {
+            return Err(DomainError::forbidden(
+                "only the owner can claim a business that has one",
+            ));
+        }
+        if let Some(claim) = &self.claim
+            && claim.is_open()
+            && claim.claimant() != claimant
+        {
+            return Err(DomainError::conflict(
+                "another user's claim on this business is under way",
+            ));
+        }
+
+        let contact_info = self.contact_info.as_ref();
+        let website = contact_info.and_then(|ci| ci.website.clone());
+        let email_domain = contact_info
+            .and_then(|ci| ci.email.as_ref())
+            .map(|email| email.domain().to_owned());
+        let email = match method {
+            ClaimMethod::Email => {
+                let contact_email = contact_info.and_then(|ci| ci.email.as_ref()).ok_or(
+                    DomainError::validation("business has no contact email to claim it by"),
+                )?;
+                let email = email.ok_or(DomainError::validation(
+                    "an email address is needed to claim by email",
+                ))?;
+                if !email.domain().eq_ignore_ascii_case(contact_email.domain()) {
+                    return Err(DomainError::validation(format!(
+                        "email must be on the {} domain",
+                        contact_email.domain()
+                    )));
+                }
+                Some(email)
+            }
+            ClaimMethod::Website => {
+                if website.is_none() {
+                    return Err(DomainError::validation(
+                        "business has no website to claim it by",
+                    ));
+                }
+                None
+            }
+        };
+
+        self.touch();
+        self.pending_events
+            .push(BusinessDomainEvent::BusinessClaimRequested {
+                meta: self.next_meta(),
+                event_name: "business.claim.requested".to_owned(),
+                claimant,
+                method,
+            });
+        Ok(self.claim.insert(BusinessClaim::new(
+            claimant,
+            method,
+            email,
+            website,
+            email_domain,
+        )))
+    }
+
+    /// Checks `evidence` against the open claim of `claimant`, see `BusinessClaim::prove`
+    pub fn prove_claim(&mut self, claimant: UserId, evidence: &str) -> Result<(), DomainError> {
+        let claim = self.claim.as_mut().ok_or(DomainError::not_found("claim"))?;
+        claim.prove(claimant, evidence)?;
+        let method = claim.method();
+
+        self.touch();
+        self.pending_events
+            .push(BusinessDomainEvent::BusinessClaimProven {
+                meta: self.next_meta(),
+                event_name: "business.claim.proven".to_owned(),
+                claimant,
+                method,
+            });
+        Ok(())
+    }
+
+    /// Verifies the business and makes the claimant an owner
+    pub fn approve_claim(&mut self) -> Result<(), DomainError> {
+        let claim = self.claim.as_ref().ok_or(DomainError::not_found("claim"))?;
+        claim.ensure_awaiting_review()?;
+        let (claimant, method) = (claim.claimant(), claim.method());
+
+        self.claim = None;
+        self.verification = BusinessVerification::Verified {
+            claimant,
+            method,
+            verified_at: Utc::now(),
+        };
+        self.members.add_owner(claimant);
+
+        self.touch();
+        self.pending_events
+            .push(BusinessDomainEvent::BusinessClaimApproved {
+                meta: self.next_meta(),
+                event_name: "business.claim.approved".to_owned(),
+                claimant,
+                method,
+            });
+        Ok(())
+    }
+
+    pub fn reject_claim(&mut self, reason: String) -> Result<(), DomainError> {
+        let claim = self.claim.as_mut().ok_or(DomainError::not_found("claim"))?;
+        let reason = claim.reject(reason)?;
+        let claimant = claim.claimant();
+
+        self.touch();
+        self.pending_events
+            .push(BusinessDomainEvent::BusinessClaimRejected {
+                meta: self.next_meta(),
+                event_name: "business.claim.rejected".to_owned(),
+                claimant,
+                reason,
+            });
+        Ok(())
+    }
+
     pub fn mark_as_deleted(&mut self) {
         self.deleted.mark_deleted();
         self.touch();
@@ -0,0 +1,31 @@
+use async_trait::async_trait;
+
+use crate::domain::{
+    business::value_objects::BusinessName,
+    shared::{
+        error::DomainError,
+        health::HealthCheck,
+        value_object::{EmailAddress, WebsiteUrl},
+    },
+};
+
+/// Sends claimants the code that proves they read mail on a business domain
+#[async_trait]
+pub trait ClaimCodeMailer: HealthCheck + Send + Sync {
+    async fn send_claim_code(
+        &self,
+        to: &EmailAddress,
+        business: &BusinessName,
+        code: &str,
+    ) -> Result<(), DomainError>;
+}
+
+/// Reads the page a claimant placed their code on
+#[async_trait]
+pub trait WebsiteFetcher: HealthCheck + Send + Sync {
+    /// Body of the page at `url`
+    ///
+    /// `Err` is kept for a site that cannot be reached, a page without the
+    /// code is for the caller to judge.
+    async fn fetch(&self, url: &WebsiteUrl) -> Result<String, DomainError>;
+}
@@ -1,9 +1,12 @@
-use crate::domain::business::events::BusinessDomainEvent;
+use crate::domain::{business::events::BusinessDomainEvent, shared::health::HealthCheck};
 
 
 
-pub trait BusinessDomainEventBus: Send + Sync {
+pub trait BusinessDomainEventBus: HealthCheck + Send + Sync {
     fn publish(&self, events: &[BusinessDomainEvent]) -> Result<(), String>;
+
+    /// Delivers every event still queued in the bus, called once on shutdown
+    fn flush(&self) -> Result<(), String>;
 }
 
 pub trait BusinessDomainEventHandler: Send + Sync {
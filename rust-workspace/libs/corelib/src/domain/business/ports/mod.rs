@@ -1,2 +1,3 @@
 pub mod respository;
-pub mod event;
\ No newline at end of file
+pub mod event;
+pub mod claim;
\ No newline at end of file
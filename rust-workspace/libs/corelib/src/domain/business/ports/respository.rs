@@ -1,15 +1,28 @@
 use crate::domain::{
     business::{Business, value_objects::BusinessId},
-    shared::{error::DomainError, specs::Specification},
+    shared::{
+        error::DomainError,
+        geo::{GeoArea, Nearby},
+        health::HealthCheck,
+        specs::Specification,
+    },
+    user::value_object::UserId,
 };
 use async_trait::async_trait;
 
 #[async_trait]
-pub trait BusinessRepository: Send + Sync {
-    async fn save(&mut self, user: &Business) -> Result<(), DomainError>;
+pub trait BusinessRepository: HealthCheck + Send + Sync {
+    async fn save(&self, user: &Business) -> Result<(), DomainError>;
 
     async fn find_by_id(&self, id: &BusinessId) -> Result<Option<Business>, DomainError>;
 
+    /// Fetches every business in `ids` in one call; unknown ids are skipped and order is not kept
+    async fn find_by_ids(&self, ids: &[BusinessId]) -> Result<Vec<Business>, DomainError>;
+
+    /// Fetches every business one of `user_ids` is a member of, invitations aside;
+    /// unknown ids are skipped and order is not kept
+    async fn find_by_member_ids(&self, user_ids: &[UserId]) -> Result<Vec<Business>, DomainError>;
+
     async fn query(
         &self,
         spec: &(dyn Specification<Business> + Send + Sync),
@@ -17,5 +30,17 @@ pub trait BusinessRepository: Send + Sync {
         offset: usize,
     ) -> Result<Vec<Business>, DomainError>;
 
-    async fn delete(&mut self, id: &BusinessId) -> Result<(), DomainError>;
+    /// Live businesses whose address lies in `area`, nearest to `area.origin()` first;
+    /// businesses without coordinates are never returned
+    async fn find_in_area(
+        &self,
+        area: &GeoArea,
+        limit: usize,
+        offset: usize,
+    ) -> Result<Vec<Nearby<Business>>, DomainError>;
+
+    async fn delete(&self, id: &BusinessId) -> Result<(), DomainError>;
+
+    /// Releases connections held by the repository, called once on shutdown
+    async fn close(&self) -> Result<(), DomainError>;
 }
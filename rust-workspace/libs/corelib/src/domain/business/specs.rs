@@ -0,0 +1,34 @@
+use crate::domain::{
+    business::Business,
+    shared::{specs::Specification, term_selection::TermSelection},
+};
+
+/// Businesses with a proven claim waiting for an admin
+pub struct ClaimAwaitingReviewSpec;
+
+impl Specification<Business> for ClaimAwaitingReviewSpec {
+    fn is_satisfied_by(&self, candidate: &Business) -> bool {
+        !candidate.deleted.is_deleted()
+            && candidate
+                .claim
+                .as_ref()
+                .is_some_and(|claim| claim.is_awaiting_review())
+    }
+}
+
+/// Live businesses filed under at least one of the terms of every taxonomy of `categories`;
+/// empty `categories` match every live business
+pub struct InCategoriesSpec {
+    pub categories: TermSelection,
+}
+
+impl Specification<Business> for InCategoriesSpec {
+    fn is_satisfied_by(&self, candidate: &Business) -> bool {
+        !candidate.deleted.is_deleted()
+            && self.categories.iter().all(|(taxonomy_id, term_ids)| {
+                term_ids
+                    .iter()
+                    .any(|term_id| candidate.categories.contains(taxonomy_id, term_id))
+            })
+    }
+}
@@ -3,9 +3,12 @@ use std::collections::HashMap;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
-use crate::domain::shared::{
-    error::DomainError,
-    value_object::{EmailAddress, PhoneNumber, PhysicalAddress, Tag, WebsiteUrl},
+use crate::domain::{
+    business::hours::BusinessHours,
+    shared::{
+        error::DomainError,
+        value_object::{EmailAddress, PhoneNumber, PhysicalAddress, Tag, WebsiteUrl},
+    },
 };
 
 /// Unique identifier for business
@@ -35,6 +38,12 @@ impl BusinessId {
     }
 }
 
+impl Default for BusinessId {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 /// Business name
 ///
 /// # Field
@@ -136,39 +145,6 @@ impl BusinessDescription {
     }
 }
 
-/// Business hour entry
-///
-/// Example: ("Mon", "9am–5pm")
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
-pub struct BusinessHourEntry {
-    day: String,
-    hours: String,
-}
-
-impl BusinessHourEntry {
-    pub fn new(day: impl Into<String>, hours: impl Into<String>) -> Result<Self, DomainError> {
-        let day = day.into();
-        let hours = hours.into();
-
-        if day.trim().is_empty() {
-            return Err(DomainError::validation("Day cannot be empty"));
-        }
-
-        if hours.trim().is_empty() {
-            return Err(DomainError::validation("Hours cannot be empty"));
-        }
-
-        Ok(Self { day, hours })
-    }
-
-    pub fn day(&self) -> &str {
-        &self.day
-    }
-    pub fn hours(&self) -> &str {
-        &self.hours
-    }
-}
-
 /// A named service provided by the business
 ///
 /// # Field
@@ -267,8 +243,8 @@ impl ContactInfo {
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BusinessFeatures {
-    /// Example: Mon -> "9am–5pm"
-    pub hours: Option<Vec<BusinessHourEntry>>,
+    /// Opening hours in the business's timezone
+    pub hours: Option<BusinessHours>,
 
     /// Services provided by the business
     pub services: Option<Vec<ServiceName>>,
@@ -282,7 +258,7 @@ pub struct BusinessFeatures {
 
 impl BusinessFeatures {
     pub fn new(
-        hours: Option<Vec<BusinessHourEntry>>,
+        hours: Option<BusinessHours>,
         services: Option<Vec<ServiceName>>,
         tags: Option<Vec<Tag>>,
         extra: Option<HashMap<ExtraFeatureKey, ExtraFeatureValue>>,
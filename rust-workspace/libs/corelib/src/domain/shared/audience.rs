@@ -0,0 +1,315 @@
+use std::collections::BTreeMap;
+
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+use crate::domain::{
+    shared::{
+        error::{DomainError, FieldError},
+        term_selection::TermSelection,
+    },
+    taxonomy::value_objects::TaxonomyId,
+    term::value_objects::TermId,
+};
+
+/// Share of an audience, stored in basis points so `12.34%` is kept exactly
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct Percentage(u16);
+
+impl Percentage {
+    pub const FULL: Percentage = Percentage(10_000);
+
+    /// Rounds `percent` to two decimals; fails outside `0..=100`
+    pub fn new(percent: f64) -> Result<Self, DomainError> {
+        if !percent.is_finite() || !(0.0..=100.0).contains(&percent) {
+            return Err(DomainError::validation(format!(
+                "percentage must be between 0 and 100, got {}",
+                percent
+            )));
+        }
+        Ok(Self((percent * 100.0).round() as u16))
+    }
+
+    pub fn as_percent(&self) -> f64 {
+        self.0 as f64 / 100.0
+    }
+
+    /// `0.0` to `1.0`
+    pub fn as_ratio(&self) -> f64 {
+        self.0 as f64 / 10_000.0
+    }
+}
+
+/// Where audience figures come from
+///
+/// # Variants
+/// - `SelfReported`: Entered by the influencer.
+/// - `PlatformInsights`: Exported from the platform's own analytics.
+/// - `ThirdParty`: Measured by an outside analytics provider.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AudienceSource {
+    SelfReported,
+    PlatformInsights,
+    ThirdParty,
+}
+
+/// Composition of a profile's audience, e.g. 60% aged 18-24 and 40% aged 25-34
+///
+/// Shares are kept per taxonomy and add up to at most 100% within each one;
+/// the rest of the audience is unknown.
+///
+/// # Fields
+/// - `shares`: Share of the audience for each term, grouped by taxonomy.
+/// - `source`: Where the figures come from.
+/// - `as_of`: Day the figures were taken.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AudienceDemographics {
+    shares: BTreeMap<TaxonomyId, BTreeMap<TermId, Percentage>>,
+    source: AudienceSource,
+    as_of: NaiveDate,
+}
+
+impl AudienceDemographics {
+    /// Errors are reported by input position under `field`, e.g. `audience.shares.2.percentage`
+    ///
+    /// # Errors
+    /// - a term is listed twice within its taxonomy, or with a 0% share
+    /// - the shares of a taxonomy add up to more than 100%, reported on its last share
+    /// - `as_of` is after `today`
+    pub fn new(
+        field: &str,
+        shares: impl IntoIterator<Item = (TaxonomyId, TermId, Percentage)>,
+        source: AudienceSource,
+        as_of: NaiveDate,
+        today: NaiveDate,
+    ) -> Result<Self, DomainError> {
+        let mut by_taxonomy: BTreeMap<TaxonomyId, BTreeMap<TermId, Percentage>> = BTreeMap::new();
+        // input position of the last share of every taxonomy
+        let mut last_share: BTreeMap<TaxonomyId, usize> = BTreeMap::new();
+        let mut errors: Vec<FieldError> = Vec::new();
+
+        for (i, (taxonomy_id, term_id, share)) in shares.into_iter().enumerate() {
+            let share_field = format!("{}.shares.{}", field, i);
+            if share == Percentage::default() {
+                errors.push(FieldError::new(
+                    format!("{}.percentage", share_field),
+                    "share must be greater than 0%",
+                ));
+                continue;
+            }
+            last_share.insert(taxonomy_id, i);
+            if by_taxonomy
+                .entry(taxonomy_id)
+                .or_default()
+                .insert(term_id, share)
+                .is_some()
+            {
+                errors.push(FieldError::new(
+                    format!("{}.termId", share_field),
+                    "term is listed more than once",
+                ));
+            }
+        }
+
+        for (taxonomy_id, terms) in &by_taxonomy {
+            let total: u32 = terms.values().map(|share| share.0 as u32).sum();
+            if total > Percentage::FULL.0 as u32 {
+                errors.push(FieldError::new(
+                    format!("{}.shares.{}.percentage", field, last_share[taxonomy_id]),
+                    format!(
+                        "shares of the taxonomy add up to {}%, at most 100%",
+                        total as f64 / 100.0
+                    ),
+                ));
+            }
+        }
+
+        if as_of > today {
+            errors.push(FieldError::new(
+                format!("{}.asOf", field),
+                "cannot be in the future",
+            ));
+        }
+
+        if !errors.is_empty() {
+            return Err(DomainError::invalid_fields(errors));
+        }
+
+        Ok(Self {
+            shares: by_taxonomy,
+            source,
+            as_of,
+        })
+    }
+
+    pub fn source(&self) -> AudienceSource {
+        self.source
+    }
+
+    pub fn as_of(&self) -> NaiveDate {
+        self.as_of
+    }
+
+    /// Taxonomies with the share of each of their terms, both in ascending id order
+    pub fn iter(&self) -> impl Iterator<Item = (&TaxonomyId, &BTreeMap<TermId, Percentage>)> {
+        self.shares.iter()
+    }
+
+    pub fn share(&self, taxonomy_id: &TaxonomyId, term_id: &TermId) -> Option<Percentage> {
+        self.shares.get(taxonomy_id)?.get(term_id).copied()
+    }
+
+    /// Part of the audience covered by the shares of `taxonomy_id`
+    pub fn taxonomy_total(&self, taxonomy_id: &TaxonomyId) -> Percentage {
+        Percentage(
+            self.shares
+                .get(taxonomy_id)
+                .map(|terms| terms.values().map(|share| share.0).sum())
+                .unwrap_or_default(),
+        )
+    }
+
+    /// The terms with a share, without their weights
+    pub fn terms(&self) -> TermSelection {
+        TermSelection::new(
+            "audience",
+            self.shares
+                .iter()
+                .map(|(taxonomy_id, terms)| (*taxonomy_id, terms.keys().copied())),
+        )
+        .expect("every taxonomy of an audience holds at least one term")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn day(d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 3, d).unwrap()
+    }
+
+    fn percent(value: f64) -> Percentage {
+        Percentage::new(value).unwrap()
+    }
+
+    fn fields(error: DomainError) -> Vec<String> {
+        error
+            .field_errors()
+            .iter()
+            .map(|e| e.field.clone())
+            .collect()
+    }
+
+    #[test]
+    fn percentage_rounds_to_two_decimals() {
+        assert_eq!(percent(12.345).as_percent(), 12.35);
+        assert_eq!(percent(0.004), Percentage::default());
+        assert!(Percentage::new(100.01).is_err());
+        assert!(Percentage::new(-1.0).is_err());
+        assert!(Percentage::new(f64::NAN).is_err());
+    }
+
+    #[test]
+    fn shares_of_a_taxonomy_add_up_to_at_most_100() {
+        let (ages, countries) = (TaxonomyId::new(), TaxonomyId::new());
+        let shares = [
+            (ages, TermId::new(), percent(60.0)),
+            (countries, TermId::new(), percent(100.0)),
+            (ages, TermId::new(), percent(40.0)),
+        ];
+
+        let audience = AudienceDemographics::new(
+            "audience",
+            shares,
+            AudienceSource::SelfReported,
+            day(1),
+            day(1),
+        )
+        .unwrap();
+        assert_eq!(audience.taxonomy_total(&ages), Percentage::FULL);
+
+        let over = [
+            (ages, TermId::new(), percent(60.0)),
+            (countries, TermId::new(), percent(10.0)),
+            (ages, TermId::new(), percent(40.01)),
+        ];
+        let error = AudienceDemographics::new(
+            "audience",
+            over,
+            AudienceSource::SelfReported,
+            day(1),
+            day(1),
+        )
+        .unwrap_err();
+        assert_eq!(fields(error), vec!["audience.shares.2.percentage"]);
+    }
+
+    #[test]
+    fn a_share_rounded_to_zero_is_rejected() {
+        let taxonomy = TaxonomyId::new();
+        let shares = [
+            (taxonomy, TermId::new(), percent(50.0)),
+            (taxonomy, TermId::new(), percent(0.004)),
+        ];
+
+        let error = AudienceDemographics::new(
+            "socialProfiles.1.audience",
+            shares,
+            AudienceSource::ThirdParty,
+            day(1),
+            day(1),
+        )
+        .unwrap_err();
+        assert_eq!(
+            fields(error),
+            vec!["socialProfiles.1.audience.shares.1.percentage"]
+        );
+    }
+
+    #[test]
+    fn a_repeated_term_is_rejected() {
+        let (taxonomy, term) = (TaxonomyId::new(), TermId::new());
+        let shares = [
+            (taxonomy, term, percent(10.0)),
+            (taxonomy, term, percent(20.0)),
+        ];
+
+        let error = AudienceDemographics::new(
+            "audience",
+            shares,
+            AudienceSource::SelfReported,
+            day(1),
+            day(1),
+        )
+        .unwrap_err();
+        assert_eq!(fields(error), vec!["audience.shares.1.termId"]);
+    }
+
+    #[test]
+    fn as_of_cannot_be_in_the_future() {
+        let shares = [(TaxonomyId::new(), TermId::new(), percent(10.0))];
+
+        assert!(
+            AudienceDemographics::new(
+                "audience",
+                shares,
+                AudienceSource::PlatformInsights,
+                day(1),
+                day(1),
+            )
+            .is_ok()
+        );
+
+        let error = AudienceDemographics::new(
+            "audience",
+            shares,
+            AudienceSource::PlatformInsights,
+            day(2),
+            day(1),
+        )
+        .unwrap_err();
+        assert_eq!(fields(error), vec!["audience.asOf"]);
+    }
+}
@@ -1,10 +1,30 @@
 use std::borrow::Cow;
 
+/// Rejected value of one input field
+///
+/// `field` is a dotted path from the input root, e.g. `demographics.<taxonomyId>`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldError {
+    pub field: String,
+    pub message: String,
+}
+
+impl FieldError {
+    pub fn new(field: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            field: field.into(),
+            message: message.into(),
+        }
+    }
+}
+
 #[derive(Debug, thiserror::Error)]
 pub enum DomainError {
     #[error("validation: {message}")]
     Validation {
         message: Cow<'static, str>,
+        /// Fields at fault, empty when the error is not tied to an input field
+        fields: Vec<FieldError>,
         #[source]
         source: Option<Box<dyn std::error::Error + Send + Sync>>,
     },
@@ -29,16 +49,64 @@ pub enum DomainError {
         #[source]
         source: Option<Box<dyn std::error::Error + Send + Sync>>,
     },
+
+    /// A dependency outside the domain (another subgraph, a website, a mail
+    /// server) failed or could not be reached; never shown to clients as is
+    #[error("unavailable: {message}")]
+    Unavailable {
+        message: Cow<'static, str>,
+        #[source]
+        source: Option<Box<dyn std::error::Error + Send + Sync>>,
+    },
 }
 
 impl DomainError {
+    /// Stable, low-cardinality name of the error variant (e.g. for metrics labels)
+    pub fn kind(&self) -> &'static str {
+        match self {
+            DomainError::Validation { .. } => "validation",
+            DomainError::Conflict { .. } => "conflict",
+            DomainError::NotFound { .. } => "not_found",
+            DomainError::Forbidden { .. } => "forbidden",
+            DomainError::Unavailable { .. } => "unavailable",
+        }
+    }
+
     pub fn validation(msg: impl Into<Cow<'static, str>>) -> Self {
         DomainError::Validation {
             message: msg.into(),
+            fields: Vec::new(),
             source: None,
         }
     }
 
+    /// Validation error carrying every rejected field, the message names the first one
+    pub fn invalid_fields(fields: Vec<FieldError>) -> Self {
+        let message = match fields.as_slice() {
+            [] => "invalid input".to_string(),
+            [only] => format!("{}: {}", only.field, only.message),
+            [first, rest @ ..] => format!(
+                "{}: {} (and {} more invalid fields)",
+                first.field,
+                first.message,
+                rest.len()
+            ),
+        };
+        DomainError::Validation {
+            message: message.into(),
+            fields,
+            source: None,
+        }
+    }
+
+    /// Fields at fault, empty for anything but a field-level validation error
+    pub fn field_errors(&self) -> &[FieldError] {
+        match self {
+            DomainError::Validation { fields, .. } => fields,
+            _ => &[],
+        }
+    }
+
     pub fn conflict(msg: impl Into<Cow<'static, str>>) -> Self {
         DomainError::Conflict {
             message: msg.into(),
@@ -69,4 +137,21 @@ impl DomainError {
             source: Some(Box::new(err)),
         }
     }
+
+    pub fn unavailable(msg: impl Into<Cow<'static, str>>) -> Self {
+        DomainError::Unavailable {
+            message: msg.into(),
+            source: None,
+        }
+    }
+
+    pub fn unavailable_with<E>(msg: impl Into<Cow<'static, str>>, err: E) -> Self
+    where
+        E: std::error::Error + Send + Sync + 'static,
+    {
+        DomainError::Unavailable {
+            message: msg.into(),
+            source: Some(Box::new(err)),
+        }
+    }
 }
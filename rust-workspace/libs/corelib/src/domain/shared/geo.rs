@@ -0,0 +1,316 @@
+use serde::{Deserialize, Serialize};
+
+use crate::domain::shared::error::{DomainError, FieldError};
+
+/// Mean Earth radius used for great-circle distances
+pub const EARTH_RADIUS_KM: f64 = 6371.0088;
+
+/// Kilometres in one degree of latitude
+const KM_PER_DEGREE: f64 = EARTH_RADIUS_KM * std::f64::consts::PI / 180.0;
+
+/// WGS84 coordinates in degrees
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct GeoPoint {
+    latitude: f64,
+    longitude: f64,
+}
+
+impl GeoPoint {
+    /// # Errors
+    /// `latitude` outside `-90..=90` or `longitude` outside `-180..=180`, as field errors under `field`
+    pub fn new(latitude: f64, longitude: f64, field: &str) -> Result<Self, DomainError> {
+        let mut errors: Vec<FieldError> = Vec::new();
+        if !latitude.is_finite() || !(-90.0..=90.0).contains(&latitude) {
+            errors.push(FieldError::new(
+                format!("{}.latitude", field),
+                "must be between -90 and 90",
+            ));
+        }
+        if !longitude.is_finite() || !(-180.0..=180.0).contains(&longitude) {
+            errors.push(FieldError::new(
+                format!("{}.longitude", field),
+                "must be between -180 and 180",
+            ));
+        }
+        if !errors.is_empty() {
+            return Err(DomainError::invalid_fields(errors));
+        }
+
+        Ok(Self {
+            latitude,
+            longitude,
+        })
+    }
+
+    pub fn latitude(&self) -> f64 {
+        self.latitude
+    }
+
+    pub fn longitude(&self) -> f64 {
+        self.longitude
+    }
+
+    /// Great-circle distance, by the haversine formula
+    pub fn distance_km(&self, other: &GeoPoint) -> f64 {
+        let (lat1, lat2) = (self.latitude.to_radians(), other.latitude.to_radians());
+        let d_lat = lat2 - lat1;
+        let d_lon = (other.longitude - self.longitude).to_radians();
+
+        let a = (d_lat / 2.0).sin().powi(2) + lat1.cos() * lat2.cos() * (d_lon / 2.0).sin().powi(2);
+        2.0 * EARTH_RADIUS_KM * a.sqrt().min(1.0).asin()
+    }
+}
+
+/// Latitude/longitude box
+///
+/// A box whose west edge is east of its east edge crosses the antimeridian,
+/// e.g. west 170 and east -170 spans the 20 degrees around longitude 180.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct GeoBounds {
+    south: f64,
+    west: f64,
+    north: f64,
+    east: f64,
+}
+
+impl GeoBounds {
+    /// # Errors
+    /// - a corner is not a valid coordinate
+    /// - `south_west` is north of `north_east`
+    pub fn new(south_west: GeoPoint, north_east: GeoPoint) -> Result<Self, DomainError> {
+        if south_west.latitude > north_east.latitude {
+            return Err(DomainError::invalid_fields(vec![
+                FieldError::new("southWest.latitude", "is north of northEast"),
+                FieldError::new("northEast.latitude", "is south of southWest"),
+            ]));
+        }
+
+        Ok(Self {
+            south: south_west.latitude,
+            west: south_west.longitude,
+            north: north_east.latitude,
+            east: north_east.longitude,
+        })
+    }
+
+    /// Smallest box holding every point within `radius_km` of `center`
+    pub fn around(center: &GeoPoint, radius_km: f64) -> Self {
+        let d_lat = radius_km / KM_PER_DEGREE;
+        let south = center.latitude - d_lat;
+        let north = center.latitude + d_lat;
+
+        // Past a pole every longitude is within reach
+        if south <= -90.0 || north >= 90.0 {
+            return Self {
+                south: south.max(-90.0),
+                west: -180.0,
+                north: north.min(90.0),
+                east: 180.0,
+            };
+        }
+
+        // Widest longitude span of the circle, reached north of the center's
+        // parallel in the northern hemisphere and south of it in the southern
+        let ratio = (radius_km / EARTH_RADIUS_KM).sin() / center.latitude.to_radians().cos();
+        if ratio >= 1.0 {
+            return Self {
+                south,
+                west: -180.0,
+                north,
+                east: 180.0,
+            };
+        }
+        let d_lon = ratio.asin().to_degrees();
+
+        Self {
+            south,
+            west: wrap_longitude(center.longitude - d_lon),
+            north,
+            east: wrap_longitude(center.longitude + d_lon),
+        }
+    }
+
+    pub fn south_west(&self) -> GeoPoint {
+        GeoPoint {
+            latitude: self.south,
+            longitude: self.west,
+        }
+    }
+
+    pub fn north_east(&self) -> GeoPoint {
+        GeoPoint {
+            latitude: self.north,
+            longitude: self.east,
+        }
+    }
+
+    pub fn crosses_antimeridian(&self) -> bool {
+        self.west > self.east
+    }
+
+    pub fn contains(&self, point: &GeoPoint) -> bool {
+        let within_latitude = (self.south..=self.north).contains(&point.latitude);
+        let within_longitude = if self.crosses_antimeridian() {
+            point.longitude >= self.west || point.longitude <= self.east
+        } else {
+            (self.west..=self.east).contains(&point.longitude)
+        };
+        within_latitude && within_longitude
+    }
+
+    /// The box as `[south, west]`, `[north, east]` corners, split in two when it crosses the antimeridian
+    pub fn envelopes(&self) -> Vec<([f64; 2], [f64; 2])> {
+        if self.crosses_antimeridian() {
+            vec![
+                ([self.south, self.west], [self.north, 180.0]),
+                ([self.south, -180.0], [self.north, self.east]),
+            ]
+        } else {
+            vec![([self.south, self.west], [self.north, self.east])]
+        }
+    }
+
+    pub fn center(&self) -> GeoPoint {
+        let east = if self.crosses_antimeridian() {
+            self.east + 360.0
+        } else {
+            self.east
+        };
+        GeoPoint {
+            latitude: (self.south + self.north) / 2.0,
+            longitude: wrap_longitude((self.west + east) / 2.0),
+        }
+    }
+}
+
+/// Area of a geo search
+///
+/// # Variants
+/// - `Radius`: Points within `radius_km` of `center`, ordered by distance from it.
+/// - `Bounds`: Points inside the box, ordered by distance from its center.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GeoArea {
+    Radius { center: GeoPoint, radius_km: f64 },
+    Bounds(GeoBounds),
+}
+
+impl GeoArea {
+    /// # Errors
+    /// `radius_km` is not a positive number, or is more than half the Earth's circumference
+    pub fn radius(center: GeoPoint, radius_km: f64) -> Result<Self, DomainError> {
+        let max_km = std::f64::consts::PI * EARTH_RADIUS_KM;
+        if !radius_km.is_finite() || radius_km <= 0.0 || radius_km > max_km {
+            return Err(DomainError::invalid_fields(vec![FieldError::new(
+                "radiusKm",
+                format!("must be greater than 0 and at most {:.0}", max_km),
+            )]));
+        }
+        Ok(Self::Radius { center, radius_km })
+    }
+
+    /// Point distances are measured from
+    pub fn origin(&self) -> GeoPoint {
+        match self {
+            GeoArea::Radius { center, .. } => *center,
+            GeoArea::Bounds(bounds) => bounds.center(),
+        }
+    }
+
+    /// Box holding the whole area, for a first pass through a spatial index
+    pub fn bounds(&self) -> GeoBounds {
+        match self {
+            GeoArea::Radius { center, radius_km } => GeoBounds::around(center, *radius_km),
+            GeoArea::Bounds(bounds) => *bounds,
+        }
+    }
+
+    pub fn contains(&self, point: &GeoPoint) -> bool {
+        match self {
+            GeoArea::Radius { center, radius_km } => center.distance_km(point) <= *radius_km,
+            GeoArea::Bounds(bounds) => bounds.contains(point),
+        }
+    }
+}
+
+/// A search result with its distance from the search origin
+#[derive(Debug, Clone)]
+pub struct Nearby<T> {
+    pub value: T,
+    pub distance_km: f64,
+}
+
+fn wrap_longitude(longitude: f64) -> f64 {
+    if longitude > 180.0 {
+        longitude - 360.0
+    } else if longitude < -180.0 {
+        longitude + 360.0
+    } else {
+        longitude
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn point(latitude: f64, longitude: f64) -> GeoPoint {
+        GeoPoint::new(latitude, longitude, "point").unwrap()
+    }
+
+    #[test]
+    fn new_names_both_corners_when_they_are_inverted() {
+        let err = GeoBounds::new(point(10.0, 0.0), point(-10.0, 5.0)).unwrap_err();
+
+        let fields: Vec<&str> = err
+            .field_errors()
+            .iter()
+            .map(|e| e.field.as_str())
+            .collect();
+        assert_eq!(fields, ["southWest.latitude", "northEast.latitude"]);
+    }
+
+    #[test]
+    fn around_the_equator_spans_the_radius_both_ways() {
+        let center = point(0.0, 10.0);
+        let bounds = GeoBounds::around(&center, 111.0);
+
+        assert!(!bounds.crosses_antimeridian());
+        assert!((bounds.north_east().latitude() - 1.0).abs() < 0.01);
+        assert!((bounds.south_west().latitude() + 1.0).abs() < 0.01);
+        assert!((bounds.north_east().longitude() - 11.0).abs() < 0.01);
+        assert!((bounds.south_west().longitude() - 9.0).abs() < 0.01);
+        assert_eq!(bounds.envelopes().len(), 1);
+    }
+
+    #[test]
+    fn around_a_pole_takes_every_longitude() {
+        let bounds = GeoBounds::around(&point(89.5, 45.0), 200.0);
+
+        assert_eq!(bounds.north_east().latitude(), 90.0);
+        assert_eq!(bounds.south_west().longitude(), -180.0);
+        assert_eq!(bounds.north_east().longitude(), 180.0);
+        assert!(bounds.contains(&point(89.9, -135.0)));
+    }
+
+    #[test]
+    fn around_the_antimeridian_splits_into_two_envelopes() {
+        let bounds = GeoBounds::around(&point(0.0, 179.5), 111.0);
+
+        assert!(bounds.crosses_antimeridian());
+        let envelopes = bounds.envelopes();
+        assert_eq!(envelopes.len(), 2);
+        assert_eq!(envelopes[0].1[1], 180.0);
+        assert_eq!(envelopes[1].0[1], -180.0);
+        assert!(bounds.contains(&point(0.0, 179.9)));
+        assert!(bounds.contains(&point(0.0, -179.9)));
+        assert!(!bounds.contains(&point(0.0, 0.0)));
+    }
+
+    #[test]
+    fn center_of_a_box_across_the_antimeridian_wraps() {
+        let bounds = GeoBounds::new(point(-1.0, 170.0), point(1.0, -170.0)).unwrap();
+
+        assert_eq!(bounds.center().longitude().abs(), 180.0);
+        assert_eq!(bounds.center().latitude(), 0.0);
+    }
+}
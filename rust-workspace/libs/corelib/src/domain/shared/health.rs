@@ -0,0 +1,56 @@
+use async_trait::async_trait;
+use serde::Serialize;
+
+/// Health of a single component
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HealthStatus {
+    Up,
+    Degraded,
+    Down,
+}
+
+/// Result of checking one component (repository, event bus, config, ...)
+///
+/// # Fields
+/// - `component`: Name of the checked component, e.g. `"user_repository"`.
+/// - `status`: Health of the component.
+/// - `details`: Optional human readable explanation.
+#[derive(Debug, Clone, Serialize)]
+pub struct ComponentHealth {
+    pub component: String,
+    pub status: HealthStatus,
+    pub details: Option<String>,
+}
+
+impl ComponentHealth {
+    pub fn up(component: impl Into<String>, details: impl Into<String>) -> Self {
+        Self {
+            component: component.into(),
+            status: HealthStatus::Up,
+            details: Some(details.into()),
+        }
+    }
+
+    pub fn degraded(component: impl Into<String>, details: impl Into<String>) -> Self {
+        Self {
+            component: component.into(),
+            status: HealthStatus::Degraded,
+            details: Some(details.into()),
+        }
+    }
+
+    pub fn down(component: impl Into<String>, details: impl Into<String>) -> Self {
+        Self {
+            component: component.into(),
+            status: HealthStatus::Down,
+            details: Some(details.into()),
+        }
+    }
+}
+
+/// Readiness check implemented by every adapter a service depends on
+#[async_trait]
+pub trait HealthCheck: Send + Sync {
+    async fn check(&self) -> ComponentHealth;
+}
@@ -1,4 +1,10 @@
 pub mod error;
 pub mod value_object;
 pub mod event;
-pub mod specs;
\ No newline at end of file
+pub mod specs;
+pub mod health;
+pub mod patch;
+pub mod audience;
+pub mod term_selection;
+pub mod geo;
+pub mod ports;
\ No newline at end of file
@@ -0,0 +1,70 @@
+use serde::{Deserialize, Serialize};
+
+/// Requested change to an optional field of an aggregate
+///
+/// Distinguishes "leave the field as it is" from "clear the field", which a
+/// plain `Option<T>` cannot express.
+///
+/// # Variants
+/// - `Unchanged`: The field is not touched.
+/// - `Clear`: The field is reset to `None`.
+/// - `Set`: The field is replaced with the given value.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Patch<T> {
+    #[default]
+    Unchanged,
+    Clear,
+    Set(T),
+}
+
+impl<T> Patch<T> {
+    /// Maps the value of a `Set` patch, keeping `Unchanged` and `Clear` as they are
+    pub fn map<U>(self, f: impl FnOnce(T) -> U) -> Patch<U> {
+        match self {
+            Patch::Unchanged => Patch::Unchanged,
+            Patch::Clear => Patch::Clear,
+            Patch::Set(value) => Patch::Set(f(value)),
+        }
+    }
+
+    /// The value a `Set` patch writes, `None` for `Unchanged` and `Clear`
+    pub fn value(&self) -> Option<&T> {
+        match self {
+            Patch::Set(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Returns the new value of the field, or `None` when it is left unchanged
+    ///
+    /// `Some(None)` means the field must be cleared.
+    pub fn into_change(self) -> Option<Option<T>> {
+        match self {
+            Patch::Unchanged => None,
+            Patch::Clear => Some(None),
+            Patch::Set(value) => Some(Some(value)),
+        }
+    }
+}
+
+impl<T, E> Patch<Result<T, E>> {
+    /// Turns a patch of results into a result of patch, failing on the first error
+    pub fn transpose(self) -> Result<Patch<T>, E> {
+        match self {
+            Patch::Unchanged => Ok(Patch::Unchanged),
+            Patch::Clear => Ok(Patch::Clear),
+            Patch::Set(value) => value.map(Patch::Set),
+        }
+    }
+}
+
+impl<T> From<Option<T>> for Patch<T> {
+    /// `Some` becomes `Set`, `None` becomes `Clear`
+    fn from(value: Option<T>) -> Self {
+        match value {
+            Some(value) => Patch::Set(value),
+            None => Patch::Clear,
+        }
+    }
+}
@@ -0,0 +1,20 @@
+use async_trait::async_trait;
+
+use crate::domain::shared::{
+    error::{DomainError, FieldError},
+    health::HealthCheck,
+    term_selection::TermSelection,
+};
+
+/// Checks the taxonomy and term references of a `TermSelection` against the catalogue
+#[async_trait]
+pub trait TermSelectionValidator: HealthCheck + Send + Sync {
+    /// Every rejected reference under `field`, empty when all of them are valid
+    ///
+    /// `Err` is kept for a catalogue that cannot be reached, not for invalid input.
+    async fn validate(
+        &self,
+        field: &str,
+        selection: &TermSelection,
+    ) -> Result<Vec<FieldError>, DomainError>;
+}
@@ -0,0 +1,377 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+use serde::{Deserialize, Serialize};
+
+use crate::domain::{
+    shared::error::{DomainError, FieldError},
+    taxonomy::{Taxonomy, value_objects::TaxonomyId},
+    term::{Term, value_objects::TermId},
+};
+
+/// Terms picked in each taxonomy, e.g. the age ranges of an audience or the
+/// amenities of a business
+///
+/// Every taxonomy holds at least one term and no term twice. Taxonomies and
+/// terms are kept sorted, so equal selections compare, serialize and render
+/// the same. Serialized as a map of taxonomy id to term ids.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(
+    try_from = "BTreeMap<TaxonomyId, Vec<TermId>>",
+    into = "BTreeMap<TaxonomyId, Vec<TermId>>"
+)]
+pub struct TermSelection(BTreeMap<TaxonomyId, BTreeSet<TermId>>);
+
+/// Field that errors of a selection read back from storage are reported under
+const STORED_FIELD: &str = "terms";
+
+impl TermSelection {
+    /// Collects the terms of every taxonomy, a taxonomy listed twice has its terms merged
+    ///
+    /// Fails on any entry without a term, even when another entry of the same
+    /// taxonomy has some. Such entries are reported as `<field>.<taxonomyId>`.
+    pub fn new<I, T>(field: &str, entries: I) -> Result<Self, DomainError>
+    where
+        I: IntoIterator<Item = (TaxonomyId, T)>,
+        T: IntoIterator<Item = TermId>,
+    {
+        let mut selection: BTreeMap<TaxonomyId, BTreeSet<TermId>> = BTreeMap::new();
+        let mut errors: Vec<FieldError> = Vec::new();
+        for (taxonomy_id, term_ids) in entries {
+            let mut term_ids = term_ids.into_iter().peekable();
+            if term_ids.peek().is_none() {
+                errors.push(FieldError::new(
+                    format!("{}.{}", field, taxonomy_id.as_str()),
+                    "pick at least one term",
+                ));
+                continue;
+            }
+            selection.entry(taxonomy_id).or_default().extend(term_ids);
+        }
+
+        if !errors.is_empty() {
+            return Err(DomainError::invalid_fields(errors));
+        }
+        Ok(Self(selection))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Number of taxonomies with picked terms
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Taxonomies with their terms, both in ascending id order
+    pub fn iter(&self) -> impl Iterator<Item = (&TaxonomyId, &BTreeSet<TermId>)> {
+        self.0.iter()
+    }
+
+    pub fn taxonomy_ids(&self) -> impl Iterator<Item = TaxonomyId> + '_ {
+        self.0.keys().copied()
+    }
+
+    /// Every picked term once, even when filed under several taxonomies
+    pub fn term_ids(&self) -> BTreeSet<TermId> {
+        self.0.values().flatten().copied().collect()
+    }
+
+    pub fn terms(&self, taxonomy_id: &TaxonomyId) -> Option<&BTreeSet<TermId>> {
+        self.0.get(taxonomy_id)
+    }
+
+    pub fn contains(&self, taxonomy_id: &TaxonomyId, term_id: &TermId) -> bool {
+        self.0
+            .get(taxonomy_id)
+            .is_some_and(|terms| terms.contains(term_id))
+    }
+
+    /// Terms picked in either side
+    pub fn merge(&self, other: &TermSelection) -> TermSelection {
+        let mut merged = self.0.clone();
+        for (taxonomy_id, terms) in &other.0 {
+            merged
+                .entry(*taxonomy_id)
+                .or_default()
+                .extend(terms.iter().copied());
+        }
+        Self(merged)
+    }
+
+    /// Terms picked in both sides, taxonomies left without a term are dropped
+    pub fn intersect(&self, other: &TermSelection) -> TermSelection {
+        self.retain_terms(|taxonomy_id, term_id| other.contains(taxonomy_id, term_id))
+    }
+
+    /// Terms picked here but not in `other`, taxonomies left without a term are dropped
+    pub fn diff(&self, other: &TermSelection) -> TermSelection {
+        self.retain_terms(|taxonomy_id, term_id| !other.contains(taxonomy_id, term_id))
+    }
+
+    /// How well this selection fits a target audience, from `0.0` to `1.0`
+    ///
+    /// The share of the target's taxonomies in which at least one of the
+    /// target's terms is picked here. A target without taxonomies matches fully.
+    pub fn match_score(&self, target: &TermSelection) -> f64 {
+        if target.is_empty() {
+            return 1.0;
+        }
+
+        let matched = target
+            .iter()
+            .filter(|(taxonomy_id, terms)| {
+                terms
+                    .iter()
+                    .any(|term_id| self.contains(taxonomy_id, term_id))
+            })
+            .count();
+        matched as f64 / target.len() as f64
+    }
+
+    fn retain_terms(&self, keep: impl Fn(&TaxonomyId, &TermId) -> bool) -> TermSelection {
+        Self(
+            self.0
+                .iter()
+                .map(|(taxonomy_id, terms)| {
+                    let terms: BTreeSet<TermId> = terms
+                        .iter()
+                        .filter(|term_id| keep(taxonomy_id, term_id))
+                        .copied()
+                        .collect();
+                    (*taxonomy_id, terms)
+                })
+                .filter(|(_, terms)| !terms.is_empty())
+                .collect(),
+        )
+    }
+}
+
+impl TryFrom<BTreeMap<TaxonomyId, Vec<TermId>>> for TermSelection {
+    type Error = DomainError;
+
+    fn try_from(value: BTreeMap<TaxonomyId, Vec<TermId>>) -> Result<Self, Self::Error> {
+        Self::new(STORED_FIELD, value)
+    }
+}
+
+impl From<TermSelection> for BTreeMap<TaxonomyId, Vec<TermId>> {
+    fn from(value: TermSelection) -> Self {
+        value
+            .0
+            .into_iter()
+            .map(|(taxonomy_id, terms)| (taxonomy_id, terms.into_iter().collect()))
+            .collect()
+    }
+}
+
+/// What term selection validation needs to know about a taxonomy
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TaxonomyReference {
+    pub id: TaxonomyId,
+    pub visible: bool,
+    pub deleted: bool,
+}
+
+impl From<&Taxonomy> for TaxonomyReference {
+    fn from(value: &Taxonomy) -> Self {
+        Self {
+            id: value.id,
+            visible: value.visible,
+            deleted: value.deleted.is_deleted(),
+        }
+    }
+}
+
+/// What term selection validation needs to know about a term
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TermReference {
+    pub id: TermId,
+    pub taxonomy_id: TaxonomyId,
+    pub visible: bool,
+    pub deleted: bool,
+}
+
+impl From<&Term> for TermReference {
+    fn from(value: &Term) -> Self {
+        Self {
+            id: value.id,
+            taxonomy_id: value.taxonomy_id,
+            visible: value.visible,
+            deleted: value.deleted.is_deleted(),
+        }
+    }
+}
+
+/// Taxonomies and terms found for the references of a `TermSelection`
+///
+/// Adapters look the references up however they can and leave the checks to
+/// `violations`, so every adapter rejects the same inputs.
+#[derive(Debug, Clone, Default)]
+pub struct TermCatalogue {
+    taxonomies: HashMap<TaxonomyId, TaxonomyReference>,
+    terms: HashMap<TermId, TermReference>,
+}
+
+impl TermCatalogue {
+    pub fn new(
+        taxonomies: impl IntoIterator<Item = TaxonomyReference>,
+        terms: impl IntoIterator<Item = TermReference>,
+    ) -> Self {
+        Self {
+            taxonomies: taxonomies.into_iter().map(|t| (t.id, t)).collect(),
+            terms: terms.into_iter().map(|t| (t.id, t)).collect(),
+        }
+    }
+
+    /// Taxonomy and term ids referenced by `selection`, each listed once
+    pub fn references(selection: &TermSelection) -> (Vec<TaxonomyId>, Vec<TermId>) {
+        (
+            selection.taxonomy_ids().collect(),
+            selection.term_ids().into_iter().collect(),
+        )
+    }
+
+    /// Every reference of `selection` that is unknown, deleted, hidden or
+    /// filed under another taxonomy
+    ///
+    /// Taxonomies are reported as `<field>.<taxonomyId>` and terms as
+    /// `<field>.<taxonomyId>.<termId>`.
+    pub fn violations(&self, field: &str, selection: &TermSelection) -> Vec<FieldError> {
+        let mut violations = Vec::new();
+        for (taxonomy_id, term_ids) in selection.iter() {
+            let taxonomy_field = format!("{}.{}", field, taxonomy_id.as_str());
+
+            match self.taxonomies.get(taxonomy_id) {
+                None => violations.push(FieldError::new(&taxonomy_field, "unknown taxonomy")),
+                Some(taxonomy) if taxonomy.deleted => {
+                    violations.push(FieldError::new(&taxonomy_field, "taxonomy is deleted"))
+                }
+                Some(taxonomy) if !taxonomy.visible => {
+                    violations.push(FieldError::new(&taxonomy_field, "taxonomy is not visible"))
+                }
+                Some(_) => {}
+            }
+
+            for term_id in term_ids {
+                let term_field = format!("{}.{}", taxonomy_field, term_id.as_str());
+
+                let message = match self.terms.get(term_id) {
+                    None => "unknown term".to_string(),
+                    Some(term) if term.taxonomy_id != *taxonomy_id => {
+                        format!("term belongs to taxonomy {}", term.taxonomy_id.as_str())
+                    }
+                    Some(term) if term.deleted => "term is deleted".to_string(),
+                    Some(term) if !term.visible => "term is not visible".to_string(),
+                    Some(_) => continue,
+                };
+                violations.push(FieldError::new(term_field, message));
+            }
+        }
+        violations
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ids<const N: usize>() -> [TermId; N] {
+        std::array::from_fn(|_| TermId::new())
+    }
+
+    fn selection(entries: &[(TaxonomyId, &[TermId])]) -> TermSelection {
+        TermSelection::new(
+            "terms",
+            entries
+                .iter()
+                .map(|(taxonomy_id, terms)| (*taxonomy_id, terms.iter().copied())),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn new_merges_a_repeated_taxonomy_and_dedupes_terms() {
+        let taxonomy = TaxonomyId::new();
+        let [a, b] = ids();
+
+        let merged = selection(&[(taxonomy, &[b, a]), (taxonomy, &[a])]);
+
+        assert_eq!(merged.len(), 1);
+        assert_eq!(merged.terms(&taxonomy).unwrap(), &BTreeSet::from([a, b]));
+    }
+
+    #[test]
+    fn new_rejects_every_empty_entry() {
+        let (first, second) = (TaxonomyId::new(), TaxonomyId::new());
+        let [a] = ids();
+
+        let error = TermSelection::new(
+            "categories",
+            [(first, vec![]), (first, vec![a]), (second, vec![])],
+        )
+        .unwrap_err();
+
+        let mut fields: Vec<String> = error
+            .field_errors()
+            .iter()
+            .map(|e| e.field.clone())
+            .collect();
+        fields.sort();
+        let mut expected = vec![
+            format!("categories.{}", first.as_str()),
+            format!("categories.{}", second.as_str()),
+        ];
+        expected.sort();
+        assert_eq!(fields, expected);
+    }
+
+    #[test]
+    fn deserializing_keeps_the_invariants() {
+        let taxonomy = TaxonomyId::new();
+        let empty = format!(r#"{{"{}":[]}}"#, taxonomy.as_str());
+        assert!(serde_json::from_str::<TermSelection>(&empty).is_err());
+
+        let [a] = ids();
+        let value = selection(&[(taxonomy, &[a, a])]);
+        let json = serde_json::to_string(&value).unwrap();
+        assert_eq!(serde_json::from_str::<TermSelection>(&json).unwrap(), value);
+    }
+
+    #[test]
+    fn merge_intersect_and_diff() {
+        let (ages, countries) = (TaxonomyId::new(), TaxonomyId::new());
+        let [young, old, france] = ids();
+        let left = selection(&[(ages, &[young, old]), (countries, &[france])]);
+        let right = selection(&[(ages, &[old])]);
+
+        assert_eq!(left.merge(&right), left);
+        assert_eq!(right.merge(&left), left);
+        assert_eq!(left.intersect(&right), right);
+        assert_eq!(
+            left.diff(&right),
+            selection(&[(ages, &[young]), (countries, &[france])])
+        );
+        // taxonomies left without a term are dropped
+        assert!(right.diff(&left).is_empty());
+        assert_eq!(left.intersect(&TermSelection::default()).len(), 0);
+    }
+
+    #[test]
+    fn match_score_is_the_share_of_matched_target_taxonomies() {
+        let (ages, countries) = (TaxonomyId::new(), TaxonomyId::new());
+        let [young, old, france, spain] = ids();
+        let profile = selection(&[(ages, &[young]), (countries, &[spain])]);
+
+        let target = selection(&[(ages, &[young, old]), (countries, &[france])]);
+        assert_eq!(profile.match_score(&target), 0.5);
+
+        let target = selection(&[(ages, &[old]), (countries, &[france])]);
+        assert_eq!(profile.match_score(&target), 0.0);
+
+        let target = selection(&[(ages, &[young]), (countries, &[spain, france])]);
+        assert_eq!(profile.match_score(&target), 1.0);
+
+        assert_eq!(profile.match_score(&TermSelection::default()), 1.0);
+    }
+}
@@ -3,7 +3,15 @@ use serde::{Deserialize, Serialize};
 use std::fmt;
 use uuid::Uuid;
 
-use crate::{domain::{shared::error::DomainError, taxonomy::value_objects::TaxonomyId}, predule::TermId};
+use crate::domain::{
+    shared::{
+        audience::AudienceDemographics,
+        error::{DomainError, FieldError},
+        geo::GeoPoint,
+        term_selection::TermSelection,
+    },
+    term::value_objects::TermId,
+};
 use std::collections::HashMap;
 
 /// Unique identifier for event
@@ -243,9 +251,7 @@ impl fmt::Display for Deleted {
     }
 }
 
-pub type Demographics = HashMap<TaxonomyId, Vec<TermId>>;
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum SocialMediaPlatform {
     Facebook,
     Instagram,
@@ -255,60 +261,72 @@ pub enum SocialMediaPlatform {
     LinkedIn,
 }
 
+/// Verification state of a social media profile
+///
+/// Only the domain moves a profile between states; clients can request a
+/// verification, reviewers approve or reject it.
+///
+/// ```text
+/// Unverified ──request──▶ Pending ──approve──▶ Verified
+///      ▲                    │
+///      │                    └──reject──▶ Rejected ──request──▶ Pending
+///      └── profile name or link changed (from any state)
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum VerificationStatus {
+    #[default]
+    Unverified,
+    Pending,
+    Verified,
+    Rejected {
+        reason: String,
+    },
+}
+
+impl VerificationStatus {
+    /// Maximum length of a rejection reason
+    pub const MAX_REASON_LEN: usize = 500;
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct SocialMediaMetadata {
     platform: SocialMediaPlatform,
     profile_name: String,
     profile_link: String,
-    mark_for_verification: bool,
-    is_verified: bool,
+    verification: VerificationStatus,
     follower_count: u64,
-    demographics: Option<Demographics>,
+    demographics: Option<TermSelection>,
+    #[serde(default)]
+    audience: Option<AudienceDemographics>,
 }
 
 impl SocialMediaMetadata {
-    /// Creates a new SocialMediaMetadata value object
-    ///
-    /// # Errors
-    /// - profile_name cannot be empty if is_verified = true
-    /// - profile_link must be a valid URL if is_verified = true
+    /// Creates a new, unverified SocialMediaMetadata value object
     pub fn new(
         platform: SocialMediaPlatform,
         profile_name: impl Into<String>,
         profile_link: impl Into<String>,
-        mark_for_verification: bool,
-        is_verified: bool,
         follower_count: u64,
-        demographics: Option<Demographics>,
+        demographics: Option<TermSelection>,
     ) -> Result<Self, DomainError> {
-        let profile_name = profile_name.into();
-        let profile_link = profile_link.into();
-
-        if is_verified && profile_name.trim().is_empty() {
-            return Err(DomainError::validation(format!(
-                "{:?} profile name cannot be empty when verified",
-                platform
-            )));
-        }
-
-        if is_verified && profile_link.trim().is_empty() {
-            return Err(DomainError::validation(format!(
-                "{:?} profile link cannot be empty when verified",
-                platform
-            )));
-        }
-
         Ok(Self {
             platform,
-            profile_name,
-            profile_link,
-            mark_for_verification,
-            is_verified,
+            profile_name: profile_name.into(),
+            profile_link: profile_link.into(),
+            verification: VerificationStatus::Unverified,
             follower_count,
             demographics,
+            audience: None,
         })
     }
 
+    /// Attaches the weighted composition of the profile's audience
+    pub fn with_audience(mut self, audience: Option<AudienceDemographics>) -> Self {
+        self.audience = audience;
+        self
+    }
+
     /// Getters
     pub fn platform(&self) -> SocialMediaPlatform {
         self.platform
@@ -322,21 +340,113 @@ impl SocialMediaMetadata {
         &self.profile_link
     }
 
-    pub fn mark_for_verification(&self) -> bool {
-        self.mark_for_verification
+    pub fn verification(&self) -> &VerificationStatus {
+        &self.verification
     }
 
     pub fn is_verified(&self) -> bool {
-        self.is_verified
+        self.verification == VerificationStatus::Verified
+    }
+
+    pub fn is_pending(&self) -> bool {
+        self.verification == VerificationStatus::Pending
     }
 
     pub fn follower_count(&self) -> u64 {
         self.follower_count
     }
 
-    pub fn demographics(&self) -> Option<&Demographics> {
+    pub fn demographics(&self) -> Option<&TermSelection> {
         self.demographics.as_ref()
     }
+
+    pub fn audience(&self) -> Option<&AudienceDemographics> {
+        self.audience.as_ref()
+    }
+
+    /// Replaces the follower count with a freshly observed one
+    pub fn set_follower_count(&mut self, follower_count: u64) {
+        self.follower_count = follower_count;
+    }
+
+    /// Moves the profile to `Pending`
+    ///
+    /// # Errors
+    /// - profile name and link cannot be empty
+    /// - the profile cannot be pending or verified already
+    pub fn request_verification(&mut self) -> Result<(), DomainError> {
+        if self.profile_name.trim().is_empty() || self.profile_link.trim().is_empty() {
+            return Err(DomainError::validation(format!(
+                "{:?} profile needs a name and a link to be verified",
+                self.platform
+            )));
+        }
+
+        match self.verification {
+            VerificationStatus::Unverified | VerificationStatus::Rejected { .. } => {
+                self.verification = VerificationStatus::Pending;
+                Ok(())
+            }
+            VerificationStatus::Pending => Err(DomainError::conflict(format!(
+                "{:?} profile verification is already pending",
+                self.platform
+            ))),
+            VerificationStatus::Verified => Err(DomainError::conflict(format!(
+                "{:?} profile is already verified",
+                self.platform
+            ))),
+        }
+    }
+
+    /// Moves a `Pending` profile to `Verified`
+    pub fn approve_verification(&mut self) -> Result<(), DomainError> {
+        self.ensure_pending()?;
+        self.verification = VerificationStatus::Verified;
+        Ok(())
+    }
+
+    /// Moves a `Pending` profile to `Rejected`
+    ///
+    /// # Errors
+    /// - reason cannot be empty or longer than `VerificationStatus::MAX_REASON_LEN`
+    /// - the profile must be pending
+    pub fn reject_verification(&mut self, reason: impl Into<String>) -> Result<(), DomainError> {
+        let reason = reason.into().trim().to_owned();
+        if reason.is_empty() {
+            return Err(DomainError::validation("rejection reason cannot be empty"));
+        }
+        if reason.chars().count() > VerificationStatus::MAX_REASON_LEN {
+            return Err(DomainError::validation(format!(
+                "rejection reason cannot exceed {} characters",
+                VerificationStatus::MAX_REASON_LEN
+            )));
+        }
+
+        self.ensure_pending()?;
+        self.verification = VerificationStatus::Rejected { reason };
+        Ok(())
+    }
+
+    /// Takes over the verification state of `previous` when it is the same account
+    fn keep_verification_from(&mut self, previous: &SocialMediaMetadata) {
+        if self.platform == previous.platform
+            && self.profile_name == previous.profile_name
+            && self.profile_link == previous.profile_link
+        {
+            self.verification = previous.verification.clone();
+        }
+    }
+
+    fn ensure_pending(&self) -> Result<(), DomainError> {
+        if self.is_pending() {
+            Ok(())
+        } else {
+            Err(DomainError::conflict(format!(
+                "{:?} profile has no pending verification",
+                self.platform
+            )))
+        }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -345,9 +455,16 @@ pub struct SocialMediaProfiles {
 }
 
 impl SocialMediaProfiles {
-    /// Creates a new collection
+    /// Creates a new collection, a platform listed twice keeps its last profile
     pub fn new(profiles: Vec<SocialMediaMetadata>) -> Self {
-        Self { profiles }
+        let mut unique: Vec<SocialMediaMetadata> = Vec::with_capacity(profiles.len());
+        for profile in profiles {
+            match unique.iter().position(|p| p.platform() == profile.platform()) {
+                Some(pos) => unique[pos] = profile,
+                None => unique.push(profile),
+            }
+        }
+        Self { profiles: unique }
     }
 
     /// Returns all profiles
@@ -365,18 +482,27 @@ impl SocialMediaProfiles {
         self.profiles.iter().filter(|p| p.is_verified()).collect()
     }
 
+    /// Get all profiles waiting for a reviewer
+    pub fn pending(&self) -> Vec<&SocialMediaMetadata> {
+        self.profiles.iter().filter(|p| p.is_pending()).collect()
+    }
+
     /// Get total follower count across all profiles
     pub fn total_followers(&self) -> u64 {
         self.profiles.iter().map(|p| p.follower_count()).sum()
     }
 
     /// Add or update a profile in the collection
-    pub fn upsert(&mut self, profile: SocialMediaMetadata) -> Result<(), DomainError> {
+    ///
+    /// A replaced profile keeps its verification state as long as its name and
+    /// link are unchanged, otherwise it starts over as unverified.
+    pub fn upsert(&mut self, mut profile: SocialMediaMetadata) -> Result<(), DomainError> {
         if let Some(pos) = self
             .profiles
             .iter()
             .position(|p| p.platform() == profile.platform())
         {
+            profile.keep_verification_from(&self.profiles[pos]);
             self.profiles[pos] = profile;
         } else {
             self.profiles.push(profile);
@@ -384,26 +510,77 @@ impl SocialMediaProfiles {
         Ok(())
     }
 
-    /// Mark a profile for verification by platform
-    pub fn mark_for_verification(
+    /// Remove the profile of a platform, returning it
+    pub fn remove(
         &mut self,
         platform: SocialMediaPlatform,
-    ) -> Result<(), DomainError> {
-        if let Some(profile) = self.profiles.iter_mut().find(|p| p.platform() == platform) {
-            *profile = SocialMediaMetadata::new(
-                profile.platform(),
-                profile.profile_name(),
-                profile.profile_link(),
-                true,
-                profile.is_verified(),
-                profile.follower_count(),
-                profile.demographics().cloned(),
-            )?;
-            Ok(())
-        } else {
-            Err(DomainError::not_found(format!("{platform:?} profile")))
+    ) -> Result<SocialMediaMetadata, DomainError> {
+        let pos = self
+            .profiles
+            .iter()
+            .position(|p| p.platform() == platform)
+            .ok_or_else(|| DomainError::not_found(format!("{platform:?} profile")))?;
+        Ok(self.profiles.remove(pos))
+    }
+
+    /// Keeps the verification state of every profile whose name and link did not change
+    pub fn keep_verification_from(&mut self, previous: &SocialMediaProfiles) {
+        for profile in &mut self.profiles {
+            if let Some(existing) = previous.get_by_platform(profile.platform()) {
+                profile.keep_verification_from(existing);
+            }
         }
     }
+
+    /// Returns `true` when the collection has no profiles
+    pub fn is_empty(&self) -> bool {
+        self.profiles.is_empty()
+    }
+
+    /// Update the follower count of a profile by platform
+    pub fn set_follower_count(
+        &mut self,
+        platform: SocialMediaPlatform,
+        follower_count: u64,
+    ) -> Result<(), DomainError> {
+        self.get_mut(platform)?.set_follower_count(follower_count);
+        Ok(())
+    }
+
+    /// Request verification of a profile by platform
+    pub fn request_verification(
+        &mut self,
+        platform: SocialMediaPlatform,
+    ) -> Result<(), DomainError> {
+        self.get_mut(platform)?.request_verification()
+    }
+
+    /// Approve the pending verification of a profile by platform
+    pub fn approve_verification(
+        &mut self,
+        platform: SocialMediaPlatform,
+    ) -> Result<(), DomainError> {
+        self.get_mut(platform)?.approve_verification()
+    }
+
+    /// Reject the pending verification of a profile by platform
+    pub fn reject_verification(
+        &mut self,
+        platform: SocialMediaPlatform,
+        reason: impl Into<String>,
+    ) -> Result<(), DomainError> {
+        self.get_mut(platform)?.reject_verification(reason)
+    }
+
+    fn get_mut(
+        &mut self,
+        platform: SocialMediaPlatform,
+    ) -> Result<&mut SocialMediaMetadata, DomainError> {
+        self.profiles
+            .iter_mut()
+            .find(|p| p.platform() == platform)
+            .ok_or_else(|| DomainError::not_found(format!("{platform:?} profile")))
+    }
 }
 
 /// Email address
@@ -434,6 +611,11 @@ impl EmailAddress {
     pub fn as_str(&self) -> &str {
         &self.0
     }
+
+    /// Part after the `@`, e.g. `example.com` for `info@example.com`
+    pub fn domain(&self) -> &str {
+        self.0.rsplit('@').next().unwrap_or_default().trim()
+    }
 }
 
 /// Phone number
@@ -471,33 +653,101 @@ impl PhoneNumber {
     }
 }
 
-/// Physical address of the business
+/// Postal address, optionally located on the map
 ///
-/// # Field
-/// - `value` - raw string value
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
-#[serde(transparent)]
-pub struct PhysicalAddress(String);
+/// # Fields
+/// - `street`: Street and number, e.g. "12 Rue de Rivoli".
+/// - `city`: City or town.
+/// - `region`: State, province or county.
+/// - `postal_code`: Postal or ZIP code.
+/// - `country_term_id`: The country `Term`.
+/// - `location`: Coordinates of the address, needed to show up in geo searches.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PhysicalAddress {
+    street: String,
+    city: String,
+    region: Option<String>,
+    postal_code: Option<String>,
+    country_term_id: TermId,
+    location: Option<GeoPoint>,
+}
 
 impl PhysicalAddress {
-    pub fn new(value: impl Into<String>) -> Result<Self, DomainError> {
-        let value = value.into();
-
-        if value.trim().is_empty() {
-            return Err(DomainError::validation("Address cannot be empty"));
+    /// # Errors
+    /// `street` or `city` is empty, or a part is too long, as field errors under `address`
+    pub fn new(
+        street: impl Into<String>,
+        city: impl Into<String>,
+        region: Option<String>,
+        postal_code: Option<String>,
+        country_term_id: TermId,
+        location: Option<GeoPoint>,
+    ) -> Result<Self, DomainError> {
+        let mut errors: Vec<FieldError> = Vec::new();
+        let mut check = |field: &str, value: String, max: usize, required: bool| {
+            let value = value.trim().to_string();
+            if value.is_empty() {
+                if required {
+                    errors.push(FieldError::new(
+                        format!("address.{}", field),
+                        "cannot be empty",
+                    ));
+                }
+            } else if value.chars().count() > max {
+                errors.push(FieldError::new(
+                    format!("address.{}", field),
+                    format!("is too long (max {} chars)", max),
+                ));
+            }
+            value
+        };
+
+        let street = check("street", street.into(), 200, true);
+        let city = check("city", city.into(), 100, true);
+        // Optional parts left blank are dropped
+        let region = region
+            .map(|region| check("region", region, 100, false))
+            .filter(|region| !region.is_empty());
+        let postal_code = postal_code
+            .map(|postal_code| check("postalCode", postal_code, 20, false))
+            .filter(|postal_code| !postal_code.is_empty());
+
+        if !errors.is_empty() {
+            return Err(DomainError::invalid_fields(errors));
         }
 
-        if value.len() > 200 {
-            return Err(DomainError::validation(
-                "Address is too long (max 200 chars)",
-            ));
-        }
+        Ok(Self {
+            street,
+            city,
+            region,
+            postal_code,
+            country_term_id,
+            location,
+        })
+    }
 
-        Ok(Self(value))
+    pub fn street(&self) -> &str {
+        &self.street
     }
 
-    pub fn as_str(&self) -> &str {
-        &self.0
+    pub fn city(&self) -> &str {
+        &self.city
+    }
+
+    pub fn region(&self) -> Option<&str> {
+        self.region.as_deref()
+    }
+
+    pub fn postal_code(&self) -> Option<&str> {
+        self.postal_code.as_deref()
+    }
+
+    pub fn country_term_id(&self) -> TermId {
+        self.country_term_id
+    }
+
+    pub fn location(&self) -> Option<GeoPoint> {
+        self.location
     }
 }
 
@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-use crate::domain::shared::event::EventMeta;
+use crate::domain::shared::{event::EventMeta, patch::Patch};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(tag = "type", content = "data")]
@@ -16,10 +16,10 @@ pub enum TaxonomyDomainEvent {
     TaxonomyUpdated {
         meta: EventMeta,
         event_name: String,
-        parent_id: Option<String>,
+        parent_id: Patch<String>,
         name: Option<String>,
         visible: Option<bool>,
-        description: Option<String>,
+        description: Patch<String>,
     },
     TaxonomyDeleted {
         meta: EventMeta,
@@ -34,3 +34,16 @@ pub enum TaxonomyDomainEvent {
         event_name: String,
     },
 }
+
+impl TaxonomyDomainEvent {
+    /// Name of the event, e.g. `"taxonomy.created"`
+    pub fn event_name(&self) -> &str {
+        match self {
+            TaxonomyDomainEvent::TaxonomyCreated { event_name, .. }
+            | TaxonomyDomainEvent::TaxonomyUpdated { event_name, .. }
+            | TaxonomyDomainEvent::TaxonomyDeleted { event_name, .. }
+            | TaxonomyDomainEvent::TaxonomySoftDeleted { event_name, .. }
+            | TaxonomyDomainEvent::TaxonomyRestoredFromSoftDeleted { event_name, .. } => event_name,
+        }
+    }
+}
@@ -5,6 +5,7 @@ pub mod ports;
 use crate::domain::{
     shared::{
         event::EventMeta,
+        patch::Patch,
         value_object::{Deleted, EventId, OcurredAt, Timestamp},
     },
     taxonomy::{
@@ -40,7 +41,7 @@ impl Taxonomy {
     ) -> Self {
         let mut taxonomy = Taxonomy {
             id,
-            parent_id: parent_id.clone(),
+            parent_id,
             name: name.clone(),
             visible,
             description: description.clone(),
@@ -69,17 +70,17 @@ impl Taxonomy {
         taxonomy
     }
 
-    pub fn set_parent_id(&mut self, parent_id: TaxonomyId) {
-        self.parent_id = Some(parent_id.clone());
+    pub fn set_parent_id(&mut self, parent_id: Option<TaxonomyId>) {
+        self.parent_id = parent_id;
         self.touch();
         self.pending_events
             .push(TaxonomyDomainEvent::TaxonomyUpdated {
                 meta: self.next_meta(),
                 event_name: "taxonomy.updated".to_owned(),
-                parent_id: Some(parent_id.as_str().to_owned()),
+                parent_id: parent_id.map(|v| v.as_str().to_owned()).into(),
                 name: None,
                 visible: None,
-                description: None,
+                description: Patch::Unchanged,
             });
     }
 
@@ -90,38 +91,38 @@ impl Taxonomy {
             .push(TaxonomyDomainEvent::TaxonomyUpdated {
                 meta: self.next_meta(),
                 event_name: "taxonomy.updated".to_owned(),
-                parent_id: None,
+                parent_id: Patch::Unchanged,
                 name: Some(name.as_str().to_owned()),
                 visible: None,
-                description: None,
+                description: Patch::Unchanged,
             });
     }
 
     pub fn set_visible(&mut self, visible: bool) {
-        self.visible = visible.clone();
+        self.visible = visible;
         self.touch();
         self.pending_events
             .push(TaxonomyDomainEvent::TaxonomyUpdated {
                 meta: self.next_meta(),
                 event_name: "taxonomy.updated".to_owned(),
-                parent_id: None,
+                parent_id: Patch::Unchanged,
                 name: None,
                 visible: Some(visible.to_owned()),
-                description: None,
+                description: Patch::Unchanged,
             });
     }
 
-    pub fn set_description(&mut self, description: TaxonomyDescription) {
-        self.description = Some(description.clone());
+    pub fn set_description(&mut self, description: Option<TaxonomyDescription>) {
+        self.description = description.clone();
         self.touch();
         self.pending_events
             .push(TaxonomyDomainEvent::TaxonomyUpdated {
                 meta: self.next_meta(),
                 event_name: "taxonomy.updated".to_owned(),
-                parent_id: None,
+                parent_id: Patch::Unchanged,
                 name: None,
                 visible: None,
-                description: Some(description.as_str().to_owned()),
+                description: description.map(|v| v.as_str().to_owned()).into(),
             });
     }
 
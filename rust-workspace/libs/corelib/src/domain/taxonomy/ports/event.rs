@@ -1,9 +1,12 @@
-use crate::domain::taxonomy::events::TaxonomyDomainEvent;
+use crate::domain::{taxonomy::events::TaxonomyDomainEvent, shared::health::HealthCheck};
 
 
 
-pub trait TaxonomyDomainEventBus: Send + Sync {
+pub trait TaxonomyDomainEventBus: HealthCheck + Send + Sync {
     fn publish(&self, events: &[TaxonomyDomainEvent]) -> Result<(), String>;
+
+    /// Delivers every event still queued in the bus, called once on shutdown
+    fn flush(&self) -> Result<(), String>;
 }
 
 pub trait TaxonomyDomainEventHandler: Send + Sync {
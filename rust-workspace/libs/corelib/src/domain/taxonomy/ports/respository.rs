@@ -1,15 +1,18 @@
 use crate::domain::{
-    shared::{error::DomainError, specs::Specification},
+    shared::{error::DomainError, health::HealthCheck, specs::Specification},
     taxonomy::{Taxonomy, TaxonomyId},
 };
 use async_trait::async_trait;
 
 #[async_trait]
-pub trait TaxonomyRepository: Send + Sync {
-    async fn save(&mut self, taxonomy: &Taxonomy) -> Result<(), DomainError>;
+pub trait TaxonomyRepository: HealthCheck + Send + Sync {
+    async fn save(&self, taxonomy: &Taxonomy) -> Result<(), DomainError>;
 
     async fn find_by_id(&self, id: &TaxonomyId) -> Result<Option<Taxonomy>, DomainError>;
 
+    /// Fetches every taxonomy in `ids` in one call; unknown ids are skipped and order is not kept
+    async fn find_by_ids(&self, ids: &[TaxonomyId]) -> Result<Vec<Taxonomy>, DomainError>;
+
     async fn query(
         &self,
         spec: &(dyn Specification<Taxonomy> + Send + Sync),
@@ -17,5 +20,8 @@ pub trait TaxonomyRepository: Send + Sync {
         offset: usize,
     ) -> Result<Vec<Taxonomy>, DomainError>;
 
-    async fn delete(&mut self, id: &TaxonomyId) -> Result<(), DomainError>;
+    async fn delete(&self, id: &TaxonomyId) -> Result<(), DomainError>;
+
+    /// Releases connections held by the repository, called once on shutdown
+    async fn close(&self) -> Result<(), DomainError>;
 }
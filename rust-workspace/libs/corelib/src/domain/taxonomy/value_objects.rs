@@ -29,6 +29,12 @@ impl TaxonomyId {
     }
 }
 
+impl Default for TaxonomyId {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 /// Taxonomy name of a user
 ///
 /// # Field
@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-use crate::domain::shared::event::EventMeta;
+use crate::domain::shared::{event::EventMeta, patch::Patch};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(tag = "type", content = "data")]
@@ -18,10 +18,10 @@ pub enum TermDomainEvent {
         meta: EventMeta,
         event_name: String,
         taxonomy_id: Option<String>,
-        parent_id: Option<String>,
+        parent_id: Patch<String>,
         name: Option<String>,
         visible: Option<bool>,
-        description: Option<String>,
+        description: Patch<String>,
     },
     TermDeleted {
         meta: EventMeta,
@@ -36,3 +36,16 @@ pub enum TermDomainEvent {
         event_name: String,
     },
 }
+
+impl TermDomainEvent {
+    /// Name of the event, e.g. `"term.created"`
+    pub fn event_name(&self) -> &str {
+        match self {
+            TermDomainEvent::TermCreated { event_name, .. }
+            | TermDomainEvent::TermUpdated { event_name, .. }
+            | TermDomainEvent::TermDeleted { event_name, .. }
+            | TermDomainEvent::TermSoftDeleted { event_name, .. }
+            | TermDomainEvent::TermRestoredFromSoftDeleted { event_name, .. } => event_name,
+        }
+    }
+}
@@ -5,6 +5,7 @@ pub mod ports;
 use crate::domain::{
     shared::{
         event::EventMeta,
+        patch::Patch,
         value_object::{Deleted, EventId, OcurredAt, Timestamp},
     },
     taxonomy::value_objects::TaxonomyId,
@@ -43,8 +44,8 @@ impl Term {
     ) -> Self {
         let mut term = Term {
             id,
-            taxonomy_id: taxonomy_id.clone(),
-            parent_id: parent_id.clone(),
+            taxonomy_id,
+            parent_id,
             name: name.clone(),
             visible,
             description: description.clone(),
@@ -72,30 +73,30 @@ impl Term {
         term
     }
     pub fn set_taxonomy_id(&mut self, taxonomy_id: TaxonomyId) {
-        self.taxonomy_id = taxonomy_id.clone();
+        self.taxonomy_id = taxonomy_id;
         self.touch();
         self.pending_events.push(TermDomainEvent::TermUpdated {
             meta: self.next_meta(),
             event_name: "term.updated".to_owned(),
             taxonomy_id: Some(taxonomy_id.as_str().to_owned()),
-            parent_id: None,
+            parent_id: Patch::Unchanged,
             name: None,
             visible: None,
-            description: None,
+            description: Patch::Unchanged,
         });
     }
 
-    pub fn set_parent_id(&mut self, parent_id: TermId) {
-        self.parent_id = Some(parent_id.clone());
+    pub fn set_parent_id(&mut self, parent_id: Option<TermId>) {
+        self.parent_id = parent_id;
         self.touch();
         self.pending_events.push(TermDomainEvent::TermUpdated {
             meta: self.next_meta(),
             event_name: "term.updated".to_owned(),
             taxonomy_id: None,
-            parent_id: Some(parent_id.as_str().to_owned()),
+            parent_id: parent_id.map(|v| v.as_str().to_owned()).into(),
             name: None,
             visible: None,
-            description: None,
+            description: Patch::Unchanged,
         });
     }
 
@@ -106,38 +107,38 @@ impl Term {
             meta: self.next_meta(),
             event_name: "term.updated".to_owned(),
             taxonomy_id: None,
-            parent_id: None,
+            parent_id: Patch::Unchanged,
             name: Some(name.as_str().to_owned()),
             visible: None,
-            description: None,
+            description: Patch::Unchanged,
         });
     }
 
     pub fn set_visible(&mut self, visible: bool) {
-        self.visible = visible.clone();
+        self.visible = visible;
         self.touch();
         self.pending_events.push(TermDomainEvent::TermUpdated {
             meta: self.next_meta(),
             event_name: "term.updated".to_owned(),
             taxonomy_id: None,
-            parent_id: None,
+            parent_id: Patch::Unchanged,
             name: None,
             visible: Some(visible.to_owned()),
-            description: None,
+            description: Patch::Unchanged,
         });
     }
 
-    pub fn set_description(&mut self, description: TermDescription) {
-        self.description = Some(description.clone());
+    pub fn set_description(&mut self, description: Option<TermDescription>) {
+        self.description = description.clone();
         self.touch();
         self.pending_events.push(TermDomainEvent::TermUpdated {
             meta: self.next_meta(),
             event_name: "term.updated".to_owned(),
             taxonomy_id: None,
-            parent_id: None,
+            parent_id: Patch::Unchanged,
             name: None,
             visible: None,
-            description: Some(description.as_str().to_owned()),
+            description: description.map(|v| v.as_str().to_owned()).into(),
         });
     }
 
@@ -1,9 +1,12 @@
-use crate::domain::term::events::TermDomainEvent;
+use crate::domain::{term::events::TermDomainEvent, shared::health::HealthCheck};
 
 
 
-pub trait TermDomainEventBus: Send + Sync {
+pub trait TermDomainEventBus: HealthCheck + Send + Sync {
     fn publish(&self, events: &[TermDomainEvent]) -> Result<(), String>;
+
+    /// Delivers every event still queued in the bus, called once on shutdown
+    fn flush(&self) -> Result<(), String>;
 }
 
 pub trait TermDomainEventHandler: Send + Sync {
@@ -1,15 +1,18 @@
 use crate::domain::{
-    shared::{error::DomainError, specs::Specification},
+    shared::{error::DomainError, health::HealthCheck, specs::Specification},
     term::{Term, TermId},
 };
 use async_trait::async_trait;
 
 #[async_trait]
-pub trait TermRepository: Send + Sync {
-    async fn save(&mut self, term: &Term) -> Result<(), DomainError>;
+pub trait TermRepository: HealthCheck + Send + Sync {
+    async fn save(&self, term: &Term) -> Result<(), DomainError>;
 
     async fn find_by_id(&self, id: &TermId) -> Result<Option<Term>, DomainError>;
 
+    /// Fetches every term in `ids` in one call; unknown ids are skipped and order is not kept
+    async fn find_by_ids(&self, ids: &[TermId]) -> Result<Vec<Term>, DomainError>;
+
     async fn query(
         &self,
         spec: &(dyn Specification<Term> + Send + Sync),
@@ -17,5 +20,8 @@ pub trait TermRepository: Send + Sync {
         offset: usize,
     ) -> Result<Vec<Term>, DomainError>;
 
-    async fn delete(&mut self, id: &TermId) -> Result<(), DomainError>;
+    async fn delete(&self, id: &TermId) -> Result<(), DomainError>;
+
+    /// Releases connections held by the repository, called once on shutdown
+    async fn close(&self) -> Result<(), DomainError>;
 }
@@ -29,6 +29,12 @@ impl TermId {
     }
 }
 
+impl Default for TermId {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 /// Term name of a user
 ///
 /// # Field
@@ -0,0 +1,46 @@
+use crate::domain::{shared::error::DomainError, user::value_object::UserId};
+
+/// Who asks for a change to a user, resolved from the caller's identity and roles
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UserActor {
+    /// Reviewers and operators, who may change any user
+    Admin,
+    /// The user of the calling identity
+    User(UserId),
+    /// Anonymous callers and identities without a user
+    Anonymous,
+}
+
+impl UserActor {
+    /// `forbidden` unless the actor is `user_id` itself or an admin
+    pub fn authorize_change(&self, user_id: &UserId) -> Result<(), DomainError> {
+        match self {
+            UserActor::Admin => Ok(()),
+            UserActor::User(actor) if actor == user_id => Ok(()),
+            UserActor::User(_) | UserActor::Anonymous => Err(DomainError::forbidden(
+                "only the user themselves or an admin can change a user",
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn users_may_only_change_themselves() {
+        let user = UserId::new();
+
+        assert!(UserActor::User(user).authorize_change(&user).is_ok());
+        assert!(UserActor::Admin.authorize_change(&user).is_ok());
+        assert!(matches!(
+            UserActor::User(UserId::new()).authorize_change(&user),
+            Err(DomainError::Forbidden { .. })
+        ));
+        assert!(matches!(
+            UserActor::Anonymous.authorize_change(&user),
+            Err(DomainError::Forbidden { .. })
+        ));
+    }
+}
@@ -2,7 +2,8 @@ use serde::{Deserialize, Serialize};
 
 use crate::domain::shared::{
     event::EventMeta,
-    value_object::{Demographics, SocialMediaProfiles},
+    term_selection::TermSelection,
+    value_object::{SocialMediaMetadata, SocialMediaPlatform, SocialMediaProfiles},
 };
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -11,6 +12,7 @@ pub enum UserDomainEvent {
     UserCreated {
         meta: EventMeta,
         event_name: String,
+        identity_id: String,
         first_name: String,
         last_name: String,
         country_term_id: String,
@@ -25,12 +27,50 @@ pub enum UserDomainEvent {
     UserSocialProfileUpdated {
         meta: EventMeta,
         event_name: String,
-        latest: SocialMediaProfiles,
+        latest: Option<SocialMediaProfiles>,
+    },
+    /// A single platform profile was added or replaced
+    UserSocialProfileUpserted {
+        meta: EventMeta,
+        event_name: String,
+        profile: SocialMediaMetadata,
+    },
+    /// The profile of a platform was removed
+    UserSocialProfileRemoved {
+        meta: EventMeta,
+        event_name: String,
+        platform: SocialMediaPlatform,
+    },
+    /// New follower count of a platform profile was ingested
+    UserSocialProfileFollowersUpdated {
+        meta: EventMeta,
+        event_name: String,
+        platform: SocialMediaPlatform,
+        follower_count: u64,
+    },
+    /// Verification of a platform profile was requested
+    UserSocialProfileVerificationRequested {
+        meta: EventMeta,
+        event_name: String,
+        platform: SocialMediaPlatform,
+    },
+    /// A reviewer verified a platform profile
+    UserSocialProfileVerificationApproved {
+        meta: EventMeta,
+        event_name: String,
+        platform: SocialMediaPlatform,
+    },
+    /// A reviewer turned down the verification of a platform profile
+    UserSocialProfileVerificationRejected {
+        meta: EventMeta,
+        event_name: String,
+        platform: SocialMediaPlatform,
+        reason: String,
     },
     UserDemographicsUpdated {
         meta: EventMeta,
         event_name: String,
-        latest: Demographics,
+        latest: Option<TermSelection>,
     },
     UserDeleted {
         meta: EventMeta,
@@ -45,3 +85,24 @@ pub enum UserDomainEvent {
         event_name: String,
     },
 }
+
+impl UserDomainEvent {
+    /// Name of the event, e.g. `"user.created"`
+    pub fn event_name(&self) -> &str {
+        match self {
+            UserDomainEvent::UserCreated { event_name, .. }
+            | UserDomainEvent::UserBioUpdated { event_name, .. }
+            | UserDomainEvent::UserSocialProfileUpdated { event_name, .. }
+            | UserDomainEvent::UserSocialProfileUpserted { event_name, .. }
+            | UserDomainEvent::UserSocialProfileRemoved { event_name, .. }
+            | UserDomainEvent::UserSocialProfileFollowersUpdated { event_name, .. }
+            | UserDomainEvent::UserSocialProfileVerificationRequested { event_name, .. }
+            | UserDomainEvent::UserSocialProfileVerificationApproved { event_name, .. }
+            | UserDomainEvent::UserSocialProfileVerificationRejected { event_name, .. }
+            | UserDomainEvent::UserDemographicsUpdated { event_name, .. }
+            | UserDomainEvent::UserDeleted { event_name, .. }
+            | UserDomainEvent::UserSoftDeleted { event_name, .. }
+            | UserDomainEvent::UserRestoredFromSoftDeleted { event_name, .. } => event_name,
+        }
+    }
+}
@@ -0,0 +1,273 @@
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+
+use crate::domain::{
+    shared::value_object::{SocialMediaPlatform, SocialMediaProfiles},
+    user::value_object::UserId,
+};
+
+/// Follower count of one platform profile at a point in time
+///
+/// # Fields
+/// - `user_id`: The user owning the profile.
+/// - `platform`: Platform of the profile.
+/// - `follower_count`: Followers when the snapshot was taken.
+/// - `recorded_at`: Moment the count was observed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FollowerSnapshot {
+    pub user_id: UserId,
+    pub platform: SocialMediaPlatform,
+    pub follower_count: u64,
+    pub recorded_at: DateTime<Utc>,
+}
+
+impl FollowerSnapshot {
+    /// Snapshots of every profile in `latest` that is new or whose count differs from `previous`,
+    /// and a zero snapshot for every platform with followers in `previous` that was removed
+    pub fn changes(
+        user_id: UserId,
+        previous: Option<&SocialMediaProfiles>,
+        latest: Option<&SocialMediaProfiles>,
+        recorded_at: DateTime<Utc>,
+    ) -> Vec<Self> {
+        let snapshot = |platform, follower_count| Self {
+            user_id,
+            platform,
+            follower_count,
+            recorded_at,
+        };
+
+        let changed = latest
+            .into_iter()
+            .flat_map(SocialMediaProfiles::all)
+            .filter(|profile| {
+                previous
+                    .and_then(|previous| previous.get_by_platform(profile.platform()))
+                    .is_none_or(|before| before.follower_count() != profile.follower_count())
+            })
+            .map(|profile| snapshot(profile.platform(), profile.follower_count()));
+
+        // a removed platform no longer counts towards the totals
+        let removed = previous
+            .into_iter()
+            .flat_map(SocialMediaProfiles::all)
+            .filter(|before| before.follower_count() > 0)
+            .filter(|before| {
+                latest
+                    .and_then(|latest| latest.get_by_platform(before.platform()))
+                    .is_none()
+            })
+            .map(|before| snapshot(before.platform(), 0));
+
+        changed.chain(removed).collect()
+    }
+}
+
+/// Follower count at a point in time, summed or for a single platform
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FollowerCountPoint {
+    pub recorded_at: DateTime<Utc>,
+    pub follower_count: u64,
+}
+
+impl FollowerCountPoint {
+    /// Points of a single platform history
+    pub fn from_snapshots(snapshots: &[FollowerSnapshot]) -> Vec<Self> {
+        snapshots
+            .iter()
+            .map(|snapshot| Self {
+                recorded_at: snapshot.recorded_at,
+                follower_count: snapshot.follower_count,
+            })
+            .collect()
+    }
+
+    /// Total followers across platforms after each snapshot, the series of
+    /// `SocialMediaProfiles::total_followers` over time
+    ///
+    /// `snapshots` must be sorted oldest first.
+    pub fn totals(snapshots: &[FollowerSnapshot]) -> Vec<Self> {
+        let mut latest: HashMap<SocialMediaPlatform, u64> = HashMap::new();
+        let mut points: Vec<Self> = Vec::new();
+
+        for snapshot in snapshots {
+            latest.insert(snapshot.platform, snapshot.follower_count);
+            let point = Self {
+                recorded_at: snapshot.recorded_at,
+                follower_count: latest.values().sum(),
+            };
+
+            // Snapshots recorded together collapse into one point
+            match points.last_mut() {
+                Some(last) if last.recorded_at == point.recorded_at => *last = point,
+                _ => points.push(point),
+            }
+        }
+
+        points
+    }
+}
+
+/// Change of a follower count over a window
+///
+/// # Fields
+/// - `from`, `to`: Bounds of the window.
+/// - `start_count`: Followers at the start of the window, or at the first point inside it.
+/// - `end_count`: Followers at the last point up to `to`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FollowerGrowth {
+    pub from: DateTime<Utc>,
+    pub to: DateTime<Utc>,
+    pub start_count: u64,
+    pub end_count: u64,
+}
+
+impl FollowerGrowth {
+    /// Growth over `[from, to]` of a series sorted oldest first, `None` without any point up to `to`
+    pub fn over(
+        points: &[FollowerCountPoint],
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> Option<Self> {
+        let points: Vec<&FollowerCountPoint> =
+            points.iter().filter(|p| p.recorded_at <= to).collect();
+
+        let end = points.last()?;
+        let start = points
+            .iter()
+            .rev()
+            .find(|p| p.recorded_at <= from)
+            .or_else(|| points.first())?;
+
+        Some(Self {
+            from,
+            to,
+            start_count: start.follower_count,
+            end_count: end.follower_count,
+        })
+    }
+
+    /// Followers gained, negative when followers were lost
+    pub fn change(&self) -> i64 {
+        self.end_count as i64 - self.start_count as i64
+    }
+
+    /// Relative change, `0.25` for 25% growth; `None` when the window starts at zero followers
+    pub fn rate(&self) -> Option<f64> {
+        if self.start_count == 0 {
+            return None;
+        }
+        Some(self.change() as f64 / self.start_count as f64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+
+    use super::*;
+    use crate::domain::shared::value_object::SocialMediaMetadata;
+
+    fn profiles(counts: &[(SocialMediaPlatform, u64)]) -> SocialMediaProfiles {
+        SocialMediaProfiles::new(
+            counts
+                .iter()
+                .map(|&(platform, followers)| {
+                    SocialMediaMetadata::new(
+                        platform,
+                        "name",
+                        "https://example.com",
+                        followers,
+                        None,
+                    )
+                    .unwrap()
+                })
+                .collect(),
+        )
+    }
+
+    fn at(hour: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 1, 1, hour, 0, 0).unwrap()
+    }
+
+    fn counts(snapshots: &[FollowerSnapshot]) -> Vec<(SocialMediaPlatform, u64)> {
+        let mut counts: Vec<_> = snapshots
+            .iter()
+            .map(|s| (s.platform, s.follower_count))
+            .collect();
+        counts.sort_by_key(|(platform, _)| format!("{platform:?}"));
+        counts
+    }
+
+    #[test]
+    fn changes_cover_new_and_changed_profiles_only() {
+        let previous = profiles(&[
+            (SocialMediaPlatform::Instagram, 100),
+            (SocialMediaPlatform::TikTok, 50),
+        ]);
+        let latest = profiles(&[
+            (SocialMediaPlatform::Instagram, 100),
+            (SocialMediaPlatform::TikTok, 60),
+            (SocialMediaPlatform::Youtube, 10),
+        ]);
+
+        let changes =
+            FollowerSnapshot::changes(UserId::new(), Some(&previous), Some(&latest), at(0));
+
+        assert_eq!(
+            counts(&changes),
+            vec![
+                (SocialMediaPlatform::TikTok, 60),
+                (SocialMediaPlatform::Youtube, 10),
+            ]
+        );
+    }
+
+    #[test]
+    fn changes_zero_removed_platforms() {
+        let previous = profiles(&[
+            (SocialMediaPlatform::Instagram, 100),
+            (SocialMediaPlatform::TikTok, 50),
+            (SocialMediaPlatform::X, 0),
+        ]);
+        let latest = profiles(&[(SocialMediaPlatform::Instagram, 100)]);
+
+        let changes =
+            FollowerSnapshot::changes(UserId::new(), Some(&previous), Some(&latest), at(0));
+        assert_eq!(counts(&changes), vec![(SocialMediaPlatform::TikTok, 0)]);
+
+        let changes = FollowerSnapshot::changes(UserId::new(), Some(&previous), None, at(0));
+        assert_eq!(
+            counts(&changes),
+            vec![
+                (SocialMediaPlatform::Instagram, 0),
+                (SocialMediaPlatform::TikTok, 0),
+            ]
+        );
+    }
+
+    #[test]
+    fn totals_drop_a_removed_platform() {
+        let user_id = UserId::new();
+        let first = profiles(&[
+            (SocialMediaPlatform::Instagram, 100),
+            (SocialMediaPlatform::TikTok, 50),
+        ]);
+        let second = profiles(&[(SocialMediaPlatform::Instagram, 120)]);
+
+        let mut snapshots = FollowerSnapshot::changes(user_id, None, Some(&first), at(0));
+        snapshots.extend(FollowerSnapshot::changes(
+            user_id,
+            Some(&first),
+            Some(&second),
+            at(1),
+        ));
+
+        let totals: Vec<u64> = FollowerCountPoint::totals(&snapshots)
+            .iter()
+            .map(|point| point.follower_count)
+            .collect();
+        assert_eq!(totals, vec![150, 120]);
+    }
+}
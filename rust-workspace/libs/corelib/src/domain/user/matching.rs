@@ -0,0 +1,496 @@
+use std::collections::{BTreeMap, HashSet};
+
+use crate::domain::{
+    shared::{
+        error::{DomainError, FieldError},
+        value_object::{SocialMediaMetadata, SocialMediaPlatform},
+    },
+    taxonomy::value_objects::TaxonomyId,
+    term::value_objects::TermId,
+    user::{User, specs::InfluencerCandidateSpec},
+};
+
+/// Inclusive bounds on a follower count, either side may be left open
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct FollowerRange {
+    min: Option<u64>,
+    max: Option<u64>,
+}
+
+impl FollowerRange {
+    pub fn new(min: Option<u64>, max: Option<u64>) -> Result<Self, DomainError> {
+        if let (Some(min), Some(max)) = (min, max)
+            && min > max
+        {
+            return Err(DomainError::invalid_fields(vec![FieldError::new(
+                "target.followers",
+                format!("minimum {} is above the maximum {}", min, max),
+            )]));
+        }
+        Ok(Self { min, max })
+    }
+
+    pub fn min(&self) -> Option<u64> {
+        self.min
+    }
+
+    pub fn max(&self) -> Option<u64> {
+        self.max
+    }
+
+    pub fn contains(&self, count: u64) -> bool {
+        self.min.is_none_or(|min| count >= min) && self.max.is_none_or(|max| count <= max)
+    }
+
+    /// `1.0` inside the range, falling off with the distance to the nearest bound:
+    /// `count / min` below it and `max / count` above it
+    pub fn score(&self, count: u64) -> f64 {
+        match (self.min, self.max) {
+            (Some(min), _) if count < min => count as f64 / min as f64,
+            (_, Some(max)) if count > max => max as f64 / count as f64,
+            _ => 1.0,
+        }
+    }
+}
+
+/// Part of a target that users are scored on
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum MatchCriterion {
+    Demographics,
+    Platforms,
+    Followers,
+}
+
+/// How much each criterion counts towards the overall score, relative to the others
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CriterionWeights {
+    pub demographics: f64,
+    pub platforms: f64,
+    pub followers: f64,
+}
+
+impl Default for CriterionWeights {
+    fn default() -> Self {
+        Self {
+            demographics: 1.0,
+            platforms: 1.0,
+            followers: 1.0,
+        }
+    }
+}
+
+impl CriterionWeights {
+    fn get(&self, criterion: MatchCriterion) -> f64 {
+        match criterion {
+            MatchCriterion::Demographics => self.demographics,
+            MatchCriterion::Platforms => self.platforms,
+            MatchCriterion::Followers => self.followers,
+        }
+    }
+}
+
+/// Audience a brand wants to reach, scored against users by `MatchTarget::score`
+///
+/// # Fields
+/// - `demographics`: Wanted terms grouped by taxonomy, each with a weight.
+/// - `platforms`: Platforms the brand advertises on, any platform when empty.
+/// - `followers`: Wanted follower count across the profiles on `platforms`.
+/// - `weights`: Weight of each criterion in the overall score.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MatchTarget {
+    demographics: BTreeMap<TaxonomyId, BTreeMap<TermId, f64>>,
+    platforms: Vec<SocialMediaPlatform>,
+    followers: Option<FollowerRange>,
+    weights: CriterionWeights,
+}
+
+impl MatchTarget {
+    /// # Errors
+    /// - a term is listed twice, or a term or criterion weight is not a positive number
+    /// - the target sets none of demographics, platforms and followers
+    pub fn new(
+        demographics: impl IntoIterator<Item = (TaxonomyId, TermId, f64)>,
+        platforms: impl IntoIterator<Item = SocialMediaPlatform>,
+        followers: Option<FollowerRange>,
+        weights: CriterionWeights,
+    ) -> Result<Self, DomainError> {
+        let mut by_taxonomy: BTreeMap<TaxonomyId, BTreeMap<TermId, f64>> = BTreeMap::new();
+        let mut errors: Vec<FieldError> = Vec::new();
+
+        for (taxonomy_id, term_id, weight) in demographics {
+            let field = format!(
+                "target.demographics.{}.{}",
+                taxonomy_id.as_str(),
+                term_id.as_str()
+            );
+            if !is_positive(weight) {
+                errors.push(FieldError::new(field, "weight must be greater than 0"));
+                continue;
+            }
+            if by_taxonomy
+                .entry(taxonomy_id)
+                .or_default()
+                .insert(term_id, weight)
+                .is_some()
+            {
+                errors.push(FieldError::new(field, "term is listed more than once"));
+            }
+        }
+
+        for (criterion, weight) in [
+            ("demographics", weights.demographics),
+            ("platforms", weights.platforms),
+            ("followers", weights.followers),
+        ] {
+            if !is_positive(weight) {
+                errors.push(FieldError::new(
+                    format!("target.weights.{}", criterion),
+                    "weight must be greater than 0",
+                ));
+            }
+        }
+
+        let mut unique_platforms: Vec<SocialMediaPlatform> = Vec::new();
+        for platform in platforms {
+            if !unique_platforms.contains(&platform) {
+                unique_platforms.push(platform);
+            }
+        }
+
+        if by_taxonomy.is_empty() && unique_platforms.is_empty() && followers.is_none() {
+            errors.push(FieldError::new(
+                "target",
+                "set at least one of demographics, platforms and followers",
+            ));
+        }
+
+        if !errors.is_empty() {
+            return Err(DomainError::invalid_fields(errors));
+        }
+
+        Ok(Self {
+            demographics: by_taxonomy,
+            platforms: unique_platforms,
+            followers,
+            weights,
+        })
+    }
+
+    pub fn platforms(&self) -> &[SocialMediaPlatform] {
+        &self.platforms
+    }
+
+    /// Users worth scoring against this target
+    pub fn candidates(&self) -> InfluencerCandidateSpec {
+        InfluencerCandidateSpec {
+            platforms: self.platforms.clone(),
+        }
+    }
+
+    /// Scores `user` from `0.0` to `1.0`
+    ///
+    /// Only the criteria the target sets are scored. The overall score is their
+    /// weighted mean, so each breakdown entry's `contribution` adds up to it.
+    pub fn score(&self, user: &User) -> MatchScore {
+        let mut scored: Vec<(MatchCriterion, f64)> = Vec::new();
+        if !self.demographics.is_empty() {
+            scored.push((MatchCriterion::Demographics, self.demographics_score(user)));
+        }
+        if !self.platforms.is_empty() {
+            scored.push((MatchCriterion::Platforms, self.platforms_score(user)));
+        }
+        if let Some(range) = &self.followers {
+            let followers: u64 = self.profiles(user).map(|p| p.follower_count()).sum();
+            scored.push((MatchCriterion::Followers, range.score(followers)));
+        }
+
+        let total_weight: f64 = scored
+            .iter()
+            .map(|(criterion, _)| self.weights.get(*criterion))
+            .sum();
+        let breakdown: Vec<CriterionScore> = scored
+            .into_iter()
+            .map(|(criterion, score)| {
+                let weight = self.weights.get(criterion) / total_weight;
+                CriterionScore {
+                    criterion,
+                    score,
+                    weight,
+                    contribution: score * weight,
+                }
+            })
+            .collect();
+
+        MatchScore {
+            score: breakdown.iter().map(|c| c.contribution).sum(),
+            breakdown,
+        }
+    }
+
+    /// Weighted share of the target's terms the user reaches
+    ///
+    /// A profile reaches a term by its audience share when its audience covers
+    /// the term's taxonomy, otherwise fully when the term is picked in the
+    /// profile's or the user's demographics. The best profile counts.
+    fn demographics_score(&self, user: &User) -> f64 {
+        let mut reached = 0.0;
+        let mut total = 0.0;
+        for (taxonomy_id, terms) in &self.demographics {
+            for (term_id, weight) in terms {
+                let from_profiles = self
+                    .profiles(user)
+                    .map(|profile| profile_reach(profile, taxonomy_id, term_id))
+                    .fold(0.0, f64::max);
+                let from_user = user
+                    .demographics
+                    .as_ref()
+                    .is_some_and(|d| d.contains(taxonomy_id, term_id));
+                let reach = if from_user { 1.0 } else { from_profiles };
+
+                reached += weight * reach;
+                total += weight;
+            }
+        }
+        reached / total
+    }
+
+    /// Share of the target's platforms the user has a profile on
+    fn platforms_score(&self, user: &User) -> f64 {
+        let on: HashSet<SocialMediaPlatform> = self
+            .profiles(user)
+            .map(|profile| profile.platform())
+            .collect();
+        on.len() as f64 / self.platforms.len() as f64
+    }
+
+    fn profiles<'u>(&'u self, user: &'u User) -> impl Iterator<Item = &'u SocialMediaMetadata> {
+        user.social_profiles
+            .iter()
+            .flat_map(|profiles| profiles.all())
+            .filter(|profile| {
+                self.platforms.is_empty() || self.platforms.contains(&profile.platform())
+            })
+    }
+}
+
+fn profile_reach(profile: &SocialMediaMetadata, taxonomy_id: &TaxonomyId, term_id: &TermId) -> f64 {
+    if let Some(audience) = profile.audience()
+        && audience.iter().any(|(id, _)| id == taxonomy_id)
+    {
+        return audience
+            .share(taxonomy_id, term_id)
+            .map(|share| share.as_ratio())
+            .unwrap_or_default();
+    }
+
+    if profile
+        .demographics()
+        .is_some_and(|d| d.contains(taxonomy_id, term_id))
+    {
+        1.0
+    } else {
+        0.0
+    }
+}
+
+fn is_positive(weight: f64) -> bool {
+    weight.is_finite() && weight > 0.0
+}
+
+/// How one criterion scored
+///
+/// # Fields
+/// - `score`: From `0.0` to `1.0`.
+/// - `weight`: Share of the overall score given to this criterion, the weights add up to `1.0`.
+/// - `contribution`: `score * weight`, what this criterion adds to the overall score.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CriterionScore {
+    pub criterion: MatchCriterion,
+    pub score: f64,
+    pub weight: f64,
+    pub contribution: f64,
+}
+
+/// Overall score of a user against a target, with how each criterion scored
+#[derive(Debug, Clone, PartialEq)]
+pub struct MatchScore {
+    pub score: f64,
+    pub breakdown: Vec<CriterionScore>,
+}
+
+/// A user ranked against a target
+#[derive(Debug, Clone)]
+pub struct InfluencerMatch {
+    pub user: User,
+    pub score: MatchScore,
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+
+    use super::*;
+    use crate::domain::{
+        shared::{
+            audience::{AudienceDemographics, AudienceSource, Percentage},
+            term_selection::TermSelection,
+            value_object::SocialMediaProfiles,
+        },
+        user::value_object::{FirstName, IdentityId, LastName, UserId},
+    };
+
+    fn profile(platform: SocialMediaPlatform, followers: u64) -> SocialMediaMetadata {
+        SocialMediaMetadata::new(platform, "name", "https://example.com", followers, None).unwrap()
+    }
+
+    fn user(profiles: Vec<SocialMediaMetadata>, demographics: Option<TermSelection>) -> User {
+        User::new(
+            UserId::new(),
+            IdentityId::new("identity").unwrap(),
+            FirstName::new("Ada").unwrap(),
+            LastName::new("Lovelace").unwrap(),
+            TermId::new(),
+            Some(SocialMediaProfiles::new(profiles)),
+            demographics,
+        )
+    }
+
+    fn audience(shares: &[(TaxonomyId, TermId, f64)]) -> AudienceDemographics {
+        let day = NaiveDate::from_ymd_opt(2026, 1, 1).unwrap();
+        AudienceDemographics::new(
+            "audience",
+            shares
+                .iter()
+                .map(|&(taxonomy, term, share)| (taxonomy, term, Percentage::new(share).unwrap())),
+            AudienceSource::SelfReported,
+            day,
+            day,
+        )
+        .unwrap()
+    }
+
+    fn close(actual: f64, expected: f64) {
+        assert!(
+            (actual - expected).abs() < 1e-9,
+            "expected {expected}, got {actual}"
+        );
+    }
+
+    #[test]
+    fn follower_range_score_falls_off_outside_the_range() {
+        let range = FollowerRange::new(Some(1_000), Some(10_000)).unwrap();
+        close(range.score(500), 0.5);
+        close(range.score(1_000), 1.0);
+        close(range.score(10_000), 1.0);
+        close(range.score(40_000), 0.25);
+        close(range.score(0), 0.0);
+
+        close(FollowerRange::default().score(0), 1.0);
+        assert!(FollowerRange::new(Some(2), Some(1)).is_err());
+    }
+
+    #[test]
+    fn criterion_weights_are_normalised_over_the_scored_criteria() {
+        let target = MatchTarget::new(
+            [],
+            [SocialMediaPlatform::Instagram, SocialMediaPlatform::TikTok],
+            Some(FollowerRange::new(Some(1_000), None).unwrap()),
+            CriterionWeights {
+                // not scored, the target sets no demographics
+                demographics: 5.0,
+                platforms: 1.0,
+                followers: 3.0,
+            },
+        )
+        .unwrap();
+
+        let score = target.score(&user(
+            vec![profile(SocialMediaPlatform::Instagram, 500)],
+            None,
+        ));
+
+        let weights: Vec<(MatchCriterion, f64)> = score
+            .breakdown
+            .iter()
+            .map(|c| (c.criterion, c.weight))
+            .collect();
+        assert_eq!(
+            weights,
+            vec![
+                (MatchCriterion::Platforms, 0.25),
+                (MatchCriterion::Followers, 0.75)
+            ]
+        );
+        // platforms 0.5 * 0.25 + followers 0.5 * 0.75
+        close(score.score, 0.5);
+        close(
+            score.breakdown.iter().map(|c| c.contribution).sum(),
+            score.score,
+        );
+    }
+
+    #[test]
+    fn platforms_count_each_platform_once() {
+        let target = MatchTarget::new(
+            [],
+            [SocialMediaPlatform::Instagram, SocialMediaPlatform::TikTok],
+            None,
+            CriterionWeights::default(),
+        )
+        .unwrap();
+
+        let doubled = user(
+            vec![
+                profile(SocialMediaPlatform::Instagram, 10),
+                profile(SocialMediaPlatform::Instagram, 20),
+            ],
+            None,
+        );
+        close(target.score(&doubled).score, 0.5);
+    }
+
+    #[test]
+    fn audience_share_outranks_a_picked_term() {
+        let (ages, young, old) = (TaxonomyId::new(), TermId::new(), TermId::new());
+        let target = MatchTarget::new(
+            [(ages, young, 3.0), (ages, old, 1.0)],
+            [],
+            None,
+            CriterionWeights::default(),
+        )
+        .unwrap();
+
+        // the audience covers the taxonomy, so its shares count and not the picked terms
+        let picked = TermSelection::new("demographics", [(ages, [young, old])]).unwrap();
+        let measured = SocialMediaMetadata::new(
+            SocialMediaPlatform::Instagram,
+            "name",
+            "https://example.com",
+            10,
+            Some(picked.clone()),
+        )
+        .unwrap()
+        .with_audience(Some(audience(&[(ages, young, 40.0)])));
+        // young reached at 40% with weight 3, old not reached
+        close(target.score(&user(vec![measured], None)).score, 0.3);
+
+        // without an audience the picked terms reach fully
+        let picked_only = SocialMediaMetadata::new(
+            SocialMediaPlatform::TikTok,
+            "name",
+            "https://example.com",
+            10,
+            Some(TermSelection::new("demographics", [(ages, [old])]).unwrap()),
+        )
+        .unwrap();
+        close(target.score(&user(vec![picked_only], None)).score, 0.25);
+
+        // the user's own demographics reach fully whatever the audience says
+        let audience_only = profile(SocialMediaPlatform::Instagram, 10)
+            .with_audience(Some(audience(&[(ages, young, 40.0)])));
+        close(
+            target.score(&user(vec![audience_only], Some(picked))).score,
+            1.0,
+        );
+    }
+}
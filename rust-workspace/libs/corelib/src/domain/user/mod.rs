@@ -1,16 +1,25 @@
+pub mod actor;
 pub mod events;
+pub mod follower_history;
+pub mod matching;
 pub mod ports;
+pub mod specs;
 pub mod value_object;
 
 use crate::domain::{
     shared::{
+        error::DomainError,
         event::EventMeta,
-        value_object::{Deleted, Demographics, EventId, OcurredAt, SocialMediaProfiles, Timestamp},
+        term_selection::TermSelection,
+        value_object::{
+            Deleted, EventId, OcurredAt, SocialMediaMetadata, SocialMediaPlatform,
+            SocialMediaProfiles, Timestamp,
+        },
     },
     term::value_objects::TermId,
     user::{
         events::UserDomainEvent,
-        value_object::{FirstName, LastName, UserId},
+        value_object::{FirstName, IdentityId, LastName, UserId},
     },
 };
 
@@ -18,6 +27,7 @@ use crate::domain::{
 ///
 /// # Fields
 /// - `id`: Unique identifier for the user.
+/// - `identity_id`: The `Identity` owning the user, at most one user per identity.
 /// - `first_name`: First name of user.
 /// - `last_name`: Last name of user.
 /// - `country_id`: The reference identifier for a `Term`.
@@ -29,13 +39,14 @@ use crate::domain::{
 #[derive(Debug, Clone)]
 pub struct User {
     pub id: UserId,
+    pub identity_id: IdentityId,
 
     pub first_name: FirstName,
     pub last_name: LastName,
     pub country_term_id: TermId,
 
     pub social_profiles: Option<SocialMediaProfiles>,
-    pub demographics: Option<Demographics>,
+    pub demographics: Option<TermSelection>,
 
     pub timestamps: Timestamp,
     pub deleted: Deleted,
@@ -48,17 +59,19 @@ pub struct User {
 impl User {
     pub fn new(
         id: UserId,
+        identity_id: IdentityId,
         first_name: FirstName,
         last_name: LastName,
         country_term_id: TermId,
         social_profiles: Option<SocialMediaProfiles>,
-        demographics: Option<Demographics>,
+        demographics: Option<TermSelection>,
     ) -> Self {
         let mut user = User {
             id,
+            identity_id: identity_id.clone(),
             first_name: first_name.clone(),
             last_name: last_name.clone(),
-            country_term_id: country_term_id.clone(),
+            country_term_id,
             social_profiles,
             demographics,
             version: 0,
@@ -75,6 +88,7 @@ impl User {
                 aggregate_version: 1,
             },
             event_name: "user.created".to_owned(),
+            identity_id: identity_id.as_str().to_owned(),
             first_name: first_name.as_str().to_owned(),
             last_name: last_name.as_str().to_owned(),
             country_term_id: country_term_id.as_str().to_owned(),
@@ -107,7 +121,7 @@ impl User {
     }
 
     pub fn set_country(&mut self, country_id: TermId) {
-        self.country_term_id = country_id.clone();
+        self.country_term_id = country_id;
         self.touch();
         self.pending_events.push(UserDomainEvent::UserBioUpdated {
             meta: self.next_meta(),
@@ -117,25 +131,152 @@ impl User {
             country_term_id: Some(country_id.as_str().to_owned()),
         });
     }
-    pub fn set_social_profiles(&mut self, social_profiles: SocialMediaProfiles) {
-        self.social_profiles = Some(social_profiles.clone());
+    /// Replaces every profile; profiles whose name and link are unchanged stay verified
+    pub fn set_social_profiles(&mut self, mut social_profiles: Option<SocialMediaProfiles>) {
+        if let (Some(latest), Some(previous)) = (&mut social_profiles, &self.social_profiles) {
+            latest.keep_verification_from(previous);
+        }
+        self.social_profiles = social_profiles.clone();
         self.touch();
         self.pending_events
             .push(UserDomainEvent::UserSocialProfileUpdated {
                 meta: self.next_meta(),
                 event_name: "user.social_media_profile.updated".to_owned(),
-                latest: social_profiles.to_owned(),
+                latest: social_profiles,
+            });
+    }
+
+    /// Adds the profile of a platform, replacing any existing one
+    pub fn upsert_social_profile(
+        &mut self,
+        profile: SocialMediaMetadata,
+    ) -> Result<(), DomainError> {
+        self.social_profiles
+            .get_or_insert_with(|| SocialMediaProfiles::new(Vec::new()))
+            .upsert(profile.clone())?;
+        self.touch();
+        self.pending_events
+            .push(UserDomainEvent::UserSocialProfileUpserted {
+                meta: self.next_meta(),
+                event_name: "user.social_media_profile.upserted".to_owned(),
+                profile,
+            });
+        Ok(())
+    }
+
+    /// Removes the profile of a platform, `not_found` if the user has none
+    pub fn remove_social_profile(
+        &mut self,
+        platform: SocialMediaPlatform,
+    ) -> Result<(), DomainError> {
+        let profiles = self.profiles_mut(platform)?;
+        profiles.remove(platform)?;
+        if profiles.is_empty() {
+            self.social_profiles = None;
+        }
+        self.touch();
+        self.pending_events
+            .push(UserDomainEvent::UserSocialProfileRemoved {
+                meta: self.next_meta(),
+                event_name: "user.social_media_profile.removed".to_owned(),
+                platform,
+            });
+        Ok(())
+    }
+
+    /// Records the follower count of a platform profile observed by metrics ingestion
+    pub fn record_follower_count(
+        &mut self,
+        platform: SocialMediaPlatform,
+        follower_count: u64,
+    ) -> Result<(), DomainError> {
+        self.profiles_mut(platform)?
+            .set_follower_count(platform, follower_count)?;
+        self.touch();
+        self.pending_events
+            .push(UserDomainEvent::UserSocialProfileFollowersUpdated {
+                meta: self.next_meta(),
+                event_name: "user.social_media_profile.followers_updated".to_owned(),
+                platform,
+                follower_count,
             });
+        Ok(())
+    }
+
+    /// Asks reviewers to verify the profile of a platform
+    ///
+    /// # Errors
+    /// - `not_found` if the user has no profile for `platform`
+    /// - `conflict` if the profile is already pending or verified
+    pub fn request_profile_verification(
+        &mut self,
+        platform: SocialMediaPlatform,
+    ) -> Result<(), DomainError> {
+        self.profiles_mut(platform)?
+            .request_verification(platform)?;
+        self.touch();
+        self.pending_events
+            .push(UserDomainEvent::UserSocialProfileVerificationRequested {
+                meta: self.next_meta(),
+                event_name: "user.social_media_profile.verification_requested".to_owned(),
+                platform,
+            });
+        Ok(())
+    }
+
+    /// Approves the pending verification of a platform profile
+    pub fn approve_profile_verification(
+        &mut self,
+        platform: SocialMediaPlatform,
+    ) -> Result<(), DomainError> {
+        self.profiles_mut(platform)?
+            .approve_verification(platform)?;
+        self.touch();
+        self.pending_events
+            .push(UserDomainEvent::UserSocialProfileVerificationApproved {
+                meta: self.next_meta(),
+                event_name: "user.social_media_profile.verification_approved".to_owned(),
+                platform,
+            });
+        Ok(())
+    }
+
+    /// Rejects the pending verification of a platform profile
+    pub fn reject_profile_verification(
+        &mut self,
+        platform: SocialMediaPlatform,
+        reason: String,
+    ) -> Result<(), DomainError> {
+        self.profiles_mut(platform)?
+            .reject_verification(platform, reason.clone())?;
+        self.touch();
+        self.pending_events
+            .push(UserDomainEvent::UserSocialProfileVerificationRejected {
+                meta: self.next_meta(),
+                event_name: "user.social_media_profile.verification_rejected".to_owned(),
+                platform,
+                reason,
+            });
+        Ok(())
+    }
+
+    fn profiles_mut(
+        &mut self,
+        platform: SocialMediaPlatform,
+    ) -> Result<&mut SocialMediaProfiles, DomainError> {
+        self.social_profiles
+            .as_mut()
+            .ok_or_else(|| DomainError::not_found(format!("{platform:?} profile")))
     }
 
-    pub fn set_demographics(&mut self, demographics: Demographics) {
-        self.demographics = Some(demographics.clone());
+    pub fn set_demographics(&mut self, demographics: Option<TermSelection>) {
+        self.demographics = demographics.clone();
         self.touch();
         self.pending_events
             .push(UserDomainEvent::UserDemographicsUpdated {
                 meta: self.next_meta(),
                 event_name: "user.demographics.updated".to_owned(),
-                latest: demographics.to_owned(),
+                latest: demographics,
             });
     }
     pub fn mark_as_deleted(&mut self) {
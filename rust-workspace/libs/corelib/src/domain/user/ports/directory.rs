@@ -0,0 +1,15 @@
+use async_trait::async_trait;
+
+use crate::domain::{
+    shared::{error::DomainError, health::HealthCheck},
+    user::value_object::{IdentityId, UserId},
+};
+
+/// Finds the user behind an identity, for subgraphs acting on behalf of their caller
+#[async_trait]
+pub trait UserDirectory: HealthCheck + Send + Sync {
+    /// User of `identity_id`, `None` when the identity has not created one
+    ///
+    /// `Err` is kept for a user store that cannot be reached.
+    async fn user_of(&self, identity_id: &IdentityId) -> Result<Option<UserId>, DomainError>;
+}
@@ -1,7 +1,10 @@
-use crate::domain::user::events::UserDomainEvent;
+use crate::domain::{user::events::UserDomainEvent, shared::health::HealthCheck};
 
-pub trait UserDomainEventBus: Send + Sync {
+pub trait UserDomainEventBus: HealthCheck + Send + Sync {
     fn publish(&self, events: &[UserDomainEvent]) -> Result<(), String>;
+
+    /// Delivers every event still queued in the bus, called once on shutdown
+    fn flush(&self) -> Result<(), String>;
 }
 
 pub trait UserDomainEventHandler: Send + Sync {
@@ -0,0 +1,27 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+use crate::domain::{
+    shared::{error::DomainError, health::HealthCheck, value_object::SocialMediaPlatform},
+    user::{follower_history::FollowerSnapshot, value_object::UserId},
+};
+
+/// Append-only store of follower count snapshots
+#[async_trait]
+pub trait FollowerHistoryRepository: HealthCheck + Send + Sync {
+    async fn append(&self, snapshots: &[FollowerSnapshot]) -> Result<(), DomainError>;
+
+    /// Snapshots of `user_id` recorded up to `until`, oldest first
+    ///
+    /// `platform` narrows the history to one profile; `since` drops older snapshots.
+    async fn history(
+        &self,
+        user_id: &UserId,
+        platform: Option<SocialMediaPlatform>,
+        since: Option<DateTime<Utc>>,
+        until: DateTime<Utc>,
+    ) -> Result<Vec<FollowerSnapshot>, DomainError>;
+
+    /// Releases connections held by the repository, called once on shutdown
+    async fn close(&self) -> Result<(), DomainError>;
+}
@@ -1,2 +1,4 @@
 pub mod respository;
-pub mod event;
\ No newline at end of file
+pub mod event;
+pub mod follower_history;
+pub mod directory;
\ No newline at end of file
@@ -1,15 +1,28 @@
 use crate::domain::{
-    shared::{error::DomainError, specs::Specification},
-    user::{User, value_object::UserId},
+    shared::{error::DomainError, health::HealthCheck, specs::Specification},
+    user::{
+        User,
+        value_object::{IdentityId, UserId},
+    },
 };
 use async_trait::async_trait;
 
 #[async_trait]
-pub trait UserRepository: Send + Sync {
-    async fn save(&mut self, user: &User) -> Result<(), DomainError>;
+pub trait UserRepository: HealthCheck + Send + Sync {
+    /// Fails with a conflict when another user already belongs to `user.identity_id`
+    async fn save(&self, user: &User) -> Result<(), DomainError>;
 
     async fn find_by_id(&self, id: &UserId) -> Result<Option<User>, DomainError>;
 
+    /// Fetches every user in `ids` in one call; unknown ids are skipped and order is not kept
+    async fn find_by_ids(&self, ids: &[UserId]) -> Result<Vec<User>, DomainError>;
+
+    /// Fetches the users owned by `identity_ids`; unknown ids are skipped and order is not kept
+    async fn find_by_identity_ids(
+        &self,
+        identity_ids: &[IdentityId],
+    ) -> Result<Vec<User>, DomainError>;
+
     async fn query(
         &self,
         spec: &(dyn Specification<User> + Send + Sync),
@@ -17,5 +30,8 @@ pub trait UserRepository: Send + Sync {
         offset: usize,
     ) -> Result<Vec<User>, DomainError>;
 
-    async fn delete(&mut self, id: &UserId) -> Result<(), DomainError> ;
+    async fn delete(&self, id: &UserId) -> Result<(), DomainError> ;
+
+    /// Releases connections held by the repository, called once on shutdown
+    async fn close(&self) -> Result<(), DomainError>;
 }
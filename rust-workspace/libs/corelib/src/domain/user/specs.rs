@@ -0,0 +1,33 @@
+use crate::domain::{
+    shared::{specs::Specification, value_object::SocialMediaPlatform},
+    user::User,
+};
+
+/// Users with at least one social profile waiting for a reviewer
+pub struct PendingProfileVerificationSpec;
+
+impl Specification<User> for PendingProfileVerificationSpec {
+    fn is_satisfied_by(&self, candidate: &User) -> bool {
+        !candidate.deleted.is_deleted()
+            && candidate
+                .social_profiles
+                .as_ref()
+                .is_some_and(|profiles| !profiles.pending().is_empty())
+    }
+}
+
+/// Users with a profile on one of `platforms`, or on any platform when empty
+pub struct InfluencerCandidateSpec {
+    pub platforms: Vec<SocialMediaPlatform>,
+}
+
+impl Specification<User> for InfluencerCandidateSpec {
+    fn is_satisfied_by(&self, candidate: &User) -> bool {
+        !candidate.deleted.is_deleted()
+            && candidate.social_profiles.as_ref().is_some_and(|profiles| {
+                profiles.all().iter().any(|profile| {
+                    self.platforms.is_empty() || self.platforms.contains(&profile.platform())
+                })
+            })
+    }
+}
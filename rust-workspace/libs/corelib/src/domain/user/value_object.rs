@@ -1,3 +1,4 @@
+use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
 use crate::domain::shared::error::DomainError;
@@ -6,7 +7,7 @@ use crate::domain::shared::error::DomainError;
 /// # Field
 /// - `value`- raw uuid v4 value.
 
-#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, PartialOrd, Ord)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, PartialOrd, Ord, Deserialize, Serialize)]
 pub struct UserId(Uuid);
 
 impl UserId {
@@ -28,6 +29,47 @@ impl UserId {
     }
 }
 
+impl Default for UserId {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Id of the `Identity` (owned by the identity service) a user belongs to
+///
+/// Opaque to this service; only trimmed and bounded.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IdentityId(String);
+
+impl IdentityId {
+    pub fn new(value: impl Into<String>) -> Result<Self, DomainError> {
+        let value = value.into();
+        let trimmed = value.trim();
+
+        if trimmed.is_empty() {
+            return Err(DomainError::validation("Identity id cannot be empty"));
+        }
+
+        if trimmed.chars().count() > 128 {
+            return Err(DomainError::validation(
+                "Identity id is too long (max 128 chars)",
+            ));
+        }
+
+        if trimmed.chars().any(char::is_whitespace) {
+            return Err(DomainError::validation(
+                "Identity id cannot contain whitespace",
+            ));
+        }
+
+        Ok(Self(trimmed.to_string()))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
 /// First name of a user
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct FirstName(String);
@@ -0,0 +1,286 @@
+use std::{
+    net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr},
+    sync::Arc,
+    time::Duration,
+};
+
+use reqwest::{
+    Url,
+    dns::{Addrs, Name, Resolve, Resolving},
+    redirect::Policy,
+};
+use serde_json::json;
+
+use crate::domain::{
+    business::{
+        ports::claim::{ClaimCodeMailer, WebsiteFetcher},
+        value_objects::BusinessName,
+    },
+    shared::{
+        error::DomainError,
+        health::{ComponentHealth, HealthCheck},
+        value_object::{EmailAddress, WebsiteUrl},
+    },
+};
+
+/// Pages larger than this are cut off, a claim code belongs near the top anyway
+const MAX_PAGE_BYTES: usize = 1024 * 1024;
+
+/// Redirects followed within the website's own host, e.g. from `/` to `/en/`
+const MAX_REDIRECTS: usize = 5;
+
+/// Fetches business websites over HTTP
+///
+/// The URL comes from whoever edited the business, so the fetcher only talks
+/// to public addresses: host names are resolved here and every private,
+/// loopback or link-local address is dropped, and redirects may not leave the
+/// host. Errors never name the URL or the address.
+pub struct HttpWebsiteFetcher {
+    client: reqwest::Client,
+}
+
+impl HttpWebsiteFetcher {
+    pub fn new(timeout: Duration) -> Result<Self, DomainError> {
+        let client = reqwest::Client::builder()
+            .timeout(timeout)
+            .no_proxy()
+            .dns_resolver(Arc::new(PublicAddressResolver))
+            .redirect(Policy::custom(|attempt| {
+                let same_host = attempt
+                    .previous()
+                    .first()
+                    .is_some_and(|first| first.host_str() == attempt.url().host_str());
+                if !same_host {
+                    attempt.error("redirect leaves the website's host")
+                } else if attempt.previous().len() > MAX_REDIRECTS {
+                    attempt.error("too many redirects")
+                } else {
+                    attempt.follow()
+                }
+            }))
+            .build()
+            .map_err(|e| DomainError::unavailable_with("failed to build the website client", e))?;
+
+        Ok(Self { client })
+    }
+}
+
+#[async_trait::async_trait]
+impl HealthCheck for HttpWebsiteFetcher {
+    async fn check(&self) -> ComponentHealth {
+        // Websites are third-party, there is nothing of ours to ping
+        ComponentHealth::up("website_fetcher", "over HTTP")
+    }
+}
+
+#[async_trait::async_trait]
+impl WebsiteFetcher for HttpWebsiteFetcher {
+    async fn fetch(&self, url: &WebsiteUrl) -> Result<String, DomainError> {
+        let unreachable = |e: reqwest::Error| {
+            DomainError::unavailable_with("business website unreachable", e.without_url())
+        };
+
+        // IP literals never reach the resolver, so they are checked here
+        let url = Url::parse(url.as_str())
+            .map_err(|_| DomainError::validation("business website is not a valid URL"))?;
+        let literal = url
+            .host_str()
+            .and_then(|host| host.trim_matches(['[', ']']).parse::<IpAddr>().ok());
+        if literal.is_some_and(|ip| !is_public(ip)) {
+            return Err(DomainError::unavailable("business website unreachable"));
+        }
+
+        let mut response = self
+            .client
+            .get(url)
+            .send()
+            .await
+            .and_then(|response| response.error_for_status())
+            .map_err(unreachable)?;
+
+        let mut body: Vec<u8> = Vec::new();
+        while let Some(chunk) = response.chunk().await.map_err(unreachable)? {
+            body.extend_from_slice(&chunk);
+            if body.len() >= MAX_PAGE_BYTES {
+                body.truncate(MAX_PAGE_BYTES);
+                break;
+            }
+        }
+
+        Ok(String::from_utf8_lossy(&body).into_owned())
+    }
+}
+
+/// Resolves host names to their public addresses only, failing when none is left
+struct PublicAddressResolver;
+
+impl Resolve for PublicAddressResolver {
+    fn resolve(&self, name: Name) -> Resolving {
+        Box::pin(async move {
+            let resolved = tokio::net::lookup_host((name.as_str(), 0)).await?;
+            let public: Vec<SocketAddr> = resolved.filter(|addr| is_public(addr.ip())).collect();
+            if public.is_empty() {
+                return Err("host has no public address".into());
+            }
+            Ok(Box::new(public.into_iter()) as Addrs)
+        })
+    }
+}
+
+/// Whether `ip` is routable on the public internet
+fn is_public(ip: IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(ip) => is_public_v4(ip),
+        IpAddr::V6(ip) => match ip.to_ipv4_mapped() {
+            Some(mapped) => is_public_v4(mapped),
+            None => is_public_v6(ip),
+        },
+    }
+}
+
+fn is_public_v4(ip: Ipv4Addr) -> bool {
+    let [a, b, ..] = ip.octets();
+    !(ip.is_unspecified()
+        || ip.is_loopback()
+        || ip.is_private()
+        || ip.is_link_local()
+        || ip.is_broadcast()
+        || ip.is_documentation()
+        || ip.is_multicast()
+        // 0.0.0.0/8 "this network", 100.64.0.0/10 carrier-grade NAT, 240.0.0.0/4 reserved
+        || a == 0
+        || (a == 100 && (64..128).contains(&b))
+        || a >= 240)
+}
+
+fn is_public_v6(ip: Ipv6Addr) -> bool {
+    let first = ip.segments()[0];
+    !(ip.is_unspecified()
+        || ip.is_loopback()
+        || ip.is_multicast()
+        // fc00::/7 unique local, fe80::/10 link-local
+        || (first & 0xfe00) == 0xfc00
+        || (first & 0xffc0) == 0xfe80)
+}
+
+/// Mails claim codes through an HTTP mail API
+///
+/// Posts `{ from, to, subject, text }` as JSON with the API key as a bearer
+/// token, the shape most transactional mail services accept.
+pub struct HttpClaimCodeMailer {
+    client: reqwest::Client,
+    api_url: String,
+    api_key: String,
+    from: String,
+}
+
+impl HttpClaimCodeMailer {
+    pub fn new(
+        api_url: impl Into<String>,
+        api_key: impl Into<String>,
+        from: impl Into<String>,
+        timeout: Duration,
+    ) -> Result<Self, DomainError> {
+        let client = reqwest::Client::builder()
+            .timeout(timeout)
+            .build()
+            .map_err(|e| DomainError::unavailable_with("failed to build the mail client", e))?;
+
+        Ok(Self {
+            client,
+            api_url: api_url.into(),
+            api_key: api_key.into(),
+            from: from.into(),
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl HealthCheck for HttpClaimCodeMailer {
+    async fn check(&self) -> ComponentHealth {
+        // Sending a mail is the only way to exercise the API, which a probe must not do
+        ComponentHealth::up("claim_mailer", format!("mail API at {}", self.api_url))
+    }
+}
+
+#[async_trait::async_trait]
+impl ClaimCodeMailer for HttpClaimCodeMailer {
+    async fn send_claim_code(
+        &self,
+        to: &EmailAddress,
+        business: &BusinessName,
+        code: &str,
+    ) -> Result<(), DomainError> {
+        let body = json!({
+            "from": self.from,
+            "to": to.as_str(),
+            "subject": format!("Your claim code for {}", business.as_str()),
+            "text": format!(
+                "Enter {} to prove you run {}. If you did not claim it, ignore this mail.",
+                code,
+                business.as_str()
+            ),
+        });
+
+        self.client
+            .post(&self.api_url)
+            .bearer_auth(&self.api_key)
+            .json(&body)
+            .send()
+            .await
+            .and_then(|response| response.error_for_status())
+            .map_err(|e| DomainError::unavailable_with("failed to mail the claim code", e))?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn public(ip: &str) -> bool {
+        is_public(ip.parse().unwrap())
+    }
+
+    #[test]
+    fn private_and_loopback_addresses_are_not_public() {
+        for ip in [
+            "127.0.0.1",
+            "10.1.2.3",
+            "172.16.0.1",
+            "192.168.1.1",
+            "169.254.169.254",
+            "100.64.0.1",
+            "0.0.0.0",
+            "::1",
+            "fd00::1",
+            "fe80::1",
+            "::ffff:127.0.0.1",
+        ] {
+            assert!(!public(ip), "{} passed as public", ip);
+        }
+    }
+
+    #[test]
+    fn internet_addresses_are_public() {
+        for ip in ["93.184.216.34", "8.8.8.8", "2606:2800:220:1::248"] {
+            assert!(public(ip), "{} rejected", ip);
+        }
+    }
+
+    #[tokio::test]
+    async fn loopback_websites_are_refused_without_naming_them() {
+        let fetcher = HttpWebsiteFetcher::new(Duration::from_secs(1)).unwrap();
+
+        for url in ["http://127.0.0.1:9/", "http://localhost:9/"] {
+            let err = fetcher
+                .fetch(&WebsiteUrl::new(url).unwrap())
+                .await
+                .unwrap_err();
+
+            assert!(matches!(err, DomainError::Unavailable { .. }));
+            assert!(!err.to_string().contains("127.0.0.1"));
+            assert!(!err.to_string().contains("localhost"));
+        }
+    }
+}
@@ -0,0 +1,123 @@
+use std::sync::{Arc, Mutex};
+
+use dashmap::DashMap;
+
+use crate::domain::{
+    business::{
+        ports::claim::{ClaimCodeMailer, WebsiteFetcher},
+        value_objects::BusinessName,
+    },
+    shared::{
+        error::DomainError,
+        health::{ComponentHealth, HealthCheck},
+        value_object::{EmailAddress, WebsiteUrl},
+    },
+};
+
+/// A code `InMemoryClaimCodeMailer` would have mailed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SentClaimCode {
+    pub to: EmailAddress,
+    pub business: BusinessName,
+    pub code: String,
+}
+
+/// Keeps claim codes in an outbox instead of mailing them, for tests
+///
+/// Clones share the outbox, so a test keeps one to read the codes a service sent.
+#[derive(Clone, Default)]
+pub struct InMemoryClaimCodeMailer {
+    outbox: Arc<Mutex<Vec<SentClaimCode>>>,
+}
+
+impl InMemoryClaimCodeMailer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Every code sent so far, oldest first
+    pub fn sent(&self) -> Vec<SentClaimCode> {
+        self.outbox.lock().expect("claim outbox poisoned").clone()
+    }
+
+    /// Latest code sent to `to`
+    pub fn last_code_for(&self, to: &EmailAddress) -> Option<String> {
+        self.outbox
+            .lock()
+            .expect("claim outbox poisoned")
+            .iter()
+            .rev()
+            .find(|sent| &sent.to == to)
+            .map(|sent| sent.code.clone())
+    }
+}
+
+#[async_trait::async_trait]
+impl HealthCheck for InMemoryClaimCodeMailer {
+    async fn check(&self) -> ComponentHealth {
+        let sent = self.outbox.lock().expect("claim outbox poisoned").len();
+        ComponentHealth::up(
+            "claim_mailer",
+            format!("in-memory, {} codes in the outbox", sent),
+        )
+    }
+}
+
+#[async_trait::async_trait]
+impl ClaimCodeMailer for InMemoryClaimCodeMailer {
+    async fn send_claim_code(
+        &self,
+        to: &EmailAddress,
+        business: &BusinessName,
+        code: &str,
+    ) -> Result<(), DomainError> {
+        self.outbox
+            .lock()
+            .expect("claim outbox poisoned")
+            .push(SentClaimCode {
+                to: to.clone(),
+                business: business.clone(),
+                code: code.to_owned(),
+            });
+        Ok(())
+    }
+}
+
+/// Serves website pages from memory, for tests
+///
+/// Clones share the pages, so a test keeps one to publish a page a service fetches.
+#[derive(Clone, Default)]
+pub struct InMemoryWebsiteFetcher {
+    pages: Arc<DashMap<String, String>>,
+}
+
+impl InMemoryWebsiteFetcher {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Serves `body` at `url` from now on
+    pub fn set_page(&self, url: impl Into<String>, body: impl Into<String>) {
+        self.pages.insert(url.into(), body.into());
+    }
+}
+
+#[async_trait::async_trait]
+impl HealthCheck for InMemoryWebsiteFetcher {
+    async fn check(&self) -> ComponentHealth {
+        ComponentHealth::up(
+            "website_fetcher",
+            format!("in-memory, {} pages served", self.pages.len()),
+        )
+    }
+}
+
+#[async_trait::async_trait]
+impl WebsiteFetcher for InMemoryWebsiteFetcher {
+    async fn fetch(&self, url: &WebsiteUrl) -> Result<String, DomainError> {
+        self.pages
+            .get(url.as_str())
+            .map(|page| page.value().clone())
+            .ok_or(DomainError::unavailable("business website unreachable"))
+    }
+}
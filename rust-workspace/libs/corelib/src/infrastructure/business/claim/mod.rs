@@ -0,0 +1,3 @@
+pub mod http_impl;
+#[cfg(test)]
+pub mod in_memory_impl;
@@ -1,6 +1,9 @@
-use crate::domain::business::{
-    events::BusinessDomainEvent,
-    ports::event::{BusinessDomainEventBus, BusinessDomainEventHandler},
+use crate::domain::{
+    shared::health::{ComponentHealth, HealthCheck},
+    business::{
+        events::BusinessDomainEvent,
+        ports::event::{BusinessDomainEventBus, BusinessDomainEventHandler},
+    },
 };
 
 #[derive(Default)]
@@ -28,4 +31,20 @@ impl BusinessDomainEventBus for InMemoryBusinessEventBus {
         }
         Ok(())
     }
+
+    fn flush(&self) -> Result<(), String> {
+        // Events are handed to every handler inside `publish`, so nothing is ever queued
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl HealthCheck for InMemoryBusinessEventBus {
+    async fn check(&self) -> ComponentHealth {
+        // Events are handed to every handler inside `publish`, so nothing is ever queued
+        ComponentHealth::up(
+            "business_event_bus",
+            format!("in-memory, {} handlers, 0 pending events", self.handlers.len()),
+        )
+    }
 }
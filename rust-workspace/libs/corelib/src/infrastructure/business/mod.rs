@@ -1,3 +1,4 @@
 pub mod event_bus;
 pub mod event_handlers;
-pub mod repository;
\ No newline at end of file
+pub mod repository;
+pub mod claim;
\ No newline at end of file
@@ -1,33 +1,132 @@
-use std::collections::HashMap;
+use std::{collections::HashSet, sync::RwLock};
+
+use dashmap::DashMap;
+use rstar::{AABB, RTree, primitives::GeomWithData};
 
 use crate::domain::{
     business::{Business, ports::respository::BusinessRepository, value_objects::BusinessId},
-    shared::{error::DomainError, specs::Specification},
+    shared::{
+        error::DomainError,
+        geo::{GeoArea, GeoPoint, Nearby},
+        health::{ComponentHealth, HealthCheck},
+        specs::Specification,
+    },
+    user::value_object::UserId,
 };
 
 use crate::application::business::service::BusinessService;
 
+/// `[latitude, longitude]` of a business in the spatial index
+type IndexedLocation = GeomWithData<[f64; 2], BusinessId>;
+
+#[derive(Default)]
 pub struct InMemoryBusinessRepository {
-    by_id: HashMap<BusinessId, Business>,
+    by_id: DashMap<BusinessId, Business>,
+    /// Located businesses, kept in step with `by_id` on save and delete
+    locations: RwLock<RTree<IndexedLocation>>,
+    /// Businesses of each member, kept in step with `by_id` on save and delete
+    by_member: DashMap<UserId, HashSet<BusinessId>>,
 }
 
 impl InMemoryBusinessRepository {
     pub fn new() -> Self {
         Self {
-            by_id: HashMap::new(),
+            by_id: DashMap::new(),
+            locations: RwLock::new(RTree::new()),
+            by_member: DashMap::new(),
         }
     }
+
+    fn reindex_members(&self, id: BusinessId, previous: &[UserId], latest: &[UserId]) {
+        for user_id in previous.iter().filter(|u| !latest.contains(u)) {
+            if let Some(mut ids) = self.by_member.get_mut(user_id) {
+                ids.remove(&id);
+            }
+            self.by_member.remove_if(user_id, |_, ids| ids.is_empty());
+        }
+        for user_id in latest.iter().filter(|u| !previous.contains(u)) {
+            self.by_member.entry(*user_id).or_default().insert(id);
+        }
+    }
+
+    fn reindex(&self, id: BusinessId, previous: Option<GeoPoint>, latest: Option<GeoPoint>) {
+        if previous == latest {
+            return;
+        }
+        let mut locations = self.locations.write().expect("location index poisoned");
+        if let Some(point) = previous {
+            locations.remove(&IndexedLocation::new(
+                [point.latitude(), point.longitude()],
+                id,
+            ));
+        }
+        if let Some(point) = latest {
+            locations.insert(IndexedLocation::new(
+                [point.latitude(), point.longitude()],
+                id,
+            ));
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl HealthCheck for InMemoryBusinessRepository {
+    async fn check(&self) -> ComponentHealth {
+        let located = self
+            .locations
+            .read()
+            .expect("location index poisoned")
+            .size();
+        ComponentHealth::up(
+            "business_repository",
+            format!(
+                "in-memory, {} businesses stored, {} located",
+                self.by_id.len(),
+                located
+            ),
+        )
+    }
 }
 
 #[async_trait::async_trait]
 impl BusinessRepository for InMemoryBusinessRepository {
-    async fn save(&mut self, user: &Business) -> Result<(), DomainError> {
-        self.by_id.insert(user.id.clone(), user.clone());
+    async fn save(&self, user: &Business) -> Result<(), DomainError> {
+        let previous = self.by_id.insert(user.id, user.clone());
+        self.reindex(
+            user.id,
+            previous.as_ref().and_then(Business::location),
+            user.location(),
+        );
+        self.reindex_members(
+            user.id,
+            &previous.as_ref().map(member_ids).unwrap_or_default(),
+            &member_ids(user),
+        );
         Ok(())
     }
 
     async fn find_by_id(&self, id: &BusinessId) -> Result<Option<Business>, DomainError> {
-        Ok(self.by_id.get(id).cloned())
+        Ok(self.by_id.get(id).map(|entry| entry.value().clone()))
+    }
+
+    async fn find_by_ids(&self, ids: &[BusinessId]) -> Result<Vec<Business>, DomainError> {
+        Ok(ids
+            .iter()
+            .filter_map(|id| self.by_id.get(id).map(|entry| entry.value().clone()))
+            .collect())
+    }
+
+    async fn find_by_member_ids(&self, user_ids: &[UserId]) -> Result<Vec<Business>, DomainError> {
+        let ids: HashSet<BusinessId> = user_ids
+            .iter()
+            .filter_map(|user_id| self.by_member.get(user_id))
+            .flat_map(|entry| entry.value().iter().copied().collect::<Vec<_>>())
+            .collect();
+
+        Ok(ids
+            .iter()
+            .filter_map(|id| self.by_id.get(id).map(|entry| entry.value().clone()))
+            .collect())
     }
 
     async fn query(
@@ -36,34 +135,209 @@ impl BusinessRepository for InMemoryBusinessRepository {
         limit: usize,
         offset: usize,
     ) -> Result<Vec<Business>, DomainError> {
-        let mut filtered: Vec<&Business> = self
+        let mut filtered: Vec<Business> = self
             .by_id
-            .values()
-            .filter(|u| spec.is_satisfied_by(u))
+            .iter()
+            .filter(|entry| spec.is_satisfied_by(entry.value()))
+            .map(|entry| entry.value().clone())
             .collect();
 
         // Deterministic ordering
         filtered.sort_by(|a, b| {
             a.timestamps
                 .created_at()
-                .cmp(&b.timestamps.created_at())
+                .cmp(b.timestamps.created_at())
                 .then_with(|| a.id.cmp(&b.id))
         });
 
-        Ok(filtered
+        Ok(filtered.into_iter().skip(offset).take(limit).collect())
+    }
+
+    async fn find_in_area(
+        &self,
+        area: &GeoArea,
+        limit: usize,
+        offset: usize,
+    ) -> Result<Vec<Nearby<Business>>, DomainError> {
+        // The index narrows the search to the area's bounding box,
+        // the exact shape is checked on the businesses themselves
+        let candidates: Vec<BusinessId> = {
+            let locations = self.locations.read().expect("location index poisoned");
+            area.bounds()
+                .envelopes()
+                .into_iter()
+                .flat_map(|(south_west, north_east)| {
+                    locations
+                        .locate_in_envelope(&AABB::from_corners(south_west, north_east))
+                        .map(|location| location.data)
+                        .collect::<Vec<_>>()
+                })
+                .collect()
+        };
+
+        let origin = area.origin();
+        let mut found: Vec<Nearby<Business>> = candidates
             .into_iter()
-            .skip(offset)
-            .take(limit)
-            .cloned()
-            .collect())
+            .filter_map(|id| self.by_id.get(&id).map(|entry| entry.value().clone()))
+            .filter(|business| !business.deleted.is_deleted())
+            .filter_map(|business| {
+                let location = business.location()?;
+                area.contains(&location).then(|| Nearby {
+                    distance_km: origin.distance_km(&location),
+                    value: business,
+                })
+            })
+            .collect();
+
+        found.sort_by(|a, b| {
+            a.distance_km
+                .total_cmp(&b.distance_km)
+                .then_with(|| a.value.id.cmp(&b.value.id))
+        });
+        found.dedup_by(|a, b| a.value.id == b.value.id);
+
+        Ok(found.into_iter().skip(offset).take(limit).collect())
     }
 
-    async fn delete(&mut self, id: &BusinessId) -> Result<(), DomainError> {
+    async fn delete(&self, id: &BusinessId) -> Result<(), DomainError> {
         // If needed you can return error when user does not exist
         // For now, soft-ignore it.
-        self.by_id.remove(id);
+        if let Some((_, business)) = self.by_id.remove(id) {
+            self.reindex(*id, business.location(), None);
+            self.reindex_members(*id, &member_ids(&business), &[]);
+        }
+        Ok(())
+    }
+
+    async fn close(&self) -> Result<(), DomainError> {
+        // Nothing to release, the data lives and dies with the process
         Ok(())
     }
 }
 
+fn member_ids(business: &Business) -> Vec<UserId> {
+    business
+        .members
+        .members()
+        .iter()
+        .map(|m| m.user_id())
+        .collect()
+}
+
 pub type IBusinessserviceInMemoryBusinessRepository = BusinessService<InMemoryBusinessRepository>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{
+        business::value_objects::{BusinessName, ContactInfo},
+        shared::value_object::PhysicalAddress,
+        term::value_objects::TermId,
+    };
+
+    fn point(latitude: f64, longitude: f64) -> GeoPoint {
+        GeoPoint::new(latitude, longitude, "location").unwrap()
+    }
+
+    fn contact_at(location: GeoPoint) -> ContactInfo {
+        let address = PhysicalAddress::new(
+            "1 Main St",
+            "Town",
+            None,
+            None,
+            TermId::new(),
+            Some(location),
+        )
+        .unwrap();
+        ContactInfo::new(None, None, Some(address), None)
+    }
+
+    fn business_at(location: GeoPoint) -> Business {
+        Business::new(
+            BusinessId::new(),
+            BusinessName::new("Corner Shop").unwrap(),
+            None,
+            Some(contact_at(location)),
+            None,
+            None,
+            None,
+        )
+    }
+
+    async fn found_around(
+        repository: &InMemoryBusinessRepository,
+        center: GeoPoint,
+        radius_km: f64,
+    ) -> Vec<BusinessId> {
+        let area = GeoArea::radius(center, radius_km).unwrap();
+        repository
+            .find_in_area(&area, 10, 0)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|nearby| nearby.value.id)
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn radius_search_reaches_across_the_antimeridian() {
+        let repository = InMemoryBusinessRepository::new();
+        let east = business_at(point(0.0, 179.9));
+        let west = business_at(point(0.0, -179.9));
+        let far = business_at(point(0.0, 0.0));
+        for business in [&east, &west, &far] {
+            repository.save(business).await.unwrap();
+        }
+
+        let found = found_around(&repository, point(0.0, 179.95), 50.0).await;
+
+        assert_eq!(found, [east.id, west.id]);
+    }
+
+    #[tokio::test]
+    async fn radius_search_near_a_pole_covers_every_longitude() {
+        let repository = InMemoryBusinessRepository::new();
+        let across_the_pole = business_at(point(89.8, -90.0));
+        repository.save(&across_the_pole).await.unwrap();
+
+        let found = found_around(&repository, point(89.8, 90.0), 100.0).await;
+
+        assert_eq!(found, [across_the_pole.id]);
+    }
+
+    #[tokio::test]
+    async fn a_moved_business_is_found_only_at_its_new_location() {
+        let repository = InMemoryBusinessRepository::new();
+        let mut business = business_at(point(48.85, 2.35));
+        repository.save(&business).await.unwrap();
+
+        business.set_contact_info(Some(contact_at(point(51.5, -0.12))));
+        repository.save(&business).await.unwrap();
+
+        assert!(
+            found_around(&repository, point(48.85, 2.35), 10.0)
+                .await
+                .is_empty()
+        );
+        assert_eq!(
+            found_around(&repository, point(51.5, -0.12), 10.0).await,
+            [business.id]
+        );
+    }
+
+    #[tokio::test]
+    async fn a_deleted_business_leaves_the_index() {
+        let repository = InMemoryBusinessRepository::new();
+        let business = business_at(point(48.85, 2.35));
+        repository.save(&business).await.unwrap();
+
+        repository.delete(&business.id).await.unwrap();
+
+        assert!(
+            found_around(&repository, point(48.85, 2.35), 10.0)
+                .await
+                .is_empty()
+        );
+        assert_eq!(repository.locations.read().unwrap().size(), 0);
+    }
+}
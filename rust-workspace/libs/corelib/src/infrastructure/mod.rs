@@ -1,4 +1,5 @@
 pub mod term;
 pub mod user;
 pub mod taxonomy;
-pub mod business;
\ No newline at end of file
+pub mod business;
+pub mod shared;
\ No newline at end of file
@@ -0,0 +1 @@
+pub mod term_selection;
\ No newline at end of file
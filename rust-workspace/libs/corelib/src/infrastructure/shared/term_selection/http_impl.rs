@@ -0,0 +1,220 @@
+use std::time::Duration;
+
+use serde::{Deserialize, de::DeserializeOwned};
+use serde_json::json;
+use uuid::Uuid;
+
+use crate::domain::{
+    shared::{
+        error::{DomainError, FieldError},
+        health::{ComponentHealth, HealthCheck},
+        ports::term_selection::TermSelectionValidator,
+        term_selection::{TaxonomyReference, TermCatalogue, TermReference, TermSelection},
+    },
+    taxonomy::value_objects::TaxonomyId,
+    term::value_objects::TermId,
+};
+
+const TAXONOMY_SELECTION: &str = "... on Taxonomy { id visible deleted }";
+const TERM_SELECTION: &str = "... on Term { id visible deleted taxonomy { id } }";
+
+/// Validates term selections against the taxonomy and term subgraphs over HTTP
+///
+/// References are resolved in one `_entities` query per subgraph, the same
+/// query the router sends, so no subgraph needs a dedicated endpoint.
+pub struct HttpTermSelectionValidator {
+    client: reqwest::Client,
+    taxonomy_url: String,
+    term_url: String,
+}
+
+impl HttpTermSelectionValidator {
+    pub fn new(
+        taxonomy_url: impl Into<String>,
+        term_url: impl Into<String>,
+        timeout: Duration,
+    ) -> Result<Self, DomainError> {
+        let client = reqwest::Client::builder()
+            .timeout(timeout)
+            .build()
+            .map_err(|e| {
+                DomainError::unavailable_with("failed to build the catalogue client", e)
+            })?;
+
+        Ok(Self {
+            client,
+            taxonomy_url: taxonomy_url.into(),
+            term_url: term_url.into(),
+        })
+    }
+
+    async fn post<T: DeserializeOwned>(
+        &self,
+        url: &str,
+        body: serde_json::Value,
+    ) -> Result<GraphqlResponse<T>, DomainError> {
+        let response = self
+            .client
+            .post(url)
+            .json(&body)
+            .send()
+            .await
+            .and_then(|response| response.error_for_status())
+            .map_err(|e| {
+                DomainError::unavailable_with(format!("catalogue at {} unreachable", url), e)
+            })?;
+
+        response.json::<GraphqlResponse<T>>().await.map_err(|e| {
+            DomainError::unavailable_with(format!("invalid catalogue response from {}", url), e)
+        })
+    }
+
+    /// Entities of `typename` with the given ids, unknown ids are left out
+    async fn entities<T: DeserializeOwned>(
+        &self,
+        url: &str,
+        typename: &str,
+        selection: &str,
+        ids: &[Uuid],
+    ) -> Result<Vec<T>, DomainError> {
+        if ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let representations: Vec<serde_json::Value> = ids
+            .iter()
+            .map(|id| json!({ "__typename": typename, "id": id }))
+            .collect();
+        let body = json!({
+            "query": format!(
+                "query($representations: [_Any!]!) {{ _entities(representations: $representations) {{ {} }} }}",
+                selection
+            ),
+            "variables": { "representations": representations },
+        });
+
+        let response = self.post::<EntitiesData<T>>(url, body).await?;
+        if let Some(error) = response.errors.first() {
+            return Err(DomainError::unavailable(format!(
+                "catalogue at {} failed: {}",
+                url, error.message
+            )));
+        }
+
+        Ok(response
+            .data
+            .map(|data| data.entities.into_iter().flatten().collect())
+            .unwrap_or_default())
+    }
+}
+
+#[async_trait::async_trait]
+impl HealthCheck for HttpTermSelectionValidator {
+    async fn check(&self) -> ComponentHealth {
+        let ping = json!({ "query": "{ __typename }" });
+
+        for url in [&self.taxonomy_url, &self.term_url] {
+            if let Err(e) = self.post::<serde_json::Value>(url, ping.clone()).await {
+                return ComponentHealth::degraded("term_selection_validator", e.to_string());
+            }
+        }
+        ComponentHealth::up(
+            "term_selection_validator",
+            format!(
+                "taxonomies at {}, terms at {}",
+                self.taxonomy_url, self.term_url
+            ),
+        )
+    }
+}
+
+#[async_trait::async_trait]
+impl TermSelectionValidator for HttpTermSelectionValidator {
+    async fn validate(
+        &self,
+        field: &str,
+        selection: &TermSelection,
+    ) -> Result<Vec<FieldError>, DomainError> {
+        let (taxonomy_ids, term_ids) = TermCatalogue::references(selection);
+
+        let taxonomy_ids: Vec<Uuid> = taxonomy_ids.iter().map(TaxonomyId::as_uuid).collect();
+        let term_ids: Vec<Uuid> = term_ids.iter().map(TermId::as_uuid).collect();
+
+        let taxonomies: Vec<TaxonomyEntity> = self
+            .entities(
+                &self.taxonomy_url,
+                "Taxonomy",
+                TAXONOMY_SELECTION,
+                &taxonomy_ids,
+            )
+            .await?;
+        let terms: Vec<TermEntity> = self
+            .entities(&self.term_url, "Term", TERM_SELECTION, &term_ids)
+            .await?;
+
+        let catalogue = TermCatalogue::new(
+            taxonomies.into_iter().map(TaxonomyReference::from),
+            terms.into_iter().map(TermReference::from),
+        );
+        Ok(catalogue.violations(field, selection))
+    }
+}
+
+#[derive(Deserialize)]
+struct GraphqlResponse<T> {
+    data: Option<T>,
+    #[serde(default)]
+    errors: Vec<GraphqlError>,
+}
+
+#[derive(Deserialize)]
+struct GraphqlError {
+    message: String,
+}
+
+#[derive(Deserialize)]
+struct EntitiesData<T> {
+    #[serde(rename = "_entities")]
+    entities: Vec<Option<T>>,
+}
+
+#[derive(Deserialize)]
+struct TaxonomyEntity {
+    id: Uuid,
+    visible: bool,
+    deleted: bool,
+}
+
+impl From<TaxonomyEntity> for TaxonomyReference {
+    fn from(value: TaxonomyEntity) -> Self {
+        Self {
+            id: TaxonomyId::from_uuid(value.id),
+            visible: value.visible,
+            deleted: value.deleted,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct TermEntity {
+    id: Uuid,
+    visible: bool,
+    deleted: bool,
+    taxonomy: EntityRef,
+}
+
+#[derive(Deserialize)]
+struct EntityRef {
+    id: Uuid,
+}
+
+impl From<TermEntity> for TermReference {
+    fn from(value: TermEntity) -> Self {
+        Self {
+            id: TermId::from_uuid(value.id),
+            taxonomy_id: TaxonomyId::from_uuid(value.taxonomy.id),
+            visible: value.visible,
+            deleted: value.deleted,
+        }
+    }
+}
@@ -0,0 +1,61 @@
+use std::sync::Arc;
+
+use crate::{
+    application::{taxonomy::service::TaxonomyService, term::service::TermService},
+    domain::{
+        shared::{
+            error::{DomainError, FieldError},
+            health::{ComponentHealth, HealthCheck},
+            ports::term_selection::TermSelectionValidator,
+            term_selection::{TaxonomyReference, TermCatalogue, TermReference, TermSelection},
+        },
+        taxonomy::ports::respository::TaxonomyRepository,
+        term::ports::respository::TermRepository,
+    },
+};
+
+/// Validates term selections against taxonomy and term services running in the same process
+pub struct InProcessTermSelectionValidator<X: TaxonomyRepository, T: TermRepository> {
+    taxonomies: Arc<TaxonomyService<X>>,
+    terms: Arc<TermService<T>>,
+}
+
+impl<X: TaxonomyRepository, T: TermRepository> InProcessTermSelectionValidator<X, T> {
+    pub fn new(taxonomies: Arc<TaxonomyService<X>>, terms: Arc<TermService<T>>) -> Self {
+        Self { taxonomies, terms }
+    }
+}
+
+#[async_trait::async_trait]
+impl<X: TaxonomyRepository, T: TermRepository> HealthCheck
+    for InProcessTermSelectionValidator<X, T>
+{
+    async fn check(&self) -> ComponentHealth {
+        ComponentHealth::up(
+            "term_selection_validator",
+            "in-process taxonomy and term services",
+        )
+    }
+}
+
+#[async_trait::async_trait]
+impl<X: TaxonomyRepository, T: TermRepository> TermSelectionValidator
+    for InProcessTermSelectionValidator<X, T>
+{
+    async fn validate(
+        &self,
+        field: &str,
+        selection: &TermSelection,
+    ) -> Result<Vec<FieldError>, DomainError> {
+        let (taxonomy_ids, term_ids) = TermCatalogue::references(selection);
+
+        let taxonomies = self.taxonomies.find_by_ids(&taxonomy_ids).await?;
+        let terms = self.terms.find_by_ids(&term_ids).await?;
+
+        let catalogue = TermCatalogue::new(
+            taxonomies.iter().map(TaxonomyReference::from),
+            terms.iter().map(TermReference::from),
+        );
+        Ok(catalogue.violations(field, selection))
+    }
+}
@@ -1,6 +1,9 @@
-use crate::domain::taxonomy::{
-    events::TaxonomyDomainEvent,
-    ports::event::{TaxonomyDomainEventBus, TaxonomyDomainEventHandler},
+use crate::domain::{
+    shared::health::{ComponentHealth, HealthCheck},
+    taxonomy::{
+        events::TaxonomyDomainEvent,
+        ports::event::{TaxonomyDomainEventBus, TaxonomyDomainEventHandler},
+    },
 };
 
 #[derive(Default)]
@@ -28,4 +31,20 @@ impl TaxonomyDomainEventBus for InMemoryTaxonomyEventBus {
         }
         Ok(())
     }
+
+    fn flush(&self) -> Result<(), String> {
+        // Events are handed to every handler inside `publish`, so nothing is ever queued
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl HealthCheck for InMemoryTaxonomyEventBus {
+    async fn check(&self) -> ComponentHealth {
+        // Events are handed to every handler inside `publish`, so nothing is ever queued
+        ComponentHealth::up(
+            "taxonomy_event_bus",
+            format!("in-memory, {} handlers, 0 pending events", self.handlers.len()),
+        )
+    }
 }
@@ -1,6 +1,9 @@
-use crate::domain::term::{
-    events::TermDomainEvent,
-    ports::event::{TermDomainEventBus, TermDomainEventHandler},
+use crate::domain::{
+    shared::health::{ComponentHealth, HealthCheck},
+    term::{
+        events::TermDomainEvent,
+        ports::event::{TermDomainEventBus, TermDomainEventHandler},
+    },
 };
 
 #[derive(Default)]
@@ -28,4 +31,20 @@ impl TermDomainEventBus for InMemoryTermEventBus {
         }
         Ok(())
     }
+
+    fn flush(&self) -> Result<(), String> {
+        // Events are handed to every handler inside `publish`, so nothing is ever queued
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl HealthCheck for InMemoryTermEventBus {
+    async fn check(&self) -> ComponentHealth {
+        // Events are handed to every handler inside `publish`, so nothing is ever queued
+        ComponentHealth::up(
+            "term_event_bus",
+            format!("in-memory, {} handlers, 0 pending events", self.handlers.len()),
+        )
+    }
 }
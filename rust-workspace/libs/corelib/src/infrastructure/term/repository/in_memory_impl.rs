@@ -1,33 +1,55 @@
-use std::collections::HashMap;
+use dashmap::DashMap;
 
 use crate::domain::{
-    shared::{error::DomainError, specs::Specification},
+    shared::{
+            error::DomainError,
+            health::{ComponentHealth, HealthCheck},
+            specs::Specification,
+        },
     term::{Term,ports::respository::TermRepository, value_objects::TermId},
 };
 
 use crate::application::term::service::TermService;
 
+#[derive(Default)]
 pub struct InMemoryTermRepository {
-    by_id: HashMap<TermId, Term>,
+    by_id: DashMap<TermId, Term>,
 }
 
 impl InMemoryTermRepository {
     pub fn new() -> Self {
         Self {
-            by_id: HashMap::new(),
+            by_id: DashMap::new(),
         }
     }
 }
 
+#[async_trait::async_trait]
+impl HealthCheck for InMemoryTermRepository {
+    async fn check(&self) -> ComponentHealth {
+        ComponentHealth::up(
+            "term_repository",
+            format!("in-memory, {} terms stored", self.by_id.len()),
+        )
+    }
+}
+
 #[async_trait::async_trait]
 impl TermRepository for InMemoryTermRepository {
-    async fn save(&mut self, user: &Term) -> Result<(), DomainError> {
-        self.by_id.insert(user.id.clone(), user.clone());
+    async fn save(&self, user: &Term) -> Result<(), DomainError> {
+        self.by_id.insert(user.id, user.clone());
         Ok(())
     }
 
     async fn find_by_id(&self, id: &TermId) -> Result<Option<Term>, DomainError> {
-        Ok(self.by_id.get(id).cloned())
+        Ok(self.by_id.get(id).map(|entry| entry.value().clone()))
+    }
+
+    async fn find_by_ids(&self, ids: &[TermId]) -> Result<Vec<Term>, DomainError> {
+        Ok(ids
+            .iter()
+            .filter_map(|id| self.by_id.get(id).map(|entry| entry.value().clone()))
+            .collect())
     }
 
     async fn query(
@@ -36,34 +58,35 @@ impl TermRepository for InMemoryTermRepository {
         limit: usize,
         offset: usize,
     ) -> Result<Vec<Term>, DomainError> {
-        let mut filtered: Vec<&Term> = self
+        let mut filtered: Vec<Term> = self
             .by_id
-            .values()
-            .filter(|u| spec.is_satisfied_by(u))
+            .iter()
+            .filter(|entry| spec.is_satisfied_by(entry.value()))
+            .map(|entry| entry.value().clone())
             .collect();
 
         // Deterministic ordering
         filtered.sort_by(|a, b| {
             a.timestamps
                 .created_at()
-                .cmp(&b.timestamps.created_at())
+                .cmp(b.timestamps.created_at())
                 .then_with(|| a.id.cmp(&b.id))
         });
 
-        Ok(filtered
-            .into_iter()
-            .skip(offset)
-            .take(limit)
-            .cloned()
-            .collect())
+        Ok(filtered.into_iter().skip(offset).take(limit).collect())
     }
 
-    async fn delete(&mut self, id: &TermId) -> Result<(), DomainError> {
+    async fn delete(&self, id: &TermId) -> Result<(), DomainError> {
         // If needed you can return error when user does not exist
         // For now, soft-ignore it.
         self.by_id.remove(id);
         Ok(())
     }
+
+    async fn close(&self) -> Result<(), DomainError> {
+        // Nothing to release, the data lives and dies with the process
+        Ok(())
+    }
 }
 
 pub type ITermserviceInMemoryTermRepository = TermService<InMemoryTermRepository>;
@@ -0,0 +1,125 @@
+use std::time::Duration;
+
+use serde::{Deserialize, de::DeserializeOwned};
+use serde_json::json;
+use uuid::Uuid;
+
+use crate::domain::{
+    shared::{
+        error::DomainError,
+        health::{ComponentHealth, HealthCheck},
+    },
+    user::{
+        ports::directory::UserDirectory,
+        value_object::{IdentityId, UserId},
+    },
+};
+
+const USER_BY_IDENTITY: &str =
+    "query($identityId: ID!) { userByIdentity(identityId: $identityId) { id } }";
+
+/// Finds users through the user subgraph over HTTP
+pub struct HttpUserDirectory {
+    client: reqwest::Client,
+    user_url: String,
+}
+
+impl HttpUserDirectory {
+    pub fn new(user_url: impl Into<String>, timeout: Duration) -> Result<Self, DomainError> {
+        let client = reqwest::Client::builder()
+            .timeout(timeout)
+            .build()
+            .map_err(|e| {
+                DomainError::unavailable_with("failed to build the user directory client", e)
+            })?;
+
+        Ok(Self {
+            client,
+            user_url: user_url.into(),
+        })
+    }
+
+    async fn post<T: DeserializeOwned>(
+        &self,
+        body: serde_json::Value,
+    ) -> Result<GraphqlResponse<T>, DomainError> {
+        let response = self
+            .client
+            .post(&self.user_url)
+            .json(&body)
+            .send()
+            .await
+            .and_then(|response| response.error_for_status())
+            .map_err(|e| {
+                DomainError::unavailable_with(
+                    format!("user directory at {} unreachable", self.user_url),
+                    e,
+                )
+            })?;
+
+        response.json::<GraphqlResponse<T>>().await.map_err(|e| {
+            DomainError::unavailable_with(
+                format!("invalid user directory response from {}", self.user_url),
+                e,
+            )
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl HealthCheck for HttpUserDirectory {
+    async fn check(&self) -> ComponentHealth {
+        let ping = json!({ "query": "{ __typename }" });
+
+        match self.post::<serde_json::Value>(ping).await {
+            Ok(_) => ComponentHealth::up("user_directory", format!("users at {}", self.user_url)),
+            Err(e) => ComponentHealth::degraded("user_directory", e.to_string()),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl UserDirectory for HttpUserDirectory {
+    async fn user_of(&self, identity_id: &IdentityId) -> Result<Option<UserId>, DomainError> {
+        let body = json!({
+            "query": USER_BY_IDENTITY,
+            "variables": { "identityId": identity_id.as_str() },
+        });
+
+        let response = self.post::<UserByIdentityData>(body).await?;
+        if let Some(error) = response.errors.first() {
+            return Err(DomainError::unavailable(format!(
+                "user directory at {} failed: {}",
+                self.user_url, error.message
+            )));
+        }
+
+        Ok(response
+            .data
+            .and_then(|data| data.user_by_identity)
+            .map(|user| UserId::from_uuid(user.id)))
+    }
+}
+
+#[derive(Deserialize)]
+struct GraphqlResponse<T> {
+    data: Option<T>,
+    #[serde(default)]
+    errors: Vec<GraphqlError>,
+}
+
+#[derive(Deserialize)]
+struct GraphqlError {
+    message: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct UserByIdentityData {
+    user_by_identity: Option<UserEntity>,
+}
+
+#[derive(Deserialize)]
+struct UserEntity {
+    id: Uuid,
+}
@@ -0,0 +1,41 @@
+use std::sync::Arc;
+
+use crate::{
+    application::user::service::UserService,
+    domain::{
+        shared::{
+            error::DomainError,
+            health::{ComponentHealth, HealthCheck},
+        },
+        user::{
+            ports::{directory::UserDirectory, respository::UserRepository},
+            value_object::{IdentityId, UserId},
+        },
+    },
+};
+
+/// Finds users through a user service running in the same process
+pub struct InProcessUserDirectory<R: UserRepository> {
+    users: Arc<UserService<R>>,
+}
+
+impl<R: UserRepository> InProcessUserDirectory<R> {
+    pub fn new(users: Arc<UserService<R>>) -> Self {
+        Self { users }
+    }
+}
+
+#[async_trait::async_trait]
+impl<R: UserRepository> HealthCheck for InProcessUserDirectory<R> {
+    async fn check(&self) -> ComponentHealth {
+        ComponentHealth::up("user_directory", "in-process user service")
+    }
+}
+
+#[async_trait::async_trait]
+impl<R: UserRepository> UserDirectory for InProcessUserDirectory<R> {
+    async fn user_of(&self, identity_id: &IdentityId) -> Result<Option<UserId>, DomainError> {
+        let user = self.users.find_by_identity(identity_id).await?;
+        Ok(user.map(|user| user.id))
+    }
+}
@@ -0,0 +1,2 @@
+pub mod http_impl;
+pub mod in_process_impl;
\ No newline at end of file
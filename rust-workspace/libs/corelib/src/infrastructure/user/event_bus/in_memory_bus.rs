@@ -1,6 +1,9 @@
-use crate::domain::user::{
-    events::UserDomainEvent,
-    ports::event::{UserDomainEventBus, UserDomainEventHandler},
+use crate::domain::{
+    shared::health::{ComponentHealth, HealthCheck},
+    user::{
+        events::UserDomainEvent,
+        ports::event::{UserDomainEventBus, UserDomainEventHandler},
+    },
 };
 
 #[derive(Default)]
@@ -28,4 +31,20 @@ impl UserDomainEventBus for InMemoryUserEventBus {
         }
         Ok(())
     }
+
+    fn flush(&self) -> Result<(), String> {
+        // Events are handed to every handler inside `publish`, so nothing is ever queued
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl HealthCheck for InMemoryUserEventBus {
+    async fn check(&self) -> ComponentHealth {
+        // Events are handed to every handler inside `publish`, so nothing is ever queued
+        ComponentHealth::up(
+            "user_event_bus",
+            format!("in-memory, {} handlers, 0 pending events", self.handlers.len()),
+        )
+    }
 }
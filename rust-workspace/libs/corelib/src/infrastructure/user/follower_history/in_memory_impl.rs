@@ -0,0 +1,76 @@
+use chrono::{DateTime, Utc};
+use dashmap::DashMap;
+
+use crate::domain::{
+    shared::{
+        error::DomainError,
+        health::{ComponentHealth, HealthCheck},
+        value_object::SocialMediaPlatform,
+    },
+    user::{
+        follower_history::FollowerSnapshot, ports::follower_history::FollowerHistoryRepository,
+        value_object::UserId,
+    },
+};
+
+#[derive(Default)]
+pub struct InMemoryFollowerHistoryRepository {
+    /// Snapshots per user, kept oldest first
+    by_user: DashMap<UserId, Vec<FollowerSnapshot>>,
+}
+
+impl InMemoryFollowerHistoryRepository {
+    pub fn new() -> Self {
+        Self {
+            by_user: DashMap::new(),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl HealthCheck for InMemoryFollowerHistoryRepository {
+    async fn check(&self) -> ComponentHealth {
+        let snapshots: usize = self.by_user.iter().map(|entry| entry.value().len()).sum();
+        ComponentHealth::up(
+            "follower_history_repository",
+            format!("in-memory, {} snapshots stored", snapshots),
+        )
+    }
+}
+
+#[async_trait::async_trait]
+impl FollowerHistoryRepository for InMemoryFollowerHistoryRepository {
+    async fn append(&self, snapshots: &[FollowerSnapshot]) -> Result<(), DomainError> {
+        for snapshot in snapshots {
+            let mut history = self.by_user.entry(snapshot.user_id).or_default();
+            // Ingested metrics may arrive late, keep the series sorted
+            let pos = history.partition_point(|s| s.recorded_at <= snapshot.recorded_at);
+            history.insert(pos, snapshot.clone());
+        }
+        Ok(())
+    }
+
+    async fn history(
+        &self,
+        user_id: &UserId,
+        platform: Option<SocialMediaPlatform>,
+        since: Option<DateTime<Utc>>,
+        until: DateTime<Utc>,
+    ) -> Result<Vec<FollowerSnapshot>, DomainError> {
+        let Some(history) = self.by_user.get(user_id) else {
+            return Ok(Vec::new());
+        };
+
+        Ok(history
+            .iter()
+            .filter(|s| platform.is_none_or(|p| s.platform == p))
+            .filter(|s| since.is_none_or(|since| s.recorded_at >= since))
+            .filter(|s| s.recorded_at <= until)
+            .cloned()
+            .collect())
+    }
+
+    async fn close(&self) -> Result<(), DomainError> {
+        Ok(())
+    }
+}
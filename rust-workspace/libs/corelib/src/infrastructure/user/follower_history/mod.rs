@@ -0,0 +1 @@
+pub mod in_memory_impl;
\ No newline at end of file
@@ -1,3 +1,5 @@
 pub mod repository;
 pub mod event_bus;
-pub mod event_handlers;
\ No newline at end of file
+pub mod event_handlers;
+pub mod follower_history;
+pub mod directory;
\ No newline at end of file
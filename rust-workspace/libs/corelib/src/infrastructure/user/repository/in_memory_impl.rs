@@ -1,34 +1,89 @@
-use std::collections::HashMap;
+use dashmap::{DashMap, mapref::entry::Entry};
 
 use crate::{
     domain::{
-        shared::{error::DomainError, specs::Specification},
-        user::{ports::respository::UserRepository, value_object::UserId},
+        shared::{
+            error::DomainError,
+            health::{ComponentHealth, HealthCheck},
+            specs::Specification,
+        },
+        user::{
+            ports::respository::UserRepository,
+            value_object::{IdentityId, UserId},
+        },
     },
     predule::{User, UserService},
 };
 
+#[derive(Default)]
 pub struct InMemoryUserRepository {
-    by_id: HashMap<UserId, User>,
+    by_id: DashMap<UserId, User>,
+    /// Unique index, one user per identity
+    by_identity: DashMap<IdentityId, UserId>,
 }
 
 impl InMemoryUserRepository {
     pub fn new() -> Self {
         Self {
-            by_id: HashMap::new(),
+            by_id: DashMap::new(),
+            by_identity: DashMap::new(),
         }
     }
 }
 
+#[async_trait::async_trait]
+impl HealthCheck for InMemoryUserRepository {
+    async fn check(&self) -> ComponentHealth {
+        ComponentHealth::up(
+            "user_repository",
+            format!("in-memory, {} users stored", self.by_id.len()),
+        )
+    }
+}
+
 #[async_trait::async_trait]
 impl UserRepository for InMemoryUserRepository {
-    async fn save(&mut self, user: &User) -> Result<(), DomainError> {
-        self.by_id.insert(user.id.clone(), user.clone());
+    async fn save(&self, user: &User) -> Result<(), DomainError> {
+        match self.by_identity.entry(user.identity_id.clone()) {
+            Entry::Occupied(owner) if *owner.get() != user.id => {
+                return Err(DomainError::conflict(format!(
+                    "identity {} already has a user",
+                    user.identity_id.as_str()
+                )));
+            }
+            Entry::Occupied(_) => {}
+            Entry::Vacant(slot) => {
+                slot.insert(user.id);
+            }
+        }
+        self.by_id.insert(user.id, user.clone());
         Ok(())
     }
 
     async fn find_by_id(&self, id: &UserId) -> Result<Option<User>, DomainError> {
-        Ok(self.by_id.get(id).cloned())
+        Ok(self.by_id.get(id).map(|entry| entry.value().clone()))
+    }
+
+    async fn find_by_ids(&self, ids: &[UserId]) -> Result<Vec<User>, DomainError> {
+        Ok(ids
+            .iter()
+            .filter_map(|id| self.by_id.get(id).map(|entry| entry.value().clone()))
+            .collect())
+    }
+
+    async fn find_by_identity_ids(
+        &self,
+        identity_ids: &[IdentityId],
+    ) -> Result<Vec<User>, DomainError> {
+        Ok(identity_ids
+            .iter()
+            .filter_map(|identity_id| {
+                self.by_identity
+                    .get(identity_id)
+                    .map(|entry| *entry.value())
+            })
+            .filter_map(|id| self.by_id.get(&id).map(|entry| entry.value().clone()))
+            .collect())
     }
 
     async fn query(
@@ -37,32 +92,35 @@ impl UserRepository for InMemoryUserRepository {
         limit: usize,
         offset: usize,
     ) -> Result<Vec<User>, DomainError> {
-        let mut filtered: Vec<&User> = self
+        let mut filtered: Vec<User> = self
             .by_id
-            .values()
-            .filter(|u| spec.is_satisfied_by(u))
+            .iter()
+            .filter(|entry| spec.is_satisfied_by(entry.value()))
+            .map(|entry| entry.value().clone())
             .collect();
 
         // Deterministic ordering
         filtered.sort_by(|a, b| {
             a.timestamps
                 .created_at()
-                .cmp(&b.timestamps.created_at())
+                .cmp(b.timestamps.created_at())
                 .then_with(|| a.id.cmp(&b.id))
         });
 
-        Ok(filtered
-            .into_iter()
-            .skip(offset)
-            .take(limit)
-            .cloned()
-            .collect())
+        Ok(filtered.into_iter().skip(offset).take(limit).collect())
     }
 
-    async fn delete(&mut self, id: &UserId) -> Result<(), DomainError> {
+    async fn delete(&self, id: &UserId) -> Result<(), DomainError> {
         // If needed you can return error when user does not exist
         // For now, soft-ignore it.
-        self.by_id.remove(id);
+        if let Some((_, user)) = self.by_id.remove(id) {
+            self.by_identity.remove(&user.identity_id);
+        }
+        Ok(())
+    }
+
+    async fn close(&self) -> Result<(), DomainError> {
+        // Nothing to release, the data lives and dies with the process
         Ok(())
     }
 }
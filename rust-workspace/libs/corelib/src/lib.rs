@@ -4,9 +4,16 @@ mod infrastructure;
 
 pub mod predule {
     // Shared
-    pub use crate::domain::shared::value_object::Demographics;
+    pub use crate::domain::shared::term_selection::TermSelection;
+    pub use crate::domain::shared::audience::AudienceDemographics;
+    pub use crate::domain::shared::audience::AudienceSource;
+    pub use crate::domain::shared::audience::Percentage;
     pub use crate::domain::shared::value_object::PhoneNumber;
     pub use crate::domain::shared::value_object::PhysicalAddress;
+    pub use crate::domain::shared::geo::GeoArea;
+    pub use crate::domain::shared::geo::GeoBounds;
+    pub use crate::domain::shared::geo::GeoPoint;
+    pub use crate::domain::shared::geo::Nearby;
     pub use crate::domain::shared::value_object::SocialMedia;
     pub use crate::domain::shared::value_object::SocialMediaLink;
     pub use crate::domain::shared::value_object::SocialMediaMetadata;
@@ -14,17 +21,52 @@ pub mod predule {
     pub use crate::domain::shared::value_object::SocialPlatformName;
     pub use crate::domain::shared::value_object::SocialMediaProfiles;
     pub use crate::domain::shared::value_object::Tag;
+    pub use crate::domain::shared::value_object::VerificationStatus;
     pub use crate::domain::shared::value_object::WebsiteUrl;
     pub use crate::domain::shared::value_object::EmailAddress;
     pub use crate::domain::shared::error::DomainError;
+    pub use crate::domain::shared::error::FieldError;
+    pub use crate::domain::shared::specs::Specification;
+    pub use crate::domain::shared::patch::Patch;
+    pub use crate::domain::shared::health::ComponentHealth;
+    pub use crate::domain::shared::health::HealthCheck;
+    pub use crate::domain::shared::health::HealthStatus;
+    pub use crate::domain::shared::term_selection::TermCatalogue;
+    pub use crate::domain::shared::term_selection::TaxonomyReference;
+    pub use crate::domain::shared::term_selection::TermReference;
+    pub use crate::domain::shared::ports::term_selection::TermSelectionValidator;
+    pub use crate::infrastructure::shared::term_selection::http_impl::HttpTermSelectionValidator;
+    pub use crate::infrastructure::shared::term_selection::in_process_impl::InProcessTermSelectionValidator;
 
     // User
     pub use crate::domain::user::User;
+    pub use crate::domain::user::actor::UserActor;
     pub use crate::domain::user::value_object::FirstName;
+    pub use crate::domain::user::value_object::IdentityId;
     pub use crate::domain::user::value_object::LastName;
     pub use crate::domain::user::value_object::UserId;
+    pub use crate::domain::user::specs::InfluencerCandidateSpec;
+    pub use crate::domain::user::specs::PendingProfileVerificationSpec;
+    pub use crate::domain::user::follower_history::FollowerCountPoint;
+    pub use crate::domain::user::follower_history::FollowerGrowth;
+    pub use crate::domain::user::follower_history::FollowerSnapshot;
+    pub use crate::domain::user::matching::CriterionScore;
+    pub use crate::domain::user::matching::CriterionWeights;
+    pub use crate::domain::user::matching::FollowerRange;
+    pub use crate::domain::user::matching::InfluencerMatch;
+    pub use crate::domain::user::matching::MatchCriterion;
+    pub use crate::domain::user::matching::MatchScore;
+    pub use crate::domain::user::matching::MatchTarget;
+
+    pub use crate::domain::user::events::UserDomainEvent;
+    pub use crate::domain::user::ports::event::UserDomainEventBus;
+    pub use crate::domain::user::ports::event::UserDomainEventHandler;
+    pub use crate::domain::user::ports::respository::UserRepository;
+    pub use crate::domain::user::ports::follower_history::FollowerHistoryRepository;
+    pub use crate::domain::user::ports::directory::UserDirectory;
 
     pub use crate::application::user::service::UserService;
+    pub use crate::application::user::service::UserChanges;
 
     pub use crate::infrastructure::user::event_bus::in_memory_bus::InMemoryUserEventBus;
     pub use crate::infrastructure::user::event_handlers::in_memory_handlers::InMemoryAuditLogHandler;
@@ -32,12 +74,25 @@ pub mod predule {
 
     pub use crate::infrastructure::user::repository::in_memory_impl::IUserserviceInMemoryUserRepository;
     pub use crate::infrastructure::user::repository::in_memory_impl::InMemoryUserRepository;
+    pub use crate::infrastructure::user::follower_history::in_memory_impl::InMemoryFollowerHistoryRepository;
+    pub use crate::infrastructure::user::directory::http_impl::HttpUserDirectory;
+    pub use crate::infrastructure::user::directory::in_process_impl::InProcessUserDirectory;
 
     // Business
     pub use crate::domain::business::Business;
     pub use crate::domain::business::value_objects::BusinessDescription;
     pub use crate::domain::business::value_objects::BusinessFeatures;
-    pub use crate::domain::business::value_objects::BusinessHourEntry;
+    pub use crate::domain::business::hours::BusinessHours;
+    pub use crate::domain::business::hours::HoursException;
+    pub use crate::domain::business::hours::TimeRange;
+    pub use crate::domain::business::claim::BusinessClaim;
+    pub use crate::domain::business::claim::BusinessVerification;
+    pub use crate::domain::business::claim::ClaimMethod;
+    pub use crate::domain::business::claim::ClaimStatus;
+    pub use crate::domain::business::membership::BusinessMembers;
+    pub use crate::domain::business::membership::Invitation;
+    pub use crate::domain::business::membership::MemberRole;
+    pub use crate::domain::business::membership::Membership;
     pub use crate::domain::business::value_objects::BusinessId;
     pub use crate::domain::business::value_objects::BusinessName;
     pub use crate::domain::business::value_objects::ContactInfo;
@@ -45,9 +100,19 @@ pub mod predule {
     pub use crate::domain::business::value_objects::ExtraFeatureValue;
     pub use crate::domain::business::value_objects::ServiceName;
 
+    pub use crate::domain::business::events::BusinessDomainEvent;
+    pub use crate::domain::business::ports::event::BusinessDomainEventBus;
+    pub use crate::domain::business::ports::event::BusinessDomainEventHandler;
+    pub use crate::domain::business::ports::respository::BusinessRepository;
+    pub use crate::domain::business::ports::claim::ClaimCodeMailer;
+    pub use crate::domain::business::ports::claim::WebsiteFetcher;
+
+    pub use crate::application::business::service::BusinessChanges;
     pub use crate::application::business::service::BusinessService;
 
     pub use crate::infrastructure::business::event_bus::in_memory_bus::InMemoryBusinessEventBus;
+    pub use crate::infrastructure::business::claim::http_impl::HttpClaimCodeMailer;
+    pub use crate::infrastructure::business::claim::http_impl::HttpWebsiteFetcher;
     pub use crate::infrastructure::business::event_handlers::in_memory_handlers::InMemoryBusinessAuditLogHandler;
 
     pub use crate::infrastructure::business::repository::in_memory_impl::IBusinessserviceInMemoryBusinessRepository;
@@ -59,6 +124,11 @@ pub mod predule {
     pub use crate::domain::taxonomy::value_objects::TaxonomyId;
     pub use crate::domain::taxonomy::value_objects::TaxonomyName;
 
+    pub use crate::domain::taxonomy::events::TaxonomyDomainEvent;
+    pub use crate::domain::taxonomy::ports::event::TaxonomyDomainEventBus;
+    pub use crate::domain::taxonomy::ports::event::TaxonomyDomainEventHandler;
+    pub use crate::domain::taxonomy::ports::respository::TaxonomyRepository;
+
     pub use crate::application::taxonomy::service::TaxonomyService;
 
     pub use crate::infrastructure::taxonomy::event_bus::in_memory_bus::InMemoryTaxonomyEventBus;
@@ -73,6 +143,11 @@ pub mod predule {
     pub use crate::domain::term::value_objects::TermId;
     pub use crate::domain::term::value_objects::TermName;
 
+    pub use crate::domain::term::events::TermDomainEvent;
+    pub use crate::domain::term::ports::event::TermDomainEventBus;
+    pub use crate::domain::term::ports::event::TermDomainEventHandler;
+    pub use crate::domain::term::ports::respository::TermRepository;
+
     pub use crate::application::term::service::TermService;
 
     pub use crate::infrastructure::term::event_bus::in_memory_bus::InMemoryTermEventBus;
@@ -1,12 +1,38 @@
+use crate::loader::layers::{Layers, non_empty};
+
+/// Shortest secret accepted for HS256 signing
+const MIN_JWT_SECRET_LEN: usize = 32;
+
+pub(crate) const KEYS: &[(&str, Option<&str>)] =
+    &[("auth.jwt_secret", None), ("auth.issuer", None)];
+
 #[derive(Debug, Clone)]
 pub struct AuthConfig {
     pub jwt_secret: String,
+    pub issuer: Option<String>,
 }
 
 impl AuthConfig {
-    pub fn load() -> Self {
-        Self {
-            jwt_secret: std::env::var("JWT_SECRET").expect("JWT_SECRET missing"),
+    /// `Some(None)` when no auth key was configured at all
+    pub(crate) fn from_layers(layers: &mut Layers) -> Option<Option<Self>> {
+        if !layers.is_set("auth") {
+            return Some(None);
         }
+
+        let jwt_secret = layers.parse("auth.jwt_secret", |raw| {
+            if raw.len() < MIN_JWT_SECRET_LEN {
+                return Err(format!(
+                    "must be at least {} bytes long",
+                    MIN_JWT_SECRET_LEN
+                ));
+            }
+            Ok(raw.to_string())
+        });
+        let issuer = layers.parse_optional("auth.issuer", non_empty);
+
+        Some(Some(Self {
+            jwt_secret: jwt_secret?,
+            issuer: issuer?,
+        }))
     }
 }
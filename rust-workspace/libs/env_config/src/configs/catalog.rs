@@ -0,0 +1,34 @@
+use crate::loader::layers::{Layers, non_empty, positive};
+
+pub(crate) const KEYS: &[(&str, Option<&str>)] = &[
+    ("catalog.taxonomy_url", None),
+    ("catalog.term_url", None),
+    ("catalog.timeout_secs", Some("5")),
+];
+
+/// Where the taxonomy and term subgraphs answer, for subgraphs that check references to them
+#[derive(Debug, Clone)]
+pub struct CatalogConfig {
+    pub taxonomy_url: String,
+    pub term_url: String,
+    pub timeout_secs: u64,
+}
+
+impl CatalogConfig {
+    /// `Some(None)` when no catalog key was configured at all
+    pub(crate) fn from_layers(layers: &mut Layers) -> Option<Option<Self>> {
+        if !layers.is_set("catalog") {
+            return Some(None);
+        }
+
+        let taxonomy_url = layers.parse("catalog.taxonomy_url", non_empty);
+        let term_url = layers.parse("catalog.term_url", non_empty);
+        let timeout_secs = layers.parse("catalog.timeout_secs", positive);
+
+        Some(Some(Self {
+            taxonomy_url: taxonomy_url?,
+            term_url: term_url?,
+            timeout_secs: timeout_secs?,
+        }))
+    }
+}
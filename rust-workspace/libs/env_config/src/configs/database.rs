@@ -1,12 +1,38 @@
+use crate::loader::layers::{Layers, non_empty, number};
+
+pub(crate) const KEYS: &[(&str, Option<&str>)] = &[
+    ("database.url", None),
+    ("database.max_connections", Some("10")),
+    ("database.connect_timeout_secs", Some("5")),
+];
+
 #[derive(Debug, Clone)]
 pub struct DatabaseConfig {
     pub url: String,
+    pub max_connections: u32,
+    pub connect_timeout_secs: u64,
 }
 
 impl DatabaseConfig {
-    pub fn load() -> Self {
-        Self {
-            url: std::env::var("DATABASE_URL").expect("DATABASE_URL missing"),
+    /// `Some(None)` when no database key was configured at all
+    pub(crate) fn from_layers(layers: &mut Layers) -> Option<Option<Self>> {
+        if !layers.is_set("database") {
+            return Some(None);
         }
+
+        let url = layers.parse("database.url", non_empty);
+        let max_connections = layers.parse("database.max_connections", |raw| {
+            match number::<u32>(raw)? {
+                0 => Err("must be at least 1".to_string()),
+                n => Ok(n),
+            }
+        });
+        let connect_timeout_secs = layers.parse("database.connect_timeout_secs", number);
+
+        Some(Some(Self {
+            url: url?,
+            max_connections: max_connections?,
+            connect_timeout_secs: connect_timeout_secs?,
+        }))
     }
 }
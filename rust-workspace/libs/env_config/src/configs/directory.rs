@@ -0,0 +1,30 @@
+use crate::loader::layers::{Layers, non_empty, positive};
+
+pub(crate) const KEYS: &[(&str, Option<&str>)] = &[
+    ("directory.user_url", None),
+    ("directory.timeout_secs", Some("5")),
+];
+
+/// Where the user subgraph answers, for subgraphs that act on behalf of the calling user
+#[derive(Debug, Clone)]
+pub struct DirectoryConfig {
+    pub user_url: String,
+    pub timeout_secs: u64,
+}
+
+impl DirectoryConfig {
+    /// `Some(None)` when no directory key was configured at all
+    pub(crate) fn from_layers(layers: &mut Layers) -> Option<Option<Self>> {
+        if !layers.is_set("directory") {
+            return Some(None);
+        }
+
+        let user_url = layers.parse("directory.user_url", non_empty);
+        let timeout_secs = layers.parse("directory.timeout_secs", positive);
+
+        Some(Some(Self {
+            user_url: user_url?,
+            timeout_secs: timeout_secs?,
+        }))
+    }
+}
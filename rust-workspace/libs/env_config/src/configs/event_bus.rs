@@ -0,0 +1,24 @@
+use crate::loader::layers::{Layers, one_of};
+
+pub(crate) const KEYS: &[(&str, Option<&str>)] = &[("event_bus.kind", Some("in_memory"))];
+
+/// Event bus implementation a subgraph publishes its domain events to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EventBusKind {
+    InMemory,
+}
+
+#[derive(Debug, Clone)]
+pub struct EventBusConfig {
+    pub kind: EventBusKind,
+}
+
+impl EventBusConfig {
+    pub(crate) fn from_layers(layers: &mut Layers) -> Option<Self> {
+        let kind = layers.parse("event_bus.kind", |raw| {
+            one_of(raw, &[("in_memory", EventBusKind::InMemory)])
+        });
+
+        Some(Self { kind: kind? })
+    }
+}
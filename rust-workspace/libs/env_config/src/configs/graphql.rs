@@ -0,0 +1,92 @@
+use std::{collections::HashSet, fs, path::PathBuf};
+
+use crate::loader::layers::{Layers, non_empty, one_of, positive};
+
+pub(crate) const KEYS: &[(&str, Option<&str>)] = &[
+    ("graphql.profile", Some("dev")),
+    ("graphql.max_depth", Some("15")),
+    ("graphql.max_complexity", Some("1000")),
+    ("graphql.persisted_queries_path", None),
+];
+
+/// How much of the GraphQL surface a subgraph exposes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Profile {
+    /// GraphiQL, introspection and full error messages
+    Dev,
+    /// No GraphiQL or introspection, capped queries, masked internal errors
+    Prod,
+}
+
+#[derive(Debug, Clone)]
+pub struct GraphqlConfig {
+    pub profile: Profile,
+    /// Deepest selection set accepted in `prod`
+    pub max_depth: usize,
+    /// Highest query complexity accepted in `prod`
+    pub max_complexity: usize,
+    /// Only these queries are executed in `prod` when set
+    pub persisted_queries: Option<PersistedQueries>,
+}
+
+/// Allowlist of SHA-256 hashes (lowercase hex) of the query texts a subgraph may run
+#[derive(Debug, Clone)]
+pub struct PersistedQueries {
+    pub path: PathBuf,
+    pub hashes: HashSet<String>,
+}
+
+impl GraphqlConfig {
+    pub(crate) fn from_layers(layers: &mut Layers) -> Option<Self> {
+        let profile = layers.parse("graphql.profile", |raw| {
+            one_of(raw, &[("dev", Profile::Dev), ("prod", Profile::Prod)])
+        });
+        let max_depth = layers.parse("graphql.max_depth", positive);
+        let max_complexity = layers.parse("graphql.max_complexity", positive);
+        let persisted_queries =
+            layers.parse_optional("graphql.persisted_queries_path", PersistedQueries::load);
+
+        Some(Self {
+            profile: profile?,
+            max_depth: max_depth?,
+            max_complexity: max_complexity?,
+            persisted_queries: persisted_queries?,
+        })
+    }
+
+    pub fn is_prod(&self) -> bool {
+        self.profile == Profile::Prod
+    }
+}
+
+impl PersistedQueries {
+    /// Reads one hash per line; blank lines and lines starting with `#` are skipped
+    fn load(raw: &str) -> Result<Self, String> {
+        let path = PathBuf::from(non_empty(raw)?);
+        let content = fs::read_to_string(&path)
+            .map_err(|e| format!("cannot read {}: {}", path.display(), e))?;
+
+        let mut hashes = HashSet::new();
+        for (number, line) in content.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            if line.len() != 64 || !line.chars().all(|c| c.is_ascii_hexdigit()) {
+                return Err(format!(
+                    "{} line {}: expected a SHA-256 hex digest, got {:?}",
+                    path.display(),
+                    number + 1,
+                    line
+                ));
+            }
+            hashes.insert(line.to_ascii_lowercase());
+        }
+
+        Ok(Self { path, hashes })
+    }
+
+    pub fn allows(&self, hash: &str) -> bool {
+        self.hashes.contains(hash)
+    }
+}
@@ -0,0 +1,55 @@
+use crate::loader::layers::{Layers, one_of};
+
+pub(crate) const KEYS: &[(&str, Option<&str>)] = &[
+    ("logging.level", Some("info")),
+    ("logging.format", Some("pretty")),
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LogLevel {
+    Trace,
+    Debug,
+    Info,
+    Warn,
+    Error,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LogFormat {
+    Pretty,
+    Json,
+}
+
+#[derive(Debug, Clone)]
+pub struct LoggingConfig {
+    pub level: LogLevel,
+    pub format: LogFormat,
+}
+
+impl LoggingConfig {
+    pub(crate) fn from_layers(layers: &mut Layers) -> Option<Self> {
+        let level = layers.parse("logging.level", |raw| {
+            one_of(
+                raw,
+                &[
+                    ("trace", LogLevel::Trace),
+                    ("debug", LogLevel::Debug),
+                    ("info", LogLevel::Info),
+                    ("warn", LogLevel::Warn),
+                    ("error", LogLevel::Error),
+                ],
+            )
+        });
+        let format = layers.parse("logging.format", |raw| {
+            one_of(
+                raw,
+                &[("pretty", LogFormat::Pretty), ("json", LogFormat::Json)],
+            )
+        });
+
+        Some(Self {
+            level: level?,
+            format: format?,
+        })
+    }
+}
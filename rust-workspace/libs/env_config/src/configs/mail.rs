@@ -0,0 +1,39 @@
+use crate::loader::layers::{Layers, non_empty, positive};
+
+pub(crate) const KEYS: &[(&str, Option<&str>)] = &[
+    ("mail.api_url", None),
+    ("mail.api_key", None),
+    ("mail.from", None),
+    ("mail.timeout_secs", Some("10")),
+];
+
+/// HTTP mail API that transactional mails, e.g. claim codes, are sent through
+#[derive(Debug, Clone)]
+pub struct MailConfig {
+    pub api_url: String,
+    pub api_key: String,
+    /// Sender address of every mail
+    pub from: String,
+    pub timeout_secs: u64,
+}
+
+impl MailConfig {
+    /// `Some(None)` when no mail key was configured at all
+    pub(crate) fn from_layers(layers: &mut Layers) -> Option<Option<Self>> {
+        if !layers.is_set("mail") {
+            return Some(None);
+        }
+
+        let api_url = layers.parse("mail.api_url", non_empty);
+        let api_key = layers.parse("mail.api_key", non_empty);
+        let from = layers.parse("mail.from", non_empty);
+        let timeout_secs = layers.parse("mail.timeout_secs", positive);
+
+        Some(Some(Self {
+            api_url: api_url?,
+            api_key: api_key?,
+            from: from?,
+            timeout_secs: timeout_secs?,
+        }))
+    }
+}
@@ -1,3 +1,9 @@
+pub mod auth;
+pub mod catalog;
 pub mod database;
+pub mod directory;
+pub mod event_bus;
+pub mod graphql;
+pub mod logging;
+pub mod mail;
 pub mod server;
-pub mod auth;
\ No newline at end of file
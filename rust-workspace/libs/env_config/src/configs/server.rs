@@ -1,19 +1,146 @@
-use serde::Deserialize;
+use std::{net::Ipv6Addr, path::PathBuf, time::Duration};
 
-#[derive(Debug, Clone, Deserialize)]
+use crate::loader::layers::{Layers, non_empty, number, positive};
+
+pub(crate) const KEYS: &[(&str, Option<&str>)] = &[
+    ("server.host", Some("127.0.0.1")),
+    ("server.port", Some("3000")),
+    ("server.shutdown_timeout_secs", Some("30")),
+    ("server.request_timeout_secs", Some("30")),
+    ("server.body_limit_bytes", Some("1048576")),
+    ("server.cors_allowed_origins", Some("*")),
+    ("server.tls_cert_path", None),
+    ("server.tls_key_path", None),
+];
+
+#[derive(Debug, Clone)]
 pub struct ServerConfig {
+    pub host: String,
     pub port: u16,
+    /// How long in-flight requests may run after a shutdown signal
+    pub shutdown_timeout_secs: u64,
+    /// Requests running longer than this are answered with 408
+    pub request_timeout_secs: u64,
+    pub body_limit_bytes: usize,
+    /// Origins allowed by CORS, `*` allowing any
+    pub cors_allowed_origins: Vec<String>,
+    /// Serve HTTPS instead of HTTP when set
+    pub tls: Option<TlsConfig>,
+}
+
+/// PEM encoded certificate chain and private key
+#[derive(Debug, Clone)]
+pub struct TlsConfig {
+    pub cert_path: PathBuf,
+    pub key_path: PathBuf,
 }
 
 impl ServerConfig {
-    pub fn load(prefix: &str) -> Self {
-        let key = format!("{}_PORT", prefix);
-
-        Self {
-            port: std::env::var(&key)
-                .unwrap_or_else(|_| "3000".to_string())
-                .parse()
-                .expect("PORT must be a number"),
+    pub(crate) fn from_layers(layers: &mut Layers) -> Option<Self> {
+        let host = layers.parse("server.host", parse_host);
+        let port = layers.parse("server.port", parse_port);
+        let shutdown_timeout_secs = layers.parse("server.shutdown_timeout_secs", number);
+        let request_timeout_secs = layers.parse("server.request_timeout_secs", positive);
+        let body_limit_bytes = layers.parse("server.body_limit_bytes", positive);
+        let cors_allowed_origins = layers.parse("server.cors_allowed_origins", parse_origins);
+        let tls = TlsConfig::from_layers(layers);
+
+        Some(Self {
+            host: host?,
+            port: port?,
+            shutdown_timeout_secs: shutdown_timeout_secs?,
+            request_timeout_secs: request_timeout_secs?,
+            body_limit_bytes: body_limit_bytes?,
+            cors_allowed_origins: cors_allowed_origins?,
+            tls: tls?,
+        })
+    }
+
+    /// `host:port` to bind the listener to
+    pub fn bind_addr(&self) -> String {
+        if self.host.parse::<Ipv6Addr>().is_ok() {
+            return format!("[{}]:{}", self.host, self.port);
+        }
+        format!("{}:{}", self.host, self.port)
+    }
+
+    pub fn shutdown_timeout(&self) -> Duration {
+        Duration::from_secs(self.shutdown_timeout_secs)
+    }
+
+    pub fn request_timeout(&self) -> Duration {
+        Duration::from_secs(self.request_timeout_secs)
+    }
+}
+
+fn parse_host(raw: &str) -> Result<String, String> {
+    let host = non_empty(raw)?;
+    if host.contains(char::is_whitespace) {
+        return Err(format!("expected a host name or IP address, got {:?}", raw));
+    }
+    Ok(host)
+}
+
+fn parse_port(raw: &str) -> Result<u16, String> {
+    match number::<u16>(raw)? {
+        0 => Err("must be between 1 and 65535".to_string()),
+        port => Ok(port),
+    }
+}
+
+fn parse_origins(raw: &str) -> Result<Vec<String>, String> {
+    let origins: Vec<String> = raw
+        .split(',')
+        .map(|origin| origin.trim().trim_end_matches('/').to_string())
+        .filter(|origin| !origin.is_empty())
+        .collect();
+    if origins.is_empty() {
+        return Err("expected `*` or a comma separated list of origins".to_string());
+    }
+    for origin in &origins {
+        if origin != "*" && !origin.starts_with("http://") && !origin.starts_with("https://") {
+            return Err(format!("expected an http(s) origin, got {:?}", origin));
         }
     }
+    Ok(origins)
+}
+
+impl TlsConfig {
+    /// `Some(None)` when neither PEM path is set
+    fn from_layers(layers: &mut Layers) -> Option<Option<Self>> {
+        let cert_path = layers.parse_optional("server.tls_cert_path", pem_file);
+        let key_path = layers.parse_optional("server.tls_key_path", pem_file);
+
+        match (cert_path?, key_path?) {
+            (Some(cert_path), Some(key_path)) => Some(Some(Self {
+                cert_path,
+                key_path,
+            })),
+            (None, None) => Some(None),
+            (Some(_), None) => {
+                layers.problem(
+                    "server.tls_key_path",
+                    "missing, required with server.tls_cert_path",
+                    None,
+                );
+                None
+            }
+            (None, Some(_)) => {
+                layers.problem(
+                    "server.tls_cert_path",
+                    "missing, required with server.tls_key_path",
+                    None,
+                );
+                None
+            }
+        }
+    }
+}
+
+fn pem_file(raw: &str) -> Result<PathBuf, String> {
+    let path = PathBuf::from(non_empty(raw)?);
+    if !path.is_file() {
+        return Err(format!("file {} not found", path.display()));
+    }
+    Ok(path)
 }
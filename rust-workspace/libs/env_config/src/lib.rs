@@ -1,7 +1,19 @@
 mod configs;
+mod loader;
+mod settings;
 
 pub mod predule {
     pub use crate::configs::auth::AuthConfig;
+    pub use crate::configs::catalog::CatalogConfig;
     pub use crate::configs::database::DatabaseConfig;
-    pub use crate::configs::server::ServerConfig;
+    pub use crate::configs::directory::DirectoryConfig;
+    pub use crate::configs::event_bus::{EventBusConfig, EventBusKind};
+    pub use crate::configs::graphql::{GraphqlConfig, PersistedQueries, Profile};
+    pub use crate::configs::logging::{LogFormat, LogLevel, LoggingConfig};
+    pub use crate::configs::mail::MailConfig;
+    pub use crate::configs::server::{ServerConfig, TlsConfig};
+    pub use crate::loader::ConfigLoader;
+    pub use crate::loader::error::{ConfigError, ConfigProblem};
+    pub use crate::loader::origin::Origin;
+    pub use crate::settings::Settings;
 }
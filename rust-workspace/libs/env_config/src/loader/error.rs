@@ -0,0 +1,46 @@
+use std::fmt;
+
+use crate::loader::origin::Origin;
+
+/// One missing or invalid configuration key
+#[derive(Debug, Clone)]
+pub struct ConfigProblem {
+    pub key: String,
+    pub message: String,
+    pub origin: Option<Origin>,
+}
+
+/// Every problem found while loading the configuration of one subgraph
+#[derive(Clone)]
+pub struct ConfigError {
+    pub prefix: String,
+    pub problems: Vec<ConfigProblem>,
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "invalid {} configuration ({} problem{}):",
+            self.prefix,
+            self.problems.len(),
+            if self.problems.len() == 1 { "" } else { "s" }
+        )?;
+        for problem in &self.problems {
+            write!(f, "\n  - {}: {}", problem.key, problem.message)?;
+            if let Some(origin) = &problem.origin {
+                write!(f, " (from {})", origin)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+// `main` prints returned errors with `Debug`, so keep it as readable as `Display`
+impl fmt::Debug for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl std::error::Error for ConfigError {}
@@ -0,0 +1,152 @@
+use std::{collections::BTreeMap, fmt::Display, str::FromStr};
+
+use crate::loader::{
+    error::{ConfigError, ConfigProblem},
+    origin::Origin,
+};
+
+/// Raw values merged from every layer, later layers overriding earlier ones
+///
+/// Typed sections parse their keys out of it and every failure is recorded
+/// instead of returned, so one load reports all problems at once.
+pub(crate) struct Layers {
+    prefix: String,
+    values: BTreeMap<String, (String, Origin)>,
+    problems: Vec<ConfigProblem>,
+}
+
+impl Layers {
+    pub(crate) fn new(prefix: &str) -> Self {
+        Self {
+            prefix: prefix.to_string(),
+            values: BTreeMap::new(),
+            problems: vec![],
+        }
+    }
+
+    pub(crate) fn set(&mut self, key: &str, value: String, origin: Origin) {
+        self.values.insert(key.to_string(), (value, origin));
+    }
+
+    pub(crate) fn contains(&self, key: &str) -> bool {
+        self.values.contains_key(key)
+    }
+
+    /// Whether any key of `section` was set by something other than a default
+    pub(crate) fn is_set(&self, section: &str) -> bool {
+        self.values.iter().any(|(key, (_, origin))| {
+            key.split('.').next() == Some(section) && *origin != Origin::Default
+        })
+    }
+
+    /// Env var holding `key`, e.g. `USER_SUBGRAPH_SERVER_PORT` for `server.port`
+    pub(crate) fn env_var(&self, key: &str) -> String {
+        format!("{}_{}", self.prefix, key.replace('.', "_").to_uppercase())
+    }
+
+    pub(crate) fn problem(
+        &mut self,
+        key: &str,
+        message: impl Into<String>,
+        origin: Option<Origin>,
+    ) {
+        self.problems.push(ConfigProblem {
+            key: key.to_string(),
+            message: message.into(),
+            origin,
+        });
+    }
+
+    pub(crate) fn missing(&mut self, key: &str) {
+        let message = format!("missing, set {} or --{}", self.env_var(key), key);
+        self.problem(key, message, None);
+    }
+
+    /// Parses a key that has a default or is otherwise required
+    pub(crate) fn parse<T>(
+        &mut self,
+        key: &str,
+        parse: impl FnOnce(&str) -> Result<T, String>,
+    ) -> Option<T> {
+        match self.parse_optional(key, parse) {
+            Some(Some(value)) => Some(value),
+            Some(None) => {
+                self.missing(key);
+                None
+            }
+            None => None,
+        }
+    }
+
+    /// Parses a key that may be left unset, `None` meaning the value was invalid
+    pub(crate) fn parse_optional<T>(
+        &mut self,
+        key: &str,
+        parse: impl FnOnce(&str) -> Result<T, String>,
+    ) -> Option<Option<T>> {
+        let Some((raw, origin)) = self.values.get(key) else {
+            return Some(None);
+        };
+        match parse(raw) {
+            Ok(value) => Some(Some(value)),
+            Err(message) => {
+                let origin = origin.clone();
+                self.problem(key, message, Some(origin));
+                None
+            }
+        }
+    }
+
+    pub(crate) fn finish(self) -> Result<(), ConfigError> {
+        if self.problems.is_empty() {
+            return Ok(());
+        }
+        Err(ConfigError {
+            prefix: self.prefix,
+            problems: self.problems,
+        })
+    }
+}
+
+pub(crate) fn number<T>(raw: &str) -> Result<T, String>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    raw.trim()
+        .parse()
+        .map_err(|e| format!("expected a number, got {:?} ({})", raw, e))
+}
+
+pub(crate) fn positive<T>(raw: &str) -> Result<T, String>
+where
+    T: FromStr + Default + PartialEq,
+    T::Err: Display,
+{
+    let value = number::<T>(raw)?;
+    if value == T::default() {
+        return Err("must be greater than 0".to_string());
+    }
+    Ok(value)
+}
+
+pub(crate) fn non_empty(raw: &str) -> Result<String, String> {
+    let value = raw.trim();
+    if value.is_empty() {
+        return Err("must not be empty".to_string());
+    }
+    Ok(value.to_string())
+}
+
+/// Matches `raw` against the accepted spellings of an enum-like setting
+pub(crate) fn one_of<T: Copy>(raw: &str, choices: &[(&str, T)]) -> Result<T, String> {
+    let value = raw.trim().to_ascii_lowercase();
+    choices
+        .iter()
+        .find(|(name, _)| *name == value)
+        .map(|(_, choice)| *choice)
+        .ok_or_else(|| {
+            let names: Vec<&str> = choices.iter().map(|(name, _)| *name).collect();
+            format!("expected one of {}, got {:?}", names.join(", "), raw)
+        })
+}
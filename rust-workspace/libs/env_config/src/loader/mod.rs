@@ -0,0 +1,469 @@
+pub mod error;
+pub mod layers;
+pub mod origin;
+
+use std::{collections::HashMap, path::PathBuf};
+
+use crate::{
+    loader::{error::ConfigError, layers::Layers, origin::Origin},
+    settings::{Settings, known_keys},
+};
+
+/// Loads `Settings` for one subgraph from four layers, each overriding the last:
+///
+/// 1. built-in defaults, optionally overridden per subgraph with `with_default`
+/// 2. a TOML file given by `--config <path>` or `{PREFIX}_CONFIG_FILE`, with one
+///    table per section (`[server]`, `[database]`, ...)
+/// 3. env vars named `{PREFIX}_{SECTION}_{KEY}`, e.g. `USER_SUBGRAPH_SERVER_PORT`
+/// 4. CLI flags named after the key, e.g. `--server.port 3001`
+///
+/// Other CLI arguments are left alone for the binary to interpret.
+pub struct ConfigLoader {
+    prefix: String,
+    defaults: Vec<(String, String)>,
+    required: Vec<String>,
+}
+
+impl ConfigLoader {
+    pub fn new(prefix: &str) -> Self {
+        Self {
+            prefix: prefix.to_string(),
+            defaults: vec![],
+            required: vec![],
+        }
+    }
+
+    /// Replaces the built-in default of `key`
+    pub fn with_default(mut self, key: &str, value: &str) -> Self {
+        self.defaults.push((key.to_string(), value.to_string()));
+        self
+    }
+
+    /// Fails the load when `key` is not set by any layer
+    pub fn require(mut self, key: &str) -> Self {
+        self.required.push(key.to_string());
+        self
+    }
+
+    /// Loads from the process arguments and environment
+    pub fn load(self) -> Result<Settings, ConfigError> {
+        self.load_from(std::env::args().skip(1), std::env::vars())
+    }
+
+    pub fn load_from(
+        self,
+        args: impl IntoIterator<Item = String>,
+        env: impl IntoIterator<Item = (String, String)>,
+    ) -> Result<Settings, ConfigError> {
+        let mut layers = Layers::new(&self.prefix);
+        let env: HashMap<String, String> = env.into_iter().collect();
+        let cli = CliArgs::parse(args, &mut layers);
+
+        // 1. defaults
+        for (key, default) in known_keys() {
+            if let Some(default) = default {
+                layers.set(key, default.to_string(), Origin::Default);
+            }
+        }
+        for (key, value) in &self.defaults {
+            if is_known(key) {
+                layers.set(key, value.clone(), Origin::Default);
+            } else {
+                layers.problem(key, "unknown key", Some(Origin::Default));
+            }
+        }
+
+        // 2. TOML file
+        let file_var = format!("{}_CONFIG_FILE", self.prefix);
+        if let Some(path) = cli
+            .config_file
+            .or_else(|| env.get(&file_var).map(PathBuf::from))
+        {
+            load_file(&path, &mut layers);
+        }
+
+        // 3. env vars, legacy names first so the prefixed ones win
+        for (key, var) in legacy_env_vars(&self.prefix) {
+            if let Some(value) = env.get(&var) {
+                layers.set(key, value.clone(), Origin::Env(var));
+            }
+        }
+        for (key, _) in known_keys() {
+            let var = layers.env_var(key);
+            if let Some(value) = env.get(&var) {
+                layers.set(key, value.clone(), Origin::Env(var));
+            }
+        }
+
+        // 4. CLI flags
+        for (key, value) in cli.values {
+            let origin = Origin::Cli(format!("--{}", key));
+            if is_known(&key) {
+                layers.set(&key, value, origin);
+            } else {
+                layers.problem(&key, "unknown key", Some(origin));
+            }
+        }
+
+        for key in &self.required {
+            if !layers.contains(key) {
+                layers.missing(key);
+            }
+        }
+
+        let settings = Settings::from_layers(&mut layers);
+        layers.finish()?;
+        Ok(settings.expect("every section parses when no problem was recorded"))
+    }
+}
+
+/// Env vars read before the prefixed ones so existing deployments keep working
+fn legacy_env_vars(prefix: &str) -> [(&'static str, String); 4] {
+    [
+        ("server.port", format!("{}_PORT", prefix)),
+        (
+            "server.shutdown_timeout_secs",
+            format!("{}_SHUTDOWN_TIMEOUT_SECS", prefix),
+        ),
+        ("database.url", "DATABASE_URL".to_string()),
+        ("auth.jwt_secret", "JWT_SECRET".to_string()),
+    ]
+}
+
+fn is_known(key: &str) -> bool {
+    known_keys().any(|(known, _)| *known == key)
+}
+
+fn load_file(path: &PathBuf, layers: &mut Layers) {
+    let origin = Origin::File(path.clone());
+    let contents = match std::fs::read_to_string(path) {
+        Ok(contents) => contents,
+        Err(e) => {
+            layers.problem("config", format!("cannot read file: {}", e), Some(origin));
+            return;
+        }
+    };
+    let table = match contents.parse::<toml::Table>() {
+        Ok(table) => table,
+        Err(e) => {
+            layers.problem(
+                "config",
+                format!("invalid TOML: {}", e.message()),
+                Some(origin),
+            );
+            return;
+        }
+    };
+
+    for (section, values) in table {
+        let toml::Value::Table(values) = values else {
+            layers.problem(&section, "expected a [section] table", Some(origin.clone()));
+            continue;
+        };
+        for (name, value) in values {
+            let key = format!("{}.{}", section, name);
+            if !is_known(&key) {
+                layers.problem(&key, "unknown key", Some(origin.clone()));
+                continue;
+            }
+            match value {
+                toml::Value::String(s) => layers.set(&key, s, origin.clone()),
+                toml::Value::Integer(_) | toml::Value::Float(_) | toml::Value::Boolean(_) => {
+                    layers.set(&key, value.to_string(), origin.clone())
+                }
+                _ => layers.problem(
+                    &key,
+                    "expected a string, number or boolean",
+                    Some(origin.clone()),
+                ),
+            }
+        }
+    }
+}
+
+/// Configuration carried on the command line
+struct CliArgs {
+    config_file: Option<PathBuf>,
+    values: Vec<(String, String)>,
+}
+
+impl CliArgs {
+    fn parse(args: impl IntoIterator<Item = String>, layers: &mut Layers) -> Self {
+        let mut cli = Self {
+            config_file: None,
+            values: vec![],
+        };
+        let mut args = args.into_iter();
+
+        while let Some(arg) = args.next() {
+            let Some(flag) = arg.strip_prefix("--") else {
+                continue;
+            };
+            let (name, inline) = match flag.split_once('=') {
+                Some((name, value)) => (name.to_string(), Some(value.to_string())),
+                None => (flag.to_string(), None),
+            };
+            if name != "config" && !name.contains('.') {
+                continue;
+            }
+            let Some(value) = inline.or_else(|| args.next()) else {
+                layers.problem(
+                    &name,
+                    "flag given without a value",
+                    Some(Origin::Cli(arg.clone())),
+                );
+                continue;
+            };
+
+            if name == "config" {
+                cli.config_file = Some(PathBuf::from(value));
+            } else {
+                cli.values.push((name, value));
+            }
+        }
+
+        cli
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        fs,
+        path::PathBuf,
+        sync::atomic::{AtomicUsize, Ordering},
+    };
+
+    use super::*;
+
+    const PREFIX: &str = "TEST_SUBGRAPH";
+
+    /// Writes `contents` to a file of its own under the temp dir
+    fn config_file(contents: &str) -> PathBuf {
+        static NEXT: AtomicUsize = AtomicUsize::new(0);
+        let path = std::env::temp_dir().join(format!(
+            "env_config_{}_{}.toml",
+            std::process::id(),
+            NEXT.fetch_add(1, Ordering::Relaxed)
+        ));
+        fs::write(&path, contents).expect("temp config file is writable");
+        path
+    }
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|arg| arg.to_string()).collect()
+    }
+
+    fn env(vars: &[(&str, &str)]) -> Vec<(String, String)> {
+        vars.iter()
+            .map(|(name, value)| (name.to_string(), value.to_string()))
+            .collect()
+    }
+
+    fn keys(error: &ConfigError) -> Vec<&str> {
+        error.problems.iter().map(|p| p.key.as_str()).collect()
+    }
+
+    #[test]
+    fn defaults_apply_when_no_layer_sets_a_key() {
+        let settings = ConfigLoader::new(PREFIX)
+            .with_default("server.port", "3004")
+            .load_from(args(&[]), env(&[]))
+            .unwrap();
+
+        assert_eq!(settings.server.port, 3004);
+        assert_eq!(settings.server.host, "127.0.0.1");
+        assert!(settings.auth.is_none());
+    }
+
+    #[test]
+    fn file_overrides_defaults_env_overrides_file_and_cli_overrides_env() {
+        let file =
+            config_file("[server]\nport = 4001\nhost = \"0.0.0.0\"\nbody_limit_bytes = 2048\n");
+        let file_var = (
+            format!("{}_CONFIG_FILE", PREFIX),
+            file.display().to_string(),
+        );
+
+        let from_file = ConfigLoader::new(PREFIX)
+            .load_from(args(&[]), vec![file_var.clone()])
+            .unwrap();
+        assert_eq!(from_file.server.port, 4001);
+        assert_eq!(from_file.server.host, "0.0.0.0");
+
+        let env_vars = vec![
+            file_var.clone(),
+            (format!("{}_SERVER_PORT", PREFIX), "4002".to_string()),
+        ];
+        let from_env = ConfigLoader::new(PREFIX)
+            .load_from(args(&[]), env_vars.clone())
+            .unwrap();
+        assert_eq!(from_env.server.port, 4002);
+        assert_eq!(from_env.server.host, "0.0.0.0");
+
+        let from_cli = ConfigLoader::new(PREFIX)
+            .load_from(args(&["--server.port", "4003"]), env_vars)
+            .unwrap();
+        assert_eq!(from_cli.server.port, 4003);
+        assert_eq!(from_cli.server.host, "0.0.0.0");
+        assert_eq!(from_cli.server.body_limit_bytes, 2048);
+
+        fs::remove_file(file).ok();
+    }
+
+    #[test]
+    fn config_flag_wins_over_config_file_env_var() {
+        let from_var = config_file("[server]\nport = 4001\n");
+        let from_flag = config_file("[server]\nport = 4002\n");
+
+        let settings = ConfigLoader::new(PREFIX)
+            .load_from(
+                args(&[&format!("--config={}", from_flag.display())]),
+                vec![(
+                    format!("{}_CONFIG_FILE", PREFIX),
+                    from_var.display().to_string(),
+                )],
+            )
+            .unwrap();
+        assert_eq!(settings.server.port, 4002);
+
+        fs::remove_file(from_var).ok();
+        fs::remove_file(from_flag).ok();
+    }
+
+    #[test]
+    fn prefixed_env_var_wins_over_legacy_name() {
+        let settings = ConfigLoader::new(PREFIX)
+            .load_from(
+                args(&[]),
+                env(&[
+                    ("TEST_SUBGRAPH_PORT", "4001"),
+                    ("TEST_SUBGRAPH_SERVER_PORT", "4002"),
+                ]),
+            )
+            .unwrap();
+        assert_eq!(settings.server.port, 4002);
+
+        let settings = ConfigLoader::new(PREFIX)
+            .load_from(args(&[]), env(&[("TEST_SUBGRAPH_PORT", "4001")]))
+            .unwrap();
+        assert_eq!(settings.server.port, 4001);
+    }
+
+    #[test]
+    fn toml_numbers_and_sections_parse() {
+        let file = config_file(
+            "[graphql]\nprofile = \"prod\"\nmax_depth = 7\n\n[catalog]\ntaxonomy_url = \"http://taxonomy\"\nterm_url = \"http://term\"\n",
+        );
+
+        let settings = ConfigLoader::new(PREFIX)
+            .load_from(args(&[&format!("--config={}", file.display())]), env(&[]))
+            .unwrap();
+        assert_eq!(settings.graphql.max_depth, 7);
+        let catalog = settings.catalog.expect("catalog section is set");
+        assert_eq!(catalog.taxonomy_url, "http://taxonomy");
+        assert_eq!(catalog.timeout_secs, 5);
+
+        fs::remove_file(file).ok();
+    }
+
+    #[test]
+    fn directory_section_is_read_from_env_vars() {
+        let settings = ConfigLoader::new(PREFIX)
+            .load_from(
+                args(&[]),
+                env(&[("TEST_SUBGRAPH_DIRECTORY_USER_URL", "http://user/graphql")]),
+            )
+            .unwrap();
+
+        let directory = settings.directory.expect("directory section is set");
+        assert_eq!(directory.user_url, "http://user/graphql");
+        assert_eq!(directory.timeout_secs, 5);
+    }
+
+    #[test]
+    fn mail_section_reports_every_missing_key() {
+        let error = ConfigLoader::new(PREFIX)
+            .load_from(
+                args(&[]),
+                env(&[("TEST_SUBGRAPH_MAIL_API_URL", "https://mail/send")]),
+            )
+            .unwrap_err();
+
+        assert_eq!(keys(&error), ["mail.api_key", "mail.from"]);
+    }
+
+    #[test]
+    fn invalid_toml_is_reported_against_the_file() {
+        let file = config_file("[server\nport = ");
+
+        let error = ConfigLoader::new(PREFIX)
+            .load_from(args(&[&format!("--config={}", file.display())]), env(&[]))
+            .unwrap_err();
+        assert_eq!(keys(&error), ["config"]);
+        assert!(error.problems[0].message.starts_with("invalid TOML"));
+        assert_eq!(error.problems[0].origin, Some(Origin::File(file.clone())));
+
+        fs::remove_file(file).ok();
+    }
+
+    #[test]
+    fn toml_keys_outside_a_table_and_unknown_keys_are_reported() {
+        let file = config_file("port = 3000\n\n[server]\nnope = 1\nport = [1]\n");
+
+        let error = ConfigLoader::new(PREFIX)
+            .load_from(args(&[&format!("--config={}", file.display())]), env(&[]))
+            .unwrap_err();
+        assert_eq!(keys(&error), ["port", "server.nope", "server.port"]);
+
+        fs::remove_file(file).ok();
+    }
+
+    #[test]
+    fn every_problem_of_every_layer_is_reported_at_once() {
+        let error = ConfigLoader::new(PREFIX)
+            .require("database.url")
+            .load_from(
+                args(&["--server.nope", "1", "--logging.level"]),
+                env(&[
+                    ("TEST_SUBGRAPH_SERVER_PORT", "not a port"),
+                    ("TEST_SUBGRAPH_SERVER_BODY_LIMIT_BYTES", "0"),
+                ]),
+            )
+            .unwrap_err();
+
+        let mut reported = keys(&error);
+        reported.sort();
+        assert_eq!(
+            reported,
+            [
+                "database.url",
+                "logging.level",
+                "server.body_limit_bytes",
+                "server.nope",
+                "server.port",
+            ]
+        );
+        let port = error
+            .problems
+            .iter()
+            .find(|p| p.key == "server.port")
+            .unwrap();
+        assert_eq!(
+            port.origin,
+            Some(Origin::Env("TEST_SUBGRAPH_SERVER_PORT".to_string()))
+        );
+        assert!(error.to_string().contains("(5 problems)"));
+    }
+
+    #[test]
+    fn arguments_that_are_not_config_flags_are_left_alone() {
+        let settings = ConfigLoader::new(PREFIX)
+            .load_from(
+                args(&["--print-schema", "positional", "--server.port=4005"]),
+                env(&[]),
+            )
+            .unwrap();
+        assert_eq!(settings.server.port, 4005);
+    }
+}
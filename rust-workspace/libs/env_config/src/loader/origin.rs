@@ -0,0 +1,21 @@
+use std::{fmt, path::PathBuf};
+
+/// Layer a configuration value was read from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Origin {
+    Default,
+    File(PathBuf),
+    Env(String),
+    Cli(String),
+}
+
+impl fmt::Display for Origin {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Origin::Default => write!(f, "default"),
+            Origin::File(path) => write!(f, "file {}", path.display()),
+            Origin::Env(var) => write!(f, "env {}", var),
+            Origin::Cli(flag) => write!(f, "flag {}", flag),
+        }
+    }
+}
@@ -0,0 +1,79 @@
+use crate::{
+    configs::{
+        auth::{self, AuthConfig},
+        catalog::{self, CatalogConfig},
+        database::{self, DatabaseConfig},
+        directory::{self, DirectoryConfig},
+        event_bus::{self, EventBusConfig},
+        graphql::{self, GraphqlConfig},
+        logging::{self, LoggingConfig},
+        mail::{self, MailConfig},
+        server::{self, ServerConfig},
+    },
+    loader::{ConfigLoader, layers::Layers},
+};
+
+/// Typed configuration of one subgraph
+///
+/// `database`, `auth`, `catalog`, `directory` and `mail` are only present when at least one of
+/// their keys is set.
+#[derive(Debug, Clone)]
+pub struct Settings {
+    pub server: ServerConfig,
+    pub database: Option<DatabaseConfig>,
+    pub auth: Option<AuthConfig>,
+    pub catalog: Option<CatalogConfig>,
+    pub directory: Option<DirectoryConfig>,
+    pub mail: Option<MailConfig>,
+    pub logging: LoggingConfig,
+    pub event_bus: EventBusConfig,
+    pub graphql: GraphqlConfig,
+}
+
+impl Settings {
+    /// Starts loading the settings of the subgraph whose env vars start with `prefix`
+    pub fn loader(prefix: &str) -> ConfigLoader {
+        ConfigLoader::new(prefix)
+    }
+
+    pub(crate) fn from_layers(layers: &mut Layers) -> Option<Self> {
+        let server = ServerConfig::from_layers(layers);
+        let database = DatabaseConfig::from_layers(layers);
+        let auth = AuthConfig::from_layers(layers);
+        let catalog = CatalogConfig::from_layers(layers);
+        let directory = DirectoryConfig::from_layers(layers);
+        let mail = MailConfig::from_layers(layers);
+        let logging = LoggingConfig::from_layers(layers);
+        let event_bus = EventBusConfig::from_layers(layers);
+        let graphql = GraphqlConfig::from_layers(layers);
+
+        Some(Self {
+            server: server?,
+            database: database?,
+            auth: auth?,
+            catalog: catalog?,
+            directory: directory?,
+            mail: mail?,
+            logging: logging?,
+            event_bus: event_bus?,
+            graphql: graphql?,
+        })
+    }
+}
+
+/// Every key the loader accepts, with its built-in default
+pub(crate) fn known_keys() -> impl Iterator<Item = &'static (&'static str, Option<&'static str>)> {
+    [
+        server::KEYS,
+        database::KEYS,
+        auth::KEYS,
+        catalog::KEYS,
+        directory::KEYS,
+        mail::KEYS,
+        logging::KEYS,
+        event_bus::KEYS,
+        graphql::KEYS,
+    ]
+    .into_iter()
+    .flatten()
+}
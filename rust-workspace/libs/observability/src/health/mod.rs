@@ -0,0 +1,2 @@
+pub mod readiness;
+pub mod report;
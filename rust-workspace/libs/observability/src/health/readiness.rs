@@ -0,0 +1,11 @@
+use std::future::Future;
+
+use corelib::predule::ComponentHealth;
+
+/// Application state that can report the health of everything it depends on
+///
+/// Implemented by each subgraph's `AppState`, usually by collecting the results of the
+/// `HealthCheck` adapters behind its services plus a config check.
+pub trait Readiness: Clone + Send + Sync + 'static {
+    fn readiness(&self) -> impl Future<Output = Vec<ComponentHealth>> + Send;
+}
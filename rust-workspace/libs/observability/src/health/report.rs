@@ -0,0 +1,42 @@
+use axum::{
+    Json,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+use corelib::predule::{ComponentHealth, HealthStatus};
+use serde::Serialize;
+
+/// JSON body returned by the `/livez` and `/readyz` endpoints
+///
+/// # Fields
+/// - `status`: Worst status across all components (`up` when there are none).
+/// - `components`: Per-component results, in the order they were checked.
+#[derive(Debug, Clone, Serialize)]
+pub struct HealthReport {
+    pub status: HealthStatus,
+    pub components: Vec<ComponentHealth>,
+}
+
+impl HealthReport {
+    pub fn new(components: Vec<ComponentHealth>) -> Self {
+        let status = components
+            .iter()
+            .map(|c| c.status)
+            .max()
+            .unwrap_or(HealthStatus::Up);
+
+        Self { status, components }
+    }
+}
+
+impl IntoResponse for HealthReport {
+    /// `503 Service Unavailable` when any component is down, `200 OK` otherwise
+    fn into_response(self) -> Response {
+        let code = match self.status {
+            HealthStatus::Down => StatusCode::SERVICE_UNAVAILABLE,
+            HealthStatus::Up | HealthStatus::Degraded => StatusCode::OK,
+        };
+
+        (code, Json(self)).into_response()
+    }
+}
@@ -0,0 +1,34 @@
+mod health;
+mod logging;
+mod metrics;
+mod routes;
+
+pub mod predule {
+    pub use crate::metrics::registry::Metrics;
+    pub use crate::metrics::registry::metrics;
+
+    pub use crate::metrics::graphql::MetricsExtension;
+
+    pub use crate::metrics::adapters::InstrumentedBusinessEventBus;
+    pub use crate::metrics::adapters::InstrumentedBusinessEventHandler;
+    pub use crate::metrics::adapters::InstrumentedBusinessRepository;
+    pub use crate::metrics::adapters::InstrumentedFollowerHistoryRepository;
+    pub use crate::metrics::adapters::InstrumentedTaxonomyEventBus;
+    pub use crate::metrics::adapters::InstrumentedTaxonomyEventHandler;
+    pub use crate::metrics::adapters::InstrumentedTaxonomyRepository;
+    pub use crate::metrics::adapters::InstrumentedTermEventBus;
+    pub use crate::metrics::adapters::InstrumentedTermEventHandler;
+    pub use crate::metrics::adapters::InstrumentedTermRepository;
+    pub use crate::metrics::adapters::InstrumentedUserEventBus;
+    pub use crate::metrics::adapters::InstrumentedUserEventHandler;
+    pub use crate::metrics::adapters::InstrumentedUserRepository;
+
+    pub use crate::health::readiness::Readiness;
+    pub use crate::health::report::HealthReport;
+
+    pub use crate::logging::subscriber::init_logging;
+
+    pub use crate::routes::handlers::health::livez_handler;
+    pub use crate::routes::handlers::health::readyz_handler;
+    pub use crate::routes::handlers::metrics::metrics_handler;
+}
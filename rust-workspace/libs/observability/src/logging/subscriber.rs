@@ -0,0 +1,22 @@
+use env_config::predule::{LogFormat, LogLevel, LoggingConfig};
+use tracing::Level;
+
+/// Installs the global `tracing` subscriber described by `config`
+///
+/// Only the first call in a process takes effect, so several subgraphs sharing
+/// one process can each call it.
+pub fn init_logging(config: &LoggingConfig) {
+    let level = match config.level {
+        LogLevel::Trace => Level::TRACE,
+        LogLevel::Debug => Level::DEBUG,
+        LogLevel::Info => Level::INFO,
+        LogLevel::Warn => Level::WARN,
+        LogLevel::Error => Level::ERROR,
+    };
+    let builder = tracing_subscriber::fmt().with_max_level(level);
+
+    let _ = match config.format {
+        LogFormat::Pretty => builder.try_init(),
+        LogFormat::Json => builder.json().try_init(),
+    };
+}
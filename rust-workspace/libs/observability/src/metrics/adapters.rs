@@ -0,0 +1,276 @@
+//! Metric-recording decorators for the repository, event bus and event handler ports
+//!
+//! Each decorator wraps an existing adapter and forwards every call to it unchanged.
+
+use std::{future::Future, time::Instant};
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use corelib::predule::{
+    Business, BusinessDomainEvent, BusinessDomainEventBus, BusinessDomainEventHandler, BusinessId,
+    BusinessRepository, ComponentHealth, DomainError, FollowerHistoryRepository, FollowerSnapshot,
+    GeoArea, HealthCheck, IdentityId, Nearby, SocialMediaPlatform, Specification, Taxonomy,
+    TaxonomyDomainEvent, TaxonomyDomainEventBus, TaxonomyDomainEventHandler, TaxonomyId,
+    TaxonomyRepository, Term, TermDomainEvent, TermDomainEventBus, TermDomainEventHandler, TermId,
+    TermRepository, User, UserDomainEvent, UserDomainEventBus, UserDomainEventHandler, UserId,
+    UserRepository,
+};
+
+use crate::metrics::registry::metrics;
+
+async fn observe_repository_call<T>(
+    repository: &'static str,
+    method: &'static str,
+    call: impl Future<Output = Result<T, DomainError>>,
+) -> Result<T, DomainError> {
+    let started = Instant::now();
+    let result = call.await;
+    metrics().observe_repository_call(repository, method, result.is_ok(), started.elapsed());
+    result
+}
+
+/// Short type name of an event handler, used as the `handler` label
+fn handler_name<H>() -> &'static str {
+    let full = std::any::type_name::<H>();
+    full.rsplit("::").next().unwrap_or(full)
+}
+
+/// Aggregate-specific repository methods go in the optional block after the
+/// repository names and are pasted into the decorator's trait impl as is.
+///
+/// A repository outside an aggregate, with no bus or handler, uses the
+/// `repository:` form and lists every method of its port in the block.
+macro_rules! instrumented_adapters {
+    (@repository $repository:ident => $instrumented_repository:ident { $($methods:tt)* }) => {
+        #[doc = concat!("Records the latency of every `", stringify!($repository), "` call")]
+        pub struct $instrumented_repository<R> {
+            inner: R,
+        }
+
+        impl<R: $repository> $instrumented_repository<R> {
+            pub fn new(inner: R) -> Self {
+                Self { inner }
+            }
+        }
+
+        #[async_trait]
+        impl<R: $repository> HealthCheck for $instrumented_repository<R> {
+            async fn check(&self) -> ComponentHealth {
+                self.inner.check().await
+            }
+        }
+
+        #[async_trait]
+        impl<R: $repository> $repository for $instrumented_repository<R> {
+            $($methods)*
+        }
+    };
+    (
+        repository: $repository:ident => $instrumented_repository:ident { $($methods:tt)* } $(,)?
+    ) => {
+        instrumented_adapters!(@repository $repository => $instrumented_repository { $($methods)* });
+    };
+    (
+        aggregate: $aggregate:literal,
+        entity: $entity:ty,
+        id: $id:ty,
+        event: $event:ty,
+        repository: $repository:ident => $instrumented_repository:ident
+            $({ $($repository_extra:tt)* })?,
+        bus: $bus:ident => $instrumented_bus:ident,
+        handler: $handler:ident => $instrumented_handler:ident $(,)?
+    ) => {
+        instrumented_adapters!(@repository $repository => $instrumented_repository {
+            async fn save(&self, entity: &$entity) -> Result<(), DomainError> {
+                observe_repository_call($aggregate, "save", self.inner.save(entity)).await
+            }
+
+            async fn find_by_id(&self, id: &$id) -> Result<Option<$entity>, DomainError> {
+                observe_repository_call($aggregate, "find_by_id", self.inner.find_by_id(id)).await
+            }
+
+            async fn find_by_ids(&self, ids: &[$id]) -> Result<Vec<$entity>, DomainError> {
+                observe_repository_call($aggregate, "find_by_ids", self.inner.find_by_ids(ids))
+                    .await
+            }
+
+            async fn query(
+                &self,
+                spec: &(dyn Specification<$entity> + Send + Sync),
+                limit: usize,
+                offset: usize,
+            ) -> Result<Vec<$entity>, DomainError> {
+                observe_repository_call($aggregate, "query", self.inner.query(spec, limit, offset))
+                    .await
+            }
+
+            async fn delete(&self, id: &$id) -> Result<(), DomainError> {
+                observe_repository_call($aggregate, "delete", self.inner.delete(id)).await
+            }
+
+            async fn close(&self) -> Result<(), DomainError> {
+                observe_repository_call($aggregate, "close", self.inner.close()).await
+            }
+
+            $($($repository_extra)*)?
+        });
+
+        #[doc = concat!("Counts every event handed to the wrapped `", stringify!($bus), "`")]
+        pub struct $instrumented_bus {
+            inner: Box<dyn $bus>,
+        }
+
+        impl $instrumented_bus {
+            pub fn new(inner: Box<dyn $bus>) -> Self {
+                Self { inner }
+            }
+        }
+
+        #[async_trait]
+        impl HealthCheck for $instrumented_bus {
+            async fn check(&self) -> ComponentHealth {
+                self.inner.check().await
+            }
+        }
+
+        impl $bus for $instrumented_bus {
+            fn publish(&self, events: &[$event]) -> Result<(), String> {
+                for ev in events {
+                    metrics().record_event_published(ev.event_name());
+                }
+                self.inner.publish(events)
+            }
+
+            fn flush(&self) -> Result<(), String> {
+                self.inner.flush()
+            }
+        }
+
+        #[doc = concat!("Counts failures of the wrapped `", stringify!($handler), "`")]
+        pub struct $instrumented_handler<H> {
+            inner: H,
+        }
+
+        impl<H: $handler> $instrumented_handler<H> {
+            pub fn new(inner: H) -> Self {
+                Self { inner }
+            }
+        }
+
+        impl<H: $handler> $handler for $instrumented_handler<H> {
+            fn handle(&self, ev: &$event) -> Result<(), String> {
+                let result = self.inner.handle(ev);
+                if result.is_err() {
+                    metrics().record_event_handler_failure(ev.event_name(), handler_name::<H>());
+                }
+                result
+            }
+        }
+    };
+}
+
+instrumented_adapters! {
+    aggregate: "user",
+    entity: User,
+    id: UserId,
+    event: UserDomainEvent,
+    repository: UserRepository => InstrumentedUserRepository {
+        async fn find_by_identity_ids(
+            &self,
+            identity_ids: &[IdentityId],
+        ) -> Result<Vec<User>, DomainError> {
+            observe_repository_call(
+                "user",
+                "find_by_identity_ids",
+                self.inner.find_by_identity_ids(identity_ids),
+            )
+            .await
+        }
+    },
+    bus: UserDomainEventBus => InstrumentedUserEventBus,
+    handler: UserDomainEventHandler => InstrumentedUserEventHandler,
+}
+
+instrumented_adapters! {
+    repository: FollowerHistoryRepository => InstrumentedFollowerHistoryRepository {
+        async fn append(&self, snapshots: &[FollowerSnapshot]) -> Result<(), DomainError> {
+            observe_repository_call("follower_history", "append", self.inner.append(snapshots))
+                .await
+        }
+
+        async fn history(
+            &self,
+            user_id: &UserId,
+            platform: Option<SocialMediaPlatform>,
+            since: Option<DateTime<Utc>>,
+            until: DateTime<Utc>,
+        ) -> Result<Vec<FollowerSnapshot>, DomainError> {
+            observe_repository_call(
+                "follower_history",
+                "history",
+                self.inner.history(user_id, platform, since, until),
+            )
+            .await
+        }
+
+        async fn close(&self) -> Result<(), DomainError> {
+            observe_repository_call("follower_history", "close", self.inner.close()).await
+        }
+    },
+}
+
+instrumented_adapters! {
+    aggregate: "business",
+    entity: Business,
+    id: BusinessId,
+    event: BusinessDomainEvent,
+    repository: BusinessRepository => InstrumentedBusinessRepository {
+        async fn find_by_member_ids(
+            &self,
+            user_ids: &[UserId],
+        ) -> Result<Vec<Business>, DomainError> {
+            observe_repository_call(
+                "business",
+                "find_by_member_ids",
+                self.inner.find_by_member_ids(user_ids),
+            )
+            .await
+        }
+
+        async fn find_in_area(
+            &self,
+            area: &GeoArea,
+            limit: usize,
+            offset: usize,
+        ) -> Result<Vec<Nearby<Business>>, DomainError> {
+            observe_repository_call(
+                "business",
+                "find_in_area",
+                self.inner.find_in_area(area, limit, offset),
+            )
+            .await
+        }
+    },
+    bus: BusinessDomainEventBus => InstrumentedBusinessEventBus,
+    handler: BusinessDomainEventHandler => InstrumentedBusinessEventHandler,
+}
+
+instrumented_adapters! {
+    aggregate: "taxonomy",
+    entity: Taxonomy,
+    id: TaxonomyId,
+    event: TaxonomyDomainEvent,
+    repository: TaxonomyRepository => InstrumentedTaxonomyRepository,
+    bus: TaxonomyDomainEventBus => InstrumentedTaxonomyEventBus,
+    handler: TaxonomyDomainEventHandler => InstrumentedTaxonomyEventHandler,
+}
+
+instrumented_adapters! {
+    aggregate: "term",
+    entity: Term,
+    id: TermId,
+    event: TermDomainEvent,
+    repository: TermRepository => InstrumentedTermRepository,
+    bus: TermDomainEventBus => InstrumentedTermEventBus,
+    handler: TermDomainEventHandler => InstrumentedTermEventHandler,
+}
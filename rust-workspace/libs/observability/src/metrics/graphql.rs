@@ -0,0 +1,67 @@
+use std::{sync::Arc, time::Instant};
+
+use async_graphql::{
+    Response,
+    extensions::{Extension, ExtensionContext, ExtensionFactory, NextExecute},
+};
+use corelib::predule::DomainError;
+
+use crate::metrics::registry::metrics;
+
+/// Schema extension recording request count, latency and domain errors per operation
+///
+/// Errors are counted by `DomainError::kind` when the resolver error was built from a
+/// `DomainError` (the usual `?` conversion keeps it as the error source), or from the
+/// `Arc<DomainError>` a DataLoader hands back.
+pub struct MetricsExtension {
+    subgraph: &'static str,
+}
+
+impl MetricsExtension {
+    pub fn new(subgraph: &'static str) -> Self {
+        Self { subgraph }
+    }
+}
+
+impl ExtensionFactory for MetricsExtension {
+    fn create(&self) -> Arc<dyn Extension> {
+        Arc::new(MetricsExtensionImpl {
+            subgraph: self.subgraph,
+        })
+    }
+}
+
+struct MetricsExtensionImpl {
+    subgraph: &'static str,
+}
+
+#[async_trait::async_trait]
+impl Extension for MetricsExtensionImpl {
+    async fn execute(
+        &self,
+        ctx: &ExtensionContext<'_>,
+        operation_name: Option<&str>,
+        next: NextExecute<'_>,
+    ) -> Response {
+        let started = Instant::now();
+        let response = next.run(ctx, operation_name).await;
+
+        metrics().observe_graphql_request(
+            self.subgraph,
+            operation_name.unwrap_or("anonymous"),
+            response.is_ok(),
+            started.elapsed(),
+        );
+
+        for error in &response.errors {
+            let domain_error = error
+                .source::<DomainError>()
+                .or_else(|| error.source::<Arc<DomainError>>().map(Arc::as_ref));
+            if let Some(domain_error) = domain_error {
+                metrics().record_domain_error(self.subgraph, domain_error.kind());
+            }
+        }
+
+        response
+    }
+}
@@ -0,0 +1,3 @@
+pub mod adapters;
+pub mod graphql;
+pub mod registry;
@@ -0,0 +1,168 @@
+use std::{sync::LazyLock, time::Duration};
+
+use prometheus::{
+    Encoder, HistogramOpts, HistogramVec, IntCounterVec, Opts, Registry, TextEncoder,
+};
+
+static METRICS: LazyLock<Metrics> = LazyLock::new(Metrics::new);
+
+/// Process-wide metrics registry
+///
+/// Every subgraph running in the process records into the same registry, so
+/// GraphQL metrics carry a `subgraph` label to tell them apart.
+pub fn metrics() -> &'static Metrics {
+    &METRICS
+}
+
+/// Prometheus collectors shared by every subgraph
+///
+/// # Collectors
+/// - `graphql_requests_total`: executed operations by subgraph, operation and outcome.
+/// - `graphql_request_duration_seconds`: operation latency by subgraph and operation.
+/// - `domain_errors_total`: `DomainError`s returned to clients, by subgraph and kind.
+/// - `repository_call_duration_seconds`: repository latency by repository, method and outcome.
+/// - `events_published_total`: domain events handed to an event bus, by event name.
+/// - `event_handler_failures_total`: failed event handler calls, by event name and handler.
+pub struct Metrics {
+    registry: Registry,
+
+    graphql_requests: IntCounterVec,
+    graphql_request_duration: HistogramVec,
+    domain_errors: IntCounterVec,
+
+    repository_call_duration: HistogramVec,
+
+    events_published: IntCounterVec,
+    event_handler_failures: IntCounterVec,
+}
+
+impl Metrics {
+    fn new() -> Self {
+        let registry = Registry::new();
+
+        let graphql_requests = IntCounterVec::new(
+            Opts::new("graphql_requests_total", "Executed GraphQL operations"),
+            &["subgraph", "operation", "outcome"],
+        )
+        .expect("valid graphql_requests_total metric");
+
+        let graphql_request_duration = HistogramVec::new(
+            HistogramOpts::new(
+                "graphql_request_duration_seconds",
+                "GraphQL operation latency in seconds",
+            ),
+            &["subgraph", "operation"],
+        )
+        .expect("valid graphql_request_duration_seconds metric");
+
+        let domain_errors = IntCounterVec::new(
+            Opts::new("domain_errors_total", "Domain errors returned to clients"),
+            &["subgraph", "kind"],
+        )
+        .expect("valid domain_errors_total metric");
+
+        let repository_call_duration = HistogramVec::new(
+            HistogramOpts::new(
+                "repository_call_duration_seconds",
+                "Repository call latency in seconds",
+            )
+            .buckets(vec![
+                0.0001, 0.0005, 0.001, 0.005, 0.01, 0.05, 0.1, 0.5, 1.0, 5.0,
+            ]),
+            &["repository", "method", "outcome"],
+        )
+        .expect("valid repository_call_duration_seconds metric");
+
+        let events_published = IntCounterVec::new(
+            Opts::new("events_published_total", "Domain events handed to an event bus"),
+            &["event_name"],
+        )
+        .expect("valid events_published_total metric");
+
+        let event_handler_failures = IntCounterVec::new(
+            Opts::new("event_handler_failures_total", "Failed event handler calls"),
+            &["event_name", "handler"],
+        )
+        .expect("valid event_handler_failures_total metric");
+
+        for collector in [
+            Box::new(graphql_requests.clone()) as Box<dyn prometheus::core::Collector>,
+            Box::new(graphql_request_duration.clone()),
+            Box::new(domain_errors.clone()),
+            Box::new(repository_call_duration.clone()),
+            Box::new(events_published.clone()),
+            Box::new(event_handler_failures.clone()),
+        ] {
+            registry
+                .register(collector)
+                .expect("metric registered only once");
+        }
+
+        Self {
+            registry,
+            graphql_requests,
+            graphql_request_duration,
+            domain_errors,
+            repository_call_duration,
+            events_published,
+            event_handler_failures,
+        }
+    }
+
+    /// Records one executed GraphQL operation
+    pub fn observe_graphql_request(
+        &self,
+        subgraph: &str,
+        operation: &str,
+        succeeded: bool,
+        elapsed: Duration,
+    ) {
+        let outcome = if succeeded { "ok" } else { "error" };
+        self.graphql_requests
+            .with_label_values(&[subgraph, operation, outcome])
+            .inc();
+        self.graphql_request_duration
+            .with_label_values(&[subgraph, operation])
+            .observe(elapsed.as_secs_f64());
+    }
+
+    /// Records one `DomainError` returned to a client
+    pub fn record_domain_error(&self, subgraph: &str, kind: &str) {
+        self.domain_errors.with_label_values(&[subgraph, kind]).inc();
+    }
+
+    /// Records the latency of one repository call
+    pub fn observe_repository_call(
+        &self,
+        repository: &str,
+        method: &str,
+        succeeded: bool,
+        elapsed: Duration,
+    ) {
+        let outcome = if succeeded { "ok" } else { "error" };
+        self.repository_call_duration
+            .with_label_values(&[repository, method, outcome])
+            .observe(elapsed.as_secs_f64());
+    }
+
+    /// Records one event handed to an event bus
+    pub fn record_event_published(&self, event_name: &str) {
+        self.events_published.with_label_values(&[event_name]).inc();
+    }
+
+    /// Records one failed event handler call
+    pub fn record_event_handler_failure(&self, event_name: &str, handler: &str) {
+        self.event_handler_failures
+            .with_label_values(&[event_name, handler])
+            .inc();
+    }
+
+    /// Renders every collector in the Prometheus text exposition format
+    pub fn render(&self) -> String {
+        let mut buffer = Vec::new();
+        TextEncoder::new()
+            .encode(&self.registry.gather(), &mut buffer)
+            .expect("text encoding of gathered metrics");
+        String::from_utf8(buffer).expect("prometheus text format is utf-8")
+    }
+}
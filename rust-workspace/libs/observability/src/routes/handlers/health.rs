@@ -0,0 +1,13 @@
+use axum::extract::State;
+
+use crate::health::{readiness::Readiness, report::HealthReport};
+
+/// Liveness probe: the process is up and serving HTTP
+pub async fn livez_handler() -> HealthReport {
+    HealthReport::new(vec![])
+}
+
+/// Readiness probe: every dependency of the subgraph can serve traffic
+pub async fn readyz_handler<S: Readiness>(State(state): State<S>) -> HealthReport {
+    HealthReport::new(state.readiness().await)
+}
@@ -0,0 +1,34 @@
+use async_graphql::{Context, Guard, Result};
+use corelib::predule::DomainError;
+
+use crate::auth::identity::AuthenticatedIdentity;
+
+/// Role required by reviewers and other back-office operations
+pub const ADMIN_ROLE: &str = "admin";
+
+/// Field guard that lets through only identities holding `role`
+///
+/// ```ignore
+/// #[graphql(guard = "RoleGuard::new(ADMIN_ROLE)")]
+/// ```
+pub struct RoleGuard {
+    role: &'static str,
+}
+
+impl RoleGuard {
+    pub fn new(role: &'static str) -> Self {
+        Self { role }
+    }
+}
+
+impl Guard for RoleGuard {
+    async fn check(&self, ctx: &Context<'_>) -> Result<()> {
+        match ctx.data_opt::<AuthenticatedIdentity>() {
+            Some(identity) if identity.has_role(self.role) => Ok(()),
+            Some(_) => {
+                Err(DomainError::forbidden(format!("requires the {} role", self.role)).into())
+            }
+            None => Err(DomainError::forbidden("requires an authenticated identity").into()),
+        }
+    }
+}
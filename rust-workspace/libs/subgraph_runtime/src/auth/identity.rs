@@ -0,0 +1,17 @@
+/// Identity that signed the request's bearer token
+///
+/// Added to the GraphQL request data only when the token verified, so resolvers
+/// read it with `ctx.data_opt::<AuthenticatedIdentity>()`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AuthenticatedIdentity {
+    /// `sub` claim of the token, the id of the `Identity` entity
+    pub id: String,
+    /// `roles` claim of the token, empty when the claim is absent
+    pub roles: Vec<String>,
+}
+
+impl AuthenticatedIdentity {
+    pub fn has_role(&self, role: &str) -> bool {
+        self.roles.iter().any(|r| r == role)
+    }
+}
@@ -0,0 +1,44 @@
+use env_config::predule::AuthConfig;
+use jsonwebtoken::{Algorithm, DecodingKey, Validation, decode, errors::Error};
+use serde::Deserialize;
+
+use crate::auth::identity::AuthenticatedIdentity;
+
+#[derive(Deserialize)]
+struct Claims {
+    sub: String,
+    #[serde(default)]
+    roles: Vec<String>,
+}
+
+/// Verifies HS256 bearer tokens signed with `auth.jwt_secret`
+///
+/// `exp` is always required; `iss` is checked only when `auth.issuer` is set.
+#[derive(Clone)]
+pub struct JwtVerifier {
+    key: DecodingKey,
+    validation: Validation,
+}
+
+impl JwtVerifier {
+    pub fn new(auth: &AuthConfig) -> Self {
+        let mut validation = Validation::new(Algorithm::HS256);
+        validation.set_required_spec_claims(&["exp", "sub"]);
+        if let Some(issuer) = &auth.issuer {
+            validation.set_issuer(&[issuer]);
+        }
+
+        Self {
+            key: DecodingKey::from_secret(auth.jwt_secret.as_bytes()),
+            validation,
+        }
+    }
+
+    pub fn verify(&self, token: &str) -> Result<AuthenticatedIdentity, Error> {
+        let data = decode::<Claims>(token, &self.key, &self.validation)?;
+        Ok(AuthenticatedIdentity {
+            id: data.claims.sub,
+            roles: data.claims.roles,
+        })
+    }
+}
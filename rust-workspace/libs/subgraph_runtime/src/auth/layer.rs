@@ -0,0 +1,48 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Request, State},
+    http::{StatusCode, header::AUTHORIZATION},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+
+use crate::auth::jwt::JwtVerifier;
+
+/// Verifies the `Authorization: Bearer` header and stores the identity as a request extension
+///
+/// Requests without the header pass through anonymously; a header that is not a
+/// valid bearer token is rejected with `401` before reaching the schema.
+pub async fn authenticate(
+    State(verifier): State<Arc<JwtVerifier>>,
+    mut request: Request,
+    next: Next,
+) -> Response {
+    let Some(header) = request.headers().get(AUTHORIZATION) else {
+        return next.run(request).await;
+    };
+
+    let token = header
+        .to_str()
+        .ok()
+        .and_then(|value| value.strip_prefix("Bearer "))
+        .map(str::trim);
+    let Some(token) = token else {
+        return (StatusCode::UNAUTHORIZED, "expected a Bearer token").into_response();
+    };
+
+    match verifier.verify(token) {
+        Ok(identity) => {
+            request.extensions_mut().insert(identity);
+            next.run(request).await
+        }
+        Err(e) => {
+            tracing::debug!("rejected bearer token: {}", e);
+            (
+                StatusCode::UNAUTHORIZED,
+                format!("invalid bearer token: {}", e),
+            )
+                .into_response()
+        }
+    }
+}
@@ -0,0 +1,4 @@
+pub mod guard;
+pub mod identity;
+pub mod jwt;
+pub mod layer;
@@ -0,0 +1,142 @@
+use std::sync::Arc;
+
+use async_graphql::{
+    ErrorExtensionValues, Response, ServerError,
+    extensions::{Extension, ExtensionContext, ExtensionFactory, NextExecute},
+};
+use corelib::predule::DomainError;
+use uuid::Uuid;
+
+/// Message returned in place of any error not written for clients
+const MASKED_MESSAGE: &str = "Internal server error";
+
+/// Schema extension replacing internal resolver errors with a generic message
+///
+/// Validation, not-found and forbidden `DomainError`s, and conflicts the domain
+/// raised deliberately, are written for clients and pass through. Anything else,
+/// including conflicts wrapping an infrastructure error, is logged with a fresh
+/// `errorId`, which is also returned so a report can be matched to the log line.
+pub struct MaskInternalErrors;
+
+impl ExtensionFactory for MaskInternalErrors {
+    fn create(&self) -> Arc<dyn Extension> {
+        Arc::new(MaskInternalErrorsImpl)
+    }
+}
+
+struct MaskInternalErrorsImpl;
+
+#[async_trait::async_trait]
+impl Extension for MaskInternalErrorsImpl {
+    async fn execute(
+        &self,
+        ctx: &ExtensionContext<'_>,
+        operation_name: Option<&str>,
+        next: NextExecute<'_>,
+    ) -> Response {
+        let mut response = next.run(ctx, operation_name).await;
+        for error in response
+            .errors
+            .iter_mut()
+            .filter(|error| !is_client_facing(error))
+        {
+            mask(error);
+        }
+        response
+    }
+}
+
+fn is_client_facing(error: &ServerError) -> bool {
+    let domain_error = error.source::<DomainError>().or_else(|| {
+        error
+            .source::<Arc<DomainError>>()
+            .map(|error| error.as_ref())
+    });
+    match domain_error {
+        Some(DomainError::Validation { .. })
+        | Some(DomainError::NotFound { .. })
+        | Some(DomainError::Forbidden { .. }) => true,
+        // `conflict_with` wraps a failing dependency, whose message is not for clients
+        Some(DomainError::Conflict { source, .. }) => source.is_none(),
+        Some(DomainError::Unavailable { .. }) | None => false,
+    }
+}
+
+fn mask(error: &mut ServerError) {
+    let error_id = Uuid::new_v4().to_string();
+    tracing::error!(error_id = %error_id, path = ?error.path, "{}", error.message);
+
+    let mut extensions = ErrorExtensionValues::default();
+    extensions.set("code", "INTERNAL_SERVER_ERROR");
+    extensions.set("errorId", error_id);
+
+    error.message = MASKED_MESSAGE.to_string();
+    error.extensions = Some(extensions);
+}
+
+#[cfg(test)]
+mod tests {
+    use corelib::predule::{
+        InMemoryTaxonomyEventBus, InMemoryTaxonomyRepository, TaxonomyDomainEvent,
+        TaxonomyDomainEventHandler, TaxonomyName, TaxonomyService,
+    };
+
+    use super::*;
+
+    fn server_error(source: DomainError) -> ServerError {
+        let mut error = ServerError::new(source.to_string(), None);
+        error.source = Some(Arc::new(source));
+        error
+    }
+
+    #[test]
+    fn passes_errors_written_for_clients() {
+        assert!(is_client_facing(&server_error(DomainError::validation(
+            "bad"
+        ))));
+        assert!(is_client_facing(&server_error(DomainError::not_found(
+            "gone"
+        ))));
+        assert!(is_client_facing(&server_error(DomainError::forbidden(
+            "no"
+        ))));
+        assert!(is_client_facing(&server_error(DomainError::conflict(
+            "taken"
+        ))));
+    }
+
+    #[test]
+    fn masks_infrastructure_and_foreign_errors() {
+        let io = std::io::Error::other("connection refused by 10.0.0.7");
+        assert!(!is_client_facing(&server_error(
+            DomainError::conflict_with("down", io)
+        )));
+        assert!(!is_client_facing(&server_error(DomainError::unavailable(
+            "down"
+        ))));
+        assert!(!is_client_facing(&ServerError::new("panic", None)));
+    }
+
+    struct UnreachableAuditLog;
+
+    impl TaxonomyDomainEventHandler for UnreachableAuditLog {
+        fn handle(&self, _: &TaxonomyDomainEvent) -> Result<(), String> {
+            Err("audit log at 10.0.0.7:5432 refused the write".to_string())
+        }
+    }
+
+    #[tokio::test]
+    async fn masks_failed_event_publishing() {
+        let mut bus = InMemoryTaxonomyEventBus::new();
+        bus.with(Box::new(UnreachableAuditLog));
+        let service =
+            TaxonomyService::new(InMemoryTaxonomyRepository::new()).with_bus(Box::new(bus));
+
+        let error = service
+            .create_taxonomy(None, TaxonomyName::new("Ages").unwrap(), true, None)
+            .await
+            .unwrap_err();
+
+        assert!(!is_client_facing(&server_error(error)));
+    }
+}
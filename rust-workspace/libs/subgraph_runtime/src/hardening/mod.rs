@@ -0,0 +1,3 @@
+pub mod masking;
+pub mod persisted;
+pub mod profile;
@@ -0,0 +1,59 @@
+use std::sync::Arc;
+
+use async_graphql::{
+    ErrorExtensionValues, Request, ServerError, ServerResult,
+    extensions::{Extension, ExtensionContext, ExtensionFactory, NextPrepareRequest},
+};
+use env_config::predule::PersistedQueries;
+use sha2::{Digest, Sha256};
+
+/// Schema extension refusing every query whose SHA-256 is not in the allowlist
+///
+/// Hashes are taken over the query text the subgraph receives, which for
+/// federated operations is the query the router plans, not the client's.
+pub struct PersistedQueryAllowlist {
+    allowlist: Arc<PersistedQueries>,
+}
+
+impl PersistedQueryAllowlist {
+    pub fn new(allowlist: PersistedQueries) -> Self {
+        Self {
+            allowlist: Arc::new(allowlist),
+        }
+    }
+}
+
+impl ExtensionFactory for PersistedQueryAllowlist {
+    fn create(&self) -> Arc<dyn Extension> {
+        Arc::new(PersistedQueryAllowlistImpl {
+            allowlist: self.allowlist.clone(),
+        })
+    }
+}
+
+struct PersistedQueryAllowlistImpl {
+    allowlist: Arc<PersistedQueries>,
+}
+
+#[async_trait::async_trait]
+impl Extension for PersistedQueryAllowlistImpl {
+    async fn prepare_request(
+        &self,
+        ctx: &ExtensionContext<'_>,
+        request: Request,
+        next: NextPrepareRequest<'_>,
+    ) -> ServerResult<Request> {
+        let hash = format!("{:x}", Sha256::digest(request.query.as_bytes()));
+        if !self.allowlist.allows(&hash) {
+            tracing::warn!(%hash, "rejected a query missing from the persisted query allowlist");
+            let mut extensions = ErrorExtensionValues::default();
+            extensions.set("code", "PERSISTED_QUERY_NOT_IN_LIST");
+
+            let mut error =
+                ServerError::new("operation is not in the persisted query allowlist", None);
+            error.extensions = Some(extensions);
+            return Err(error);
+        }
+        next.run(ctx, request).await
+    }
+}
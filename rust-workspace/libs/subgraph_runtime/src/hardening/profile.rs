@@ -0,0 +1,36 @@
+use async_graphql::{ObjectType, SchemaBuilder, SubscriptionType};
+use env_config::predule::GraphqlConfig;
+
+use crate::hardening::{masking::MaskInternalErrors, persisted::PersistedQueryAllowlist};
+
+/// Applies the `graphql` profile to a schema
+///
+/// `dev` leaves the schema as is. `prod` disables introspection, caps depth and
+/// complexity, masks internal errors and, when configured, only runs allowlisted
+/// queries. GraphiQL is turned off separately by `SubgraphRuntimeBuilder::graphql`.
+pub fn apply_profile<Q, M, S>(
+    builder: SchemaBuilder<Q, M, S>,
+    config: &GraphqlConfig,
+) -> SchemaBuilder<Q, M, S>
+where
+    Q: ObjectType + 'static,
+    M: ObjectType + 'static,
+    S: SubscriptionType + 'static,
+{
+    if !config.is_prod() {
+        return builder;
+    }
+
+    let builder = builder
+        .disable_introspection()
+        .limit_depth(config.max_depth)
+        .limit_complexity(config.max_complexity)
+        .extension(MaskInternalErrors);
+
+    match &config.persisted_queries {
+        Some(persisted_queries) => {
+            builder.extension(PersistedQueryAllowlist::new(persisted_queries.clone()))
+        }
+        None => builder,
+    }
+}
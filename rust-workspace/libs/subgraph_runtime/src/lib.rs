@@ -0,0 +1,24 @@
+mod auth;
+mod hardening;
+mod middleware;
+mod routes;
+mod runtime;
+mod server;
+
+pub mod predule {
+    pub use crate::auth::guard::ADMIN_ROLE;
+    pub use crate::auth::guard::RoleGuard;
+    pub use crate::auth::identity::AuthenticatedIdentity;
+    pub use crate::hardening::profile::apply_profile;
+    pub use crate::runtime::BoundSubgraph;
+    pub use crate::runtime::SubgraphRuntime;
+    pub use crate::runtime::group::ServeGroup;
+    pub use crate::runtime::builder::SubgraphRuntimeBuilder;
+    pub use crate::runtime::error::RuntimeError;
+    pub use crate::runtime::sdl::assert_sdl_matches;
+    pub use crate::runtime::sdl::federation_sdl;
+    pub use crate::runtime::sdl::print_schema_requested;
+    pub use crate::runtime::state::SubgraphState;
+
+    pub use crate::server::shutdown::shutdown_signal;
+}
@@ -0,0 +1,54 @@
+use axum::{
+    Router,
+    http::{HeaderName, HeaderValue, Method, StatusCode},
+};
+use env_config::predule::ServerConfig;
+use tower_http::{
+    compression::CompressionLayer,
+    cors::{AllowOrigin, Any, CorsLayer},
+    limit::RequestBodyLimitLayer,
+    request_id::{MakeRequestUuid, PropagateRequestIdLayer, SetRequestIdLayer},
+    timeout::TimeoutLayer,
+};
+
+const REQUEST_ID_HEADER: &str = "x-request-id";
+
+/// Wraps every route of a subgraph in the shared middleware stack
+///
+/// Outermost first: request id (kept from the caller, generated otherwise, and
+/// echoed on the response), CORS, gzip/br compression, request body limit and
+/// request timeout.
+pub fn with_middleware(router: Router, server: &ServerConfig) -> Router {
+    let request_id = HeaderName::from_static(REQUEST_ID_HEADER);
+
+    // `Router::layer` wraps what came before, so layers are listed innermost first
+    router
+        .layer(TimeoutLayer::with_status_code(
+            StatusCode::REQUEST_TIMEOUT,
+            server.request_timeout(),
+        ))
+        .layer(RequestBodyLimitLayer::new(server.body_limit_bytes))
+        .layer(CompressionLayer::new().gzip(true).br(true))
+        .layer(cors(&server.cors_allowed_origins, request_id.clone()))
+        .layer(PropagateRequestIdLayer::new(request_id.clone()))
+        .layer(SetRequestIdLayer::new(request_id, MakeRequestUuid))
+}
+
+fn cors(origins: &[String], request_id: HeaderName) -> CorsLayer {
+    let allow_origin = if origins.iter().any(|origin| origin == "*") {
+        AllowOrigin::any()
+    } else {
+        // origins are validated when the config is loaded
+        AllowOrigin::list(
+            origins
+                .iter()
+                .filter_map(|origin| HeaderValue::from_str(origin).ok()),
+        )
+    };
+
+    CorsLayer::new()
+        .allow_origin(allow_origin)
+        .allow_methods([Method::GET, Method::POST, Method::OPTIONS])
+        .allow_headers(Any)
+        .expose_headers([request_id])
+}
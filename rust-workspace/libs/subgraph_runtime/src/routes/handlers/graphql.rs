@@ -0,0 +1,59 @@
+use std::sync::Arc;
+
+use async_graphql::{
+    BatchResponse, Executor, Name, Response, ServerError, Value, indexmap::IndexMap,
+};
+use async_graphql_axum::{GraphQLBatchRequest, GraphQLResponse};
+use axum::{Extension, extract::State};
+use corelib::predule::DomainError;
+
+use crate::auth::identity::AuthenticatedIdentity;
+
+/// Executes the request, passing the caller's identity on as GraphQL request data
+pub async fn graphql<E: Executor>(
+    State(executor): State<E>,
+    identity: Option<Extension<AuthenticatedIdentity>>,
+    request: GraphQLBatchRequest,
+) -> GraphQLResponse {
+    let mut request = request.into_inner();
+    if let Some(Extension(identity)) = identity {
+        request = request.data(identity);
+    }
+
+    let mut response = executor.execute_batch(request).await;
+    match &mut response {
+        BatchResponse::Single(response) => attach_field_errors(response),
+        BatchResponse::Batch(responses) => responses.iter_mut().for_each(attach_field_errors),
+    }
+    response.into()
+}
+
+/// Lists the rejected fields of a validation error under its `fields` extension
+fn attach_field_errors(response: &mut Response) {
+    for error in response.errors.iter_mut() {
+        let fields: Vec<Value> = domain_error(error)
+            .map(DomainError::field_errors)
+            .unwrap_or_default()
+            .iter()
+            .map(|field| {
+                Value::Object(IndexMap::from([
+                    (Name::new("field"), Value::from(field.field.as_str())),
+                    (Name::new("message"), Value::from(field.message.as_str())),
+                ]))
+            })
+            .collect();
+
+        if !fields.is_empty() {
+            error
+                .extensions
+                .get_or_insert_with(Default::default)
+                .set("fields", Value::List(fields));
+        }
+    }
+}
+
+fn domain_error(error: &ServerError) -> Option<&DomainError> {
+    error
+        .source::<DomainError>()
+        .or_else(|| error.source::<Arc<DomainError>>().map(|e| e.as_ref()))
+}
@@ -0,0 +1,2 @@
+pub mod graphiql;
+pub mod graphql;
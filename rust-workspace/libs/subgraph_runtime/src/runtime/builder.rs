@@ -0,0 +1,105 @@
+use std::sync::Arc;
+
+use async_graphql::Executor;
+use axum::{
+    Router,
+    middleware::from_fn_with_state,
+    routing::{get, post},
+};
+use env_config::predule::{AuthConfig, GraphqlConfig, ServerConfig};
+use observability::predule::{livez_handler, metrics_handler, readyz_handler};
+
+use crate::{
+    auth::{jwt::JwtVerifier, layer::authenticate},
+    middleware::stack::with_middleware,
+    routes::handlers::{graphiql::graphiql, graphql::graphql},
+    runtime::{SubgraphRuntime, error::RuntimeError, state::SubgraphState},
+};
+
+/// Collects the pieces of a `SubgraphRuntime`; server config, schema and state are required
+pub struct SubgraphRuntimeBuilder<S> {
+    name: &'static str,
+    server: Option<ServerConfig>,
+    graphql: Option<Router>,
+    auth: Option<Arc<JwtVerifier>>,
+    graphiql: bool,
+    state: S,
+}
+
+impl SubgraphRuntimeBuilder<()> {
+    pub(crate) fn new(name: &'static str) -> Self {
+        Self {
+            name,
+            server: None,
+            graphql: None,
+            auth: None,
+            graphiql: false,
+            state: (),
+        }
+    }
+}
+
+impl<S> SubgraphRuntimeBuilder<S> {
+    pub fn server(mut self, server: &ServerConfig) -> Self {
+        self.server = Some(server.clone());
+        self
+    }
+
+    /// Serves `schema` on `POST /`
+    pub fn schema<E: Executor>(mut self, schema: E) -> Self {
+        self.graphql = Some(
+            Router::new()
+                .route("/", post(graphql::<E>))
+                .with_state(schema),
+        );
+        self
+    }
+
+    /// GraphiQL is served on `GET /` unless the profile is `prod`, without this call it
+    /// is never served; the schema side of the profile is applied with `apply_profile`
+    pub fn graphql(mut self, graphql: &GraphqlConfig) -> Self {
+        self.graphiql = !graphql.is_prod();
+        self
+    }
+
+    /// Verifies bearer tokens on the GraphQL route and hands the caller's
+    /// `AuthenticatedIdentity` to resolvers; without it every request is anonymous
+    pub fn auth(mut self, auth: Option<&AuthConfig>) -> Self {
+        self.auth = auth.map(|auth| Arc::new(JwtVerifier::new(auth)));
+        self
+    }
+
+    pub fn state<T: SubgraphState>(self, state: T) -> SubgraphRuntimeBuilder<T> {
+        SubgraphRuntimeBuilder {
+            name: self.name,
+            server: self.server,
+            graphql: self.graphql,
+            auth: self.auth,
+            graphiql: self.graphiql,
+            state,
+        }
+    }
+}
+
+impl<S: SubgraphState> SubgraphRuntimeBuilder<S> {
+    /// Adds `/livez`, `/readyz` and `/metrics` and wraps everything in the middleware stack
+    pub fn build(self) -> Result<SubgraphRuntime<S>, RuntimeError> {
+        let server = self.server.ok_or(RuntimeError::Missing("server config"))?;
+        let mut graphql = self.graphql.ok_or(RuntimeError::Missing("schema"))?;
+        if self.graphiql {
+            graphql = graphql.route("/", get(graphiql));
+        }
+        if let Some(verifier) = self.auth {
+            graphql = graphql.layer(from_fn_with_state(verifier, authenticate));
+        }
+
+        let probes = Router::new()
+            .route("/livez", get(livez_handler))
+            .route("/readyz", get(readyz_handler::<S>))
+            .route("/metrics", get(metrics_handler))
+            .with_state(self.state.clone());
+        let router = with_middleware(graphql.merge(probes), &server);
+
+        Ok(SubgraphRuntime::new(self.name, server, router, self.state))
+    }
+}
@@ -0,0 +1,27 @@
+use std::{io, time::Duration};
+
+use corelib::predule::DomainError;
+
+#[derive(Debug, thiserror::Error)]
+pub enum RuntimeError {
+    #[error("subgraph runtime built without a {0}")]
+    Missing(&'static str),
+
+    #[error("cannot bind {addr}: {source}")]
+    Bind { addr: String, source: io::Error },
+
+    #[error("cannot load the TLS certificate or key: {0}")]
+    Tls(io::Error),
+
+    #[error("server error: {0}")]
+    Serve(io::Error),
+
+    #[error("in-flight requests still running after the {0:?} shutdown deadline")]
+    DeadlineExceeded(Duration),
+
+    #[error("shutdown failed: {0}")]
+    Shutdown(#[from] DomainError),
+
+    #[error("{0} subgraph panicked")]
+    Panicked(&'static str),
+}
@@ -0,0 +1,89 @@
+use std::future::Future;
+
+use tokio::sync::{mpsc, watch};
+
+use crate::{
+    runtime::{BoundSubgraph, error::RuntimeError, state::SubgraphState},
+    server::shutdown::shutdown_signal,
+};
+
+/// Serves several bound subgraphs from one process, stopping all of them as soon
+/// as one fails or a shutdown signal arrives
+///
+/// ```ignore
+/// let group = ServeGroup::new();
+/// let served = tokio::try_join!(group.serve(user), group.serve(term));
+/// group.stop().await;
+/// served?;
+/// ```
+pub struct ServeGroup {
+    stop: watch::Sender<bool>,
+    running: mpsc::Sender<()>,
+    finished: mpsc::Receiver<()>,
+}
+
+impl ServeGroup {
+    pub fn new() -> Self {
+        let (stop, _) = watch::channel(false);
+        let (running, finished) = mpsc::channel(1);
+        Self {
+            stop,
+            running,
+            finished,
+        }
+    }
+
+    /// Spawns `subgraph`, the returned future resolves with its outcome
+    ///
+    /// The subgraph keeps draining in the background when the future is
+    /// dropped, e.g. by `try_join!` after another one failed; `stop` waits for it.
+    pub fn serve<S>(
+        &self,
+        subgraph: BoundSubgraph<S>,
+    ) -> impl Future<Output = Result<(), RuntimeError>> + use<S>
+    where
+        S: SubgraphState + Send + 'static,
+    {
+        let name = subgraph.name();
+        let stop = self.stop.clone();
+        let mut stopped = self.stop.subscribe();
+        let running = self.running.clone();
+
+        let task = tokio::spawn(async move {
+            let _running = running;
+            let shutdown = async move {
+                tokio::select! {
+                    _ = shutdown_signal() => {}
+                    _ = stopped.wait_for(|stopped| *stopped) => {}
+                }
+            };
+
+            let served = subgraph.serve_until(shutdown).await;
+            if served.is_err() {
+                stop.send_replace(true);
+            }
+            served
+        });
+
+        async move { task.await.map_err(|_| RuntimeError::Panicked(name))? }
+    }
+
+    /// Stops every subgraph still serving and waits until all of them shut down
+    pub async fn stop(self) {
+        let Self {
+            stop,
+            running,
+            mut finished,
+        } = self;
+        stop.send_replace(true);
+        drop(running);
+        // resolves once every spawned subgraph dropped its sender
+        while finished.recv().await.is_some() {}
+    }
+}
+
+impl Default for ServeGroup {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,221 @@
+pub mod builder;
+pub mod error;
+pub mod group;
+pub mod sdl;
+pub mod state;
+
+use std::future::Future;
+
+use axum::Router;
+use axum_server::tls_rustls::RustlsConfig;
+use env_config::predule::ServerConfig;
+use tokio::net::TcpListener;
+
+use crate::{
+    runtime::{builder::SubgraphRuntimeBuilder, error::RuntimeError, state::SubgraphState},
+    server::{
+        shutdown::{serve_until_shutdown, shutdown_signal},
+        tls::load_tls,
+    },
+};
+
+/// A subgraph ready to serve: its routes wrapped in the shared middleware, the
+/// server settings and the state drained on shutdown
+///
+/// ```ignore
+/// SubgraphRuntime::builder("user")
+///     .server(&env_config.server)
+///     .schema(build_schema(app_state.clone()))
+///     .state(app_state)
+///     .build()?
+///     .run()
+///     .await?;
+/// ```
+pub struct SubgraphRuntime<S> {
+    name: &'static str,
+    server: ServerConfig,
+    router: Router,
+    state: S,
+}
+
+impl SubgraphRuntime<()> {
+    pub fn builder(name: &'static str) -> SubgraphRuntimeBuilder<()> {
+        SubgraphRuntimeBuilder::new(name)
+    }
+}
+
+impl<S: SubgraphState> SubgraphRuntime<S> {
+    pub(crate) fn new(name: &'static str, server: ServerConfig, router: Router, state: S) -> Self {
+        Self {
+            name,
+            server,
+            router,
+            state,
+        }
+    }
+
+    /// Every route with the middleware applied, for serving or testing without a socket
+    pub fn router(&self) -> Router {
+        self.router.clone()
+    }
+
+    /// Binds and serves until SIGINT/SIGTERM, see `bind` and `BoundSubgraph::serve`
+    pub async fn run(self) -> Result<(), RuntimeError> {
+        self.bind().await?.serve().await
+    }
+
+    /// Loads the TLS files and binds the listener without serving yet, so a process
+    /// hosting several subgraphs can fail before any of them takes a request
+    pub async fn bind(self) -> Result<BoundSubgraph<S>, RuntimeError> {
+        let tls = match &self.server.tls {
+            Some(tls) => Some(load_tls(tls).await?),
+            None => None,
+        };
+
+        let addr = self.server.bind_addr();
+        let listener = TcpListener::bind(&addr)
+            .await
+            .map_err(|source| RuntimeError::Bind {
+                addr: addr.clone(),
+                source,
+            })?;
+
+        Ok(BoundSubgraph {
+            name: self.name,
+            server: self.server,
+            router: self.router,
+            state: self.state,
+            listener,
+            tls,
+        })
+    }
+}
+
+/// A subgraph whose listener is bound, ready to serve
+pub struct BoundSubgraph<S> {
+    name: &'static str,
+    server: ServerConfig,
+    router: Router,
+    state: S,
+    listener: TcpListener,
+    tls: Option<RustlsConfig>,
+}
+
+impl<S: SubgraphState> BoundSubgraph<S> {
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Serves until SIGINT/SIGTERM, drains in-flight requests, then shuts the state down
+    pub async fn serve(self) -> Result<(), RuntimeError> {
+        self.serve_until(shutdown_signal()).await
+    }
+
+    /// Like `serve`, stopping when `shutdown` resolves instead
+    pub async fn serve_until(
+        self,
+        shutdown: impl Future<Output = ()> + Send + 'static,
+    ) -> Result<(), RuntimeError> {
+        let scheme = if self.tls.is_some() { "https" } else { "http" };
+        tracing::info!(
+            "{} subgraph running at {}://{}",
+            self.name,
+            scheme,
+            self.server.bind_addr()
+        );
+
+        let served = serve_until_shutdown(
+            self.listener,
+            self.router,
+            self.tls,
+            self.server.shutdown_timeout(),
+            shutdown,
+        )
+        .await;
+
+        // flush events and close repositories even when the drain deadline was exceeded
+        let shut_down = self.state.shutdown().await;
+        if let (Err(_), Err(e)) = (&served, &shut_down) {
+            // the serve error is the one returned, this one would be lost otherwise
+            tracing::error!("{} subgraph shutdown failed: {}", self.name, e);
+        }
+        served?;
+        shut_down?;
+
+        tracing::info!("{} subgraph stopped", self.name);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{io::Write, sync::Arc, time::Duration};
+
+    use axum::routing::get;
+    use corelib::predule::{ComponentHealth, DomainError};
+    use observability::predule::Readiness;
+    use tokio::sync::Notify;
+
+    use super::*;
+
+    #[derive(Clone)]
+    struct FailingState;
+
+    impl Readiness for FailingState {
+        async fn readiness(&self) -> Vec<ComponentHealth> {
+            Vec::new()
+        }
+    }
+
+    impl SubgraphState for FailingState {
+        async fn shutdown(&self) -> Result<(), DomainError> {
+            Err(DomainError::unavailable("event bus unreachable"))
+        }
+    }
+
+    #[tokio::test]
+    async fn a_failed_drain_is_returned_when_shutdown_fails_too() {
+        let started = Arc::new(Notify::new());
+        let router = Router::new().route(
+            "/slow",
+            get({
+                let started = started.clone();
+                move || async move {
+                    started.notify_one();
+                    tokio::time::sleep(Duration::from_secs(30)).await;
+                }
+            }),
+        );
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let bound = BoundSubgraph {
+            name: "test",
+            server: ServerConfig {
+                host: "127.0.0.1".to_string(),
+                port: addr.port(),
+                shutdown_timeout_secs: 0,
+                request_timeout_secs: 60,
+                body_limit_bytes: 1024,
+                cors_allowed_origins: vec!["*".to_string()],
+                tls: None,
+            },
+            router,
+            state: FailingState,
+            listener,
+            tls: None,
+        };
+
+        // kept open so the request is still in flight when the deadline passes
+        let mut client = std::net::TcpStream::connect(addr).unwrap();
+        client
+            .write_all(b"GET /slow HTTP/1.1\r\nHost: localhost\r\n\r\n")
+            .unwrap();
+
+        let err = bound
+            .serve_until(async move { started.notified().await })
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, RuntimeError::DeadlineExceeded(_)), "{}", err);
+    }
+}
@@ -0,0 +1,63 @@
+use std::{fs, path::Path};
+
+use async_graphql::{ObjectType, SDLExportOptions, Schema, SubscriptionType};
+
+/// Federation SDL of `schema`, the same document the router reads from `_service { sdl }`
+pub fn federation_sdl<Q, M, S>(schema: &Schema<Q, M, S>) -> String
+where
+    Q: ObjectType + 'static,
+    M: ObjectType + 'static,
+    S: SubscriptionType + 'static,
+{
+    schema.sdl_with_options(SDLExportOptions::new().federation().compose_directive())
+}
+
+/// Whether the process was started with `--print-schema`, asking for the SDL instead of a server
+pub fn print_schema_requested() -> bool {
+    std::env::args().skip(1).any(|arg| arg == "--print-schema")
+}
+
+/// Panics at the first line where the SDL checked in at `path` drifts from `sdl`,
+/// for the schema drift test of every subgraph
+///
+/// The file is expected at `gateway/schemas/<subgraph>.graphql`, the hint to
+/// regenerate it is derived from that name.
+pub fn assert_sdl_matches(path: impl AsRef<Path>, sdl: &str) {
+    let path = path.as_ref();
+    let subgraph = path
+        .file_stem()
+        .and_then(|stem| stem.to_str())
+        .unwrap_or("<subgraph>");
+    let regenerate = format!(
+        "regenerate it from rust-workspace/ with\n  \
+        cargo run -q -p {subgraph} -- --print-schema > ../gateway/schemas/{subgraph}.graphql"
+    );
+    let checked_in = fs::read_to_string(path)
+        .unwrap_or_else(|e| panic!("cannot read {}: {}", path.display(), e));
+
+    let mut generated_lines = sdl.lines();
+    let mut checked_in_lines = checked_in.lines();
+    for line in 1.. {
+        match (generated_lines.next(), checked_in_lines.next()) {
+            (None, None) => break,
+            (expected, actual) if expected != actual => panic!(
+                "{} drifted from the {} schema at line {}:\n  generated:  {}\n  checked in: {}\n{}",
+                path.display(),
+                subgraph,
+                line,
+                expected.unwrap_or("<end of file>"),
+                actual.unwrap_or("<end of file>"),
+                regenerate,
+            ),
+            _ => {}
+        }
+    }
+
+    assert!(
+        checked_in == sdl,
+        "{} differs from the {} schema in trailing newlines; {}",
+        path.display(),
+        subgraph,
+        regenerate,
+    );
+}
@@ -0,0 +1,12 @@
+use std::future::Future;
+
+use corelib::predule::DomainError;
+use observability::predule::Readiness;
+
+/// Application state a subgraph runtime serves with
+///
+/// Readiness backs `/readyz`; `shutdown` runs once the server stopped taking
+/// requests, to flush events and close repositories.
+pub trait SubgraphState: Readiness {
+    fn shutdown(&self) -> impl Future<Output = Result<(), DomainError>> + Send;
+}
@@ -0,0 +1,97 @@
+use std::{future::Future, io, pin::Pin, time::Duration};
+
+use axum::Router;
+use axum_server::{Handle, tls_rustls::RustlsConfig};
+use tokio::{net::TcpListener, sync::watch};
+
+use crate::runtime::error::RuntimeError;
+
+/// Resolves once the process receives SIGINT (Ctrl+C) or SIGTERM
+pub async fn shutdown_signal() {
+    let ctrl_c = async {
+        tokio::signal::ctrl_c()
+            .await
+            .expect("failed to install the SIGINT handler");
+    };
+
+    #[cfg(unix)]
+    let terminate = async {
+        tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate())
+            .expect("failed to install the SIGTERM handler")
+            .recv()
+            .await;
+    };
+
+    #[cfg(not(unix))]
+    let terminate = std::future::pending::<()>();
+
+    tokio::select! {
+        _ = ctrl_c => {},
+        _ = terminate => {},
+    }
+}
+
+/// Serves `router` over HTTP, or HTTPS when `tls` is set, until `shutdown` resolves,
+/// e.g. on `shutdown_signal`
+///
+/// On shutdown the listener stops accepting connections and in-flight requests
+/// get `deadline` to finish; past that they are dropped and
+/// `RuntimeError::DeadlineExceeded` is returned.
+pub async fn serve_until_shutdown(
+    listener: TcpListener,
+    router: Router,
+    tls: Option<RustlsConfig>,
+    deadline: Duration,
+    shutdown: impl Future<Output = ()> + Send + 'static,
+) -> Result<(), RuntimeError> {
+    let (signalled_tx, mut signalled_rx) = watch::channel(false);
+    let signalled = async move {
+        shutdown.await;
+        tracing::info!(
+            "Shutdown signal received, draining in-flight requests for up to {:?}",
+            deadline
+        );
+        let _ = signalled_tx.send(true);
+    };
+
+    let server: Pin<Box<dyn Future<Output = io::Result<()>> + Send>> = match tls {
+        None => Box::pin(
+            axum::serve(listener, router)
+                .with_graceful_shutdown(signalled)
+                .into_future(),
+        ),
+        Some(config) => {
+            let handle = Handle::new();
+            tokio::spawn({
+                let handle = handle.clone();
+                async move {
+                    signalled.await;
+                    handle.graceful_shutdown(None);
+                }
+            });
+            let listener = listener.into_std().map_err(RuntimeError::Serve)?;
+            Box::pin(
+                axum_server::from_tcp_rustls(listener, config)
+                    .map_err(RuntimeError::Serve)?
+                    .handle(handle)
+                    .serve(router.into_make_service()),
+            )
+        }
+    };
+
+    let drain_deadline = async move {
+        // only start counting once the signal has actually been received
+        if signalled_rx.wait_for(|signalled| *signalled).await.is_err() {
+            std::future::pending::<()>().await;
+        }
+        tokio::time::sleep(deadline).await;
+    };
+
+    tokio::select! {
+        served = server => served.map_err(RuntimeError::Serve),
+        _ = drain_deadline => {
+            tracing::warn!("Shutdown deadline of {:?} exceeded, dropping in-flight requests", deadline);
+            Err(RuntimeError::DeadlineExceeded(deadline))
+        }
+    }
+}
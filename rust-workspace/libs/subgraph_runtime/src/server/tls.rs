@@ -0,0 +1,14 @@
+use axum_server::tls_rustls::RustlsConfig;
+use env_config::predule::TlsConfig;
+
+use crate::runtime::error::RuntimeError;
+
+/// Reads the PEM certificate chain and private key into a rustls config
+pub async fn load_tls(tls: &TlsConfig) -> Result<RustlsConfig, RuntimeError> {
+    // `ring` is the only provider compiled in, installing it twice is harmless
+    let _ = rustls::crypto::ring::default_provider().install_default();
+
+    RustlsConfig::from_pem_file(&tls.cert_path, &tls.key_path)
+        .await
+        .map_err(RuntimeError::Tls)
+}
@@ -48,10 +48,10 @@ health_url_for_service() {
   case "$1" in
     identity) echo "http://localhost:3005/graphql" ;;
     wallet) echo "http://localhost:3006/graphql" ;;
-    user)     echo "http://localhost:3001" ;;
-    term)     echo "http://localhost:3002" ;;
-    taxonomy) echo "http://localhost:3003" ;;
-    business) echo "http://localhost:3004" ;;
+    user)     echo "http://localhost:3001/readyz" ;;
+    term)     echo "http://localhost:3002/readyz" ;;
+    taxonomy) echo "http://localhost:3003/readyz" ;;
+    business) echo "http://localhost:3004/readyz" ;;
     *)        echo "" ;;
   esac
 }
@@ -71,8 +71,15 @@ wait_for_service() {
   local attempt=1
 
   while (( attempt <= max_retries )); do
-    # NOTE: removed -f so ANY HTTP response counts as healthy
-    if curl -sS "$url" >/dev/null 2>&1; then
+    # Rust subgraphs answer /readyz with 503 until every component is up, so
+    # only a 2xx counts. Node subgraphs have no readiness probe: any response
+    # from their GraphQL endpoint counts as healthy.
+    local curl_flags=(-sS)
+    if [[ "$url" == */readyz ]]; then
+      curl_flags+=(-f)
+    fi
+
+    if curl "${curl_flags[@]}" "$url" >/dev/null 2>&1; then
       echo "✅ $service is UP at $url"
       return 0
     fi
@@ -113,7 +120,8 @@ for SERVICE in "${RUST_SERVICES[@]}"; do
   echo "🦀 Starting Rust subgraph: $SERVICE"
   (
     cd "$ROOT_DIR/rust-workspace"
-    cargo run --package "$SERVICE" --release
+    # exec so the cleanup SIGTERM reaches the subgraph and lets it drain
+    exec cargo run --package "$SERVICE" --release
   ) &
   PIDS+=($!)
 done